@@ -0,0 +1,106 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Compare two pyperf result files produced by run_pyperf.py.
+
+For every benchmark present in both files the throughput (ops/sec) of the
+baseline and of the current run are reported along with their ratio. The script
+exits with a non-zero status if any benchmark slowed down by more than the
+allowed threshold.
+
+Examples:
+    git stash && pip install . && python benchmarks/run_pyperf.py --hot-paths \\
+        --json-output baseline.json
+    git stash pop && pip install . && python benchmarks/run_pyperf.py --hot-paths \\
+        --json-output current.json
+    python benchmarks/compare_pyperf.py baseline.json current.json --threshold 5
+"""
+
+import argparse
+from pathlib import Path
+
+
+def _load_means(path: str) -> dict[str, float]:
+    import pyperf
+
+    suite = pyperf.BenchmarkSuite.load(str(Path(path)))
+    return {benchmark.get_name(): benchmark.mean() for benchmark in suite}
+
+
+def _format_ops(mean: float) -> str:
+    return f"{1.0 / mean:,.0f}" if mean else "inf"
+
+
+def compare(
+    baseline: dict[str, float], current: dict[str, float], threshold: float
+) -> tuple[list[tuple[str, str, str, str, str]], list[str]]:
+    """Build the comparison rows and the list of regressed benchmarks.
+
+    The change is expressed as the relative variation of the throughput so that
+    a positive value means the current run is faster.
+    """
+    rows: list[tuple[str, str, str, str, str]] = []
+    regressions: list[str] = []
+    for name in sorted(baseline.keys() & current.keys()):
+        base_mean = baseline[name]
+        cur_mean = current[name]
+        change = (base_mean / cur_mean - 1.0) * 100.0 if cur_mean else 0.0
+        status = "ok"
+        if change < -threshold:
+            status = "REGRESSION"
+            regressions.append(name)
+        elif change > threshold:
+            status = "faster"
+        rows.append(
+            (
+                name,
+                _format_ops(base_mean),
+                _format_ops(cur_mean),
+                f"{change:+.2f}%",
+                status,
+            )
+        )
+    return rows, regressions
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(
+        description="Compare pyperf results against a baseline."
+    )
+    parser.add_argument("baseline", help="pyperf JSON file of the baseline run.")
+    parser.add_argument("current", help="pyperf JSON file of the current run.")
+    parser.add_argument(
+        "--threshold",
+        type=float,
+        default=5.0,
+        help="Allowed throughput loss in percent before reporting a regression.",
+    )
+    args = parser.parse_args()
+
+    baseline = _load_means(args.baseline)
+    current = _load_means(args.current)
+    rows, regressions = compare(baseline, current, args.threshold)
+    if not rows:
+        raise SystemExit("No common benchmarks between the two result files.")
+
+    headers = ("Benchmark", "Baseline ops/s", "Current ops/s", "Change", "Status")
+    widths = [max(len(row[i]) for row in (headers, *rows)) for i in range(5)]
+    for row in (headers, *rows):
+        print("  ".join(cell.ljust(width) for cell, width in zip(row, widths)))
+
+    for name in sorted(baseline.keys() ^ current.keys()):
+        print(f"Skipped {name}: only present in one of the result files.")
+
+    if regressions:
+        raise SystemExit(
+            f"{len(regressions)} benchmark(s) regressed by more than "
+            f"{args.threshold}%: {', '.join(regressions)}"
+        )
+
+
+if __name__ == "__main__":
+    main()
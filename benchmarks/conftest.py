@@ -13,6 +13,7 @@ import pytest
 
 from ators import Ators, freeze, member
 from ators.behaviors import ValueValidator
+from ators.validators import TypeValidator, Validator
 from benchmarks.shared.runtime import atom_benchmarks_available
 
 ATOM_AVAILABLE = atom_benchmarks_available()
@@ -89,6 +90,16 @@ class AtorsValidatedClass(Ators):
     # Optional type
     optional_int_field: Optional[int] = member()
 
+    # Same validation as above spelled as a generic union, used as a baseline
+    union_int_none_field = member().validate(
+        TypeValidator.Union(
+            [Validator(TypeValidator.Int()), Validator(TypeValidator.Typed(type(None)))]
+        )
+    )
+
+    # Union type (the benchmark value matches the second member of the union)
+    union_field: int | str | bytes = member()
+
     # Enum-like using Literal
     enum_like_field: Literal[1, 2, 3] = member()
 
@@ -254,6 +265,8 @@ def ators_typed():
         fixed_tuple_field=(0, 0, ""),
         frozen_set_field=frozenset(),
         optional_int_field=None,
+        union_int_none_field=None,
+        union_field=0,
         enum_like_field=1,
         constrained_int_field=0,
         custom_class_field=CustomClass(),
@@ -317,6 +330,7 @@ class PropertyValidatedClass:
         self._tuple_field = ()
         self._fixed_tuple_field = (0, 0, "")
         self._optional_int_field = None
+        self._union_field = 0
         self._enum_like_field = 1
         self._constrained_int_field = 0
         self._frozen_set_field = frozenset()
@@ -456,6 +470,16 @@ class PropertyValidatedClass:
             raise TypeError(f"Expected int or None, got {type(value)}")
         self._optional_int_field = value
 
+    @property
+    def union_field(self):
+        return self._union_field
+
+    @union_field.setter
+    def union_field(self, value):
+        if not isinstance(value, (int, str, bytes)):
+            raise TypeError(f"Expected int, str or bytes, got {type(value)}")
+        self._union_field = value
+
     @property
     def enum_like_field(self):
         return self._enum_like_field
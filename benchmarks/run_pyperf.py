@@ -15,6 +15,7 @@ Examples:
     python benchmarks/run_pyperf.py --family list
     python benchmarks/run_pyperf.py --family get_untyped
     python benchmarks/run_pyperf.py --family dict --group update_dict --implementation ators
+    python benchmarks/run_pyperf.py --hot-paths --json-output baseline.json
 """
 
 import argparse
@@ -25,6 +26,17 @@ ROOT = Path(__file__).resolve().parent.parent
 if str(ROOT) not in sys.path:
     sys.path.insert(0, str(ROOT))
 
+# Families covering the scalar, union and container validation paths whose
+# performance should be compared against a baseline before merging fast paths.
+HOT_PATH_FAMILIES = (
+    "validation_int",
+    "validation_str",
+    "validation_union",
+    "validation_set",
+    "validation_dict",
+    "validation_list",
+)
+
 
 def _try_make_console():
     try:
@@ -244,6 +256,17 @@ def _write_markdown_report(output_path: str, results) -> None:
     print(f"Markdown report written to: {path}")
 
 
+def _write_json_results(output_path: str, results) -> None:
+    """Dump the results as a pyperf suite usable by compare_pyperf.py."""
+    import pyperf
+
+    path = Path(output_path)
+    path.parent.mkdir(parents=True, exist_ok=True)
+    suite = pyperf.BenchmarkSuite([benchmark for _, benchmark in results])
+    suite.dump(str(path), replace=True)
+    print(f"JSON results written to: {path}")
+
+
 def _forward_only_pyperf_args(pyperf_args: list[str]) -> None:
     """Keep only script name and pyperf-specific arguments in sys.argv."""
     sys.argv = [sys.argv[0], *pyperf_args]
@@ -290,7 +313,18 @@ def main() -> None:
         "--markdown-output",
         help="Write benchmark results to a markdown report file.",
     )
+    parser.add_argument(
+        "--json-output",
+        help="Write benchmark results to a pyperf JSON file.",
+    )
+    parser.add_argument(
+        "--hot-paths",
+        action="store_true",
+        help="Select the validation hot path families guarded against regressions.",
+    )
     args, unknown = parser.parse_known_args()
+    if args.hot_paths:
+        args.family = [*(args.family or ()), *HOT_PATH_FAMILIES]
     pyperf_args = _build_pyperf_args(unknown)
     program_args = _build_script_program_args(args)
 
@@ -325,6 +359,8 @@ def main() -> None:
         _print_benchmark_results(results)
         if args.markdown_output:
             _write_markdown_report(args.markdown_output, results)
+        if args.json_output:
+            _write_json_results(args.json_output, results)
 
 
 if __name__ == "__main__":
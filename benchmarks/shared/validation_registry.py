@@ -10,6 +10,7 @@
 from collections.abc import Callable
 from typing import Any
 
+from ators import no_validation
 from benchmarks import conftest as benchmark_conftest
 from benchmarks.shared.registry_types import BenchmarkCase
 
@@ -42,6 +43,8 @@ def _make_ators_typed() -> AtorsValidatedClass:
         fixed_tuple_field=(0, 0, ""),
         frozen_set_field=frozenset(),
         optional_int_field=None,
+        union_int_none_field=None,
+        union_field=0,
         enum_like_field=1,
         constrained_int_field=0,
         custom_class_field=CustomClass(),
@@ -88,6 +91,19 @@ def _setter_op(attr_name: str, value: Any) -> Callable[[Any], Callable[[], None]
     return lambda obj: lambda: setattr(obj, attr_name, value)
 
 
+def _unvalidated_setter_op(
+    attr_name: str, value: Any
+) -> Callable[[Any], Callable[[], None]]:
+    def build(obj: Any) -> Callable[[], None]:
+        def op() -> None:
+            with no_validation():
+                setattr(obj, attr_name, value)
+
+        return op
+
+    return build
+
+
 VALIDATION_SPECS: tuple[tuple[str, str, Any, tuple[str, ...]], ...] = (
     ("validation_bool", "bool_field", True, ("py", "ators", "property", "atom")),
     ("validation_bytes", "bytes_field", b"test", ("py", "ators", "property", "atom")),
@@ -106,8 +122,18 @@ VALIDATION_SPECS: tuple[tuple[str, str, Any, tuple[str, ...]], ...] = (
         ("py", "ators", "property", "atom"),
     ),
     ("validation_float", "float_field", 3.14, ("py", "ators", "property", "atom")),
-    ("validation_int", "int_field", 42, ("py", "ators", "property", "atom")),
-    ("validation_list", "list_field", [1, 2, 3], ("py", "ators", "property", "atom")),
+    (
+        "validation_int",
+        "int_field",
+        42,
+        ("py", "ators", "ators_no_validation", "property", "atom"),
+    ),
+    (
+        "validation_list",
+        "list_field",
+        [1, 2, 3],
+        ("py", "ators", "ators_no_validation", "property", "atom"),
+    ),
     ("validation_literal", "enum_like_field", 2, ("py", "ators", "property", "atom")),
     (
         "validation_optional_int",
@@ -115,9 +141,17 @@ VALIDATION_SPECS: tuple[tuple[str, str, Any, tuple[str, ...]], ...] = (
         100,
         ("py", "ators", "property", "atom"),
     ),
+    (
+        "validation_optional_none",
+        "optional_int_field",
+        None,
+        ("py", "ators", "property", "atom"),
+    ),
     ("validation_set", "set_field", {1, 2, 3}, ("py", "ators", "property", "atom")),
     ("validation_str", "str_field", "test", ("py", "ators", "property", "atom")),
     ("validation_tuple", "tuple_field", (1, 2, 3), ("py", "ators", "property", "atom")),
+    ("validation_union", "union_field", "test", ("py", "ators", "property")),
+    ("validation_union_int_none", "union_int_none_field", 100, ("py", "ators")),
 )
 
 
@@ -145,6 +179,15 @@ def iter_validation_cases() -> list[BenchmarkCase]:
                     _setter_op(field_name, value),
                 )
             )
+        if "ators_no_validation" in implementations:
+            cases.append(
+                _make_case(
+                    family,
+                    "ators_no_validation",
+                    _make_ators_typed,
+                    _unvalidated_setter_op(field_name, value),
+                )
+            )
         if "property" in implementations:
             cases.append(
                 _make_case(
@@ -14,15 +14,25 @@ the user-facing `Ators` base class built on top of `AtorsMeta`.
 from ._ators import (
     AtorsBase as _Base,
     AtorsChange,
+    AtorsCoercionError,
+    AtorsValidationError,
+    ChangeEvent,
+    ClassMember,
     Event,
     EventCustomizationTool,
     Member,
     PicklePolicy,
+    UNSET,
     add_generic_type_attributes,
+    check_value,
+    class_member,
+    coercing_members,
+    diff,
     disable_notifications,
     enable_notifications,
     event,
     freeze,
+    from_json,
     get_event,
     get_event_customization_tool,
     get_events,
@@ -33,14 +43,30 @@ from ._ators import (
     get_members,
     get_members_by_tag,
     get_members_by_tag_and_value,
+    init_coercing_members,
     is_frozen,
     is_notifications_enabled,
+    iter_member_values,
+    json_schema,
     member,
+    no_validation,
+    notifications_enabled,
     observe,
+    register_json_codec,
     register_type_mutability_info,
+    reset_to_defaults,
+    set_instance_validator,
+    set_unchecked,
+    suppress_notifications,
+    to_dict,
+    to_json,
+    transaction,
     unobserve,
+    update_validated,
     validated,
 )
+from ._codec import codec_view
+from ._json_codecs import register_default_json_codecs as _register_default_json_codecs
 from ._meta import AtorsMeta as _Meta
 
 # Register generic type attributes for numpy ndarray if numpy is available
@@ -60,6 +86,9 @@ try:
 except ImportError:
     pass
 
+# Register the JSON codecs of the standard library types lacking a JSON counterpart
+_register_default_json_codecs()
+
 
 def __newobj__(cls, *args):
     """A compatibility pickler function.
@@ -100,15 +129,26 @@ class Ators(_Base, metaclass=_Meta):
 __all__ = [
     "Ators",
     "AtorsChange",
+    "AtorsCoercionError",
+    "AtorsValidationError",
+    "ChangeEvent",
+    "ClassMember",
     "Event",
     "EventCustomizationTool",
     "Member",
     "PicklePolicy",
+    "UNSET",
     "add_generic_type_attributes",
+    "check_value",
+    "class_member",
+    "codec_view",
+    "coercing_members",
+    "diff",
     "disable_notifications",
     "enable_notifications",
     "event",
     "freeze",
+    "from_json",
     "get_event",
     "get_event_customization_tool",
     "get_events",
@@ -119,11 +159,25 @@ __all__ = [
     "get_members",
     "get_members_by_tag",
     "get_members_by_tag_and_value",
+    "init_coercing_members",
     "is_frozen",
     "is_notifications_enabled",
+    "iter_member_values",
+    "json_schema",
     "member",
+    "no_validation",
+    "notifications_enabled",
     "observe",
+    "register_json_codec",
     "register_type_mutability_info",
+    "reset_to_defaults",
+    "set_instance_validator",
+    "set_unchecked",
+    "suppress_notifications",
+    "to_dict",
+    "to_json",
+    "transaction",
     "unobserve",
+    "update_validated",
     "validated",
 ]
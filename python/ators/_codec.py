@@ -0,0 +1,59 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Companion attribute exposing a str member as bytes or a bytes member as str."""
+
+from typing import Any
+
+
+class codec_view:
+    """Read/write view of a bytes (or str) member as str (or bytes).
+
+    Reading the view decodes the bytes (or encodes the str) stored in the member
+    using the encoding. Assigning to the view performs the reverse operation and
+    sets the member, which validates the converted value. The conversion is
+    lossless for values valid in the encoding, and encoding or decoding errors
+    are raised as UnicodeError, whose message mentions the codec.
+
+    Parameters
+    ----------
+    member_name : str
+        Name of the member storing the value.
+    encoding : str
+        Name of the codec used for the conversion.
+    errors : str, optional
+        Error handling scheme passed to ``str.encode`` and ``bytes.decode``.
+
+    """
+
+    __slots__ = ("encoding", "errors", "member_name", "name")
+
+    def __init__(self, member_name: str, encoding: str, errors: str = "strict"):
+        self.member_name = member_name
+        self.encoding = encoding
+        self.errors = errors
+        self.name = member_name
+
+    def __set_name__(self, owner: type, name: str) -> None:
+        self.name = name
+
+    def __get__(self, obj: Any, owner: type | None = None) -> Any:
+        if obj is None:
+            return self
+        return self._convert(getattr(obj, self.member_name))
+
+    def __set__(self, obj: Any, value: Any) -> None:
+        setattr(obj, self.member_name, self._convert(value))
+
+    def _convert(self, value: Any) -> Any:
+        if isinstance(value, str):
+            return value.encode(self.encoding, self.errors)
+        if isinstance(value, (bytes, bytearray)):
+            return bytes(value).decode(self.encoding, self.errors)
+        raise TypeError(
+            f"{self.name} expects a str or bytes value, got {type(value).__name__}"
+        )
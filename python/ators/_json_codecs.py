@@ -0,0 +1,36 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""JSON codecs of the standard library types lacking a JSON counterpart."""
+
+import base64
+import datetime
+import decimal
+import operator
+import uuid
+
+from ._ators import register_json_codec
+
+
+def _b64encode(value: bytes | bytearray | memoryview) -> str:
+    """Encode a bytes-like object as a base64 str."""
+    return base64.b64encode(value).decode("ascii")
+
+
+def register_default_json_codecs() -> None:
+    """Register the JSON codecs of the supported standard library types."""
+    for type_ in (datetime.datetime, datetime.date, datetime.time):
+        register_json_codec(type_, type_.isoformat, type_.fromisoformat)
+    register_json_codec(decimal.Decimal, str, decimal.Decimal)
+    register_json_codec(uuid.UUID, operator.attrgetter("hex"), uuid.UUID)
+    register_json_codec(bytes, _b64encode, base64.b64decode)
+    register_json_codec(
+        bytearray, _b64encode, lambda value: bytearray(base64.b64decode(value))
+    )
+    register_json_codec(
+        memoryview, _b64encode, lambda value: memoryview(base64.b64decode(value))
+    )
@@ -14,6 +14,7 @@ subclasses and handling generic specialization behavior.
 from typing import Any, dataclass_transform
 
 from ._ators import (
+    ClassMember,
     PicklePolicy,
     create_ators_specialized_alias as _create_ators_specialized_alias,
     create_ators_subclass as _create_ators_subclass,
@@ -21,6 +22,7 @@ from ._ators import (
     get_ators_abstract_methods as _get_ators_abstract_methods,
     get_ators_args as _get_ators_args,
     get_ators_frozen_flag as _get_ators_frozen_flag,
+    get_ators_instances as _get_ators_instances,
     get_ators_origin as _get_origin,
     get_ators_type_params as _get_ators_type_params,
     maybe_freeze_instance_after_call as _maybe_freeze_instance_after_call,
@@ -45,6 +47,26 @@ class AtorsMeta(type):
     to the metaclass constructor, instance dictionary and additional slots are
     not supported.
 
+    Live instances of a class can be tracked by passing track_instances=True
+    to the metaclass constructor, which requires weak references support. The
+    instances (including those of subclasses) can then be retrieved using
+    `instances`.
+
+    Validated class level attributes can be declared using class_member(),
+    preferably with a ClassVar annotation. Their value is stored on the class
+    (it does not consume an instance slot), is shared with subclasses and can
+    only be set on the class.
+
+    Attributes annotated on non-Ators bases (e.g. plain mixin classes) can be
+    turned into members by passing plain_base_members=True to the metaclass
+    constructor. Such members get their slot on the class being created and
+    the value assigned on the base, if any, is used as default.
+
+    Passing strict_body=True to the metaclass constructor rejects the class body
+    entries which are not members, methods (or other descriptors and callables),
+    dunders or attributes annotated with ClassVar, in order to catch stray
+    assignments and typos. The check only applies to the class being created.
+
     """
 
     __ators_frozen__: bool
@@ -62,6 +84,9 @@ class AtorsMeta(type):
         type_containers: int = -1,
         pickle_policy: PicklePolicy | None = None,
         validate_attr: bool = True,
+        track_instances: bool = False,
+        plain_base_members: bool = False,
+        strict_body: bool = False,
     ):
         # Ensure there is no weird mro calculation and that we can use our
         # re-implementation of C3
@@ -78,11 +103,30 @@ class AtorsMeta(type):
             type_containers,
             pickle_policy,
             validate_attr,
+            track_instances,
+            plain_base_members,
+            strict_body,
         )
 
+    def __setattr__(cls, name: str, value: Any) -> None:
+        # Route assignments to class members defined on the class or its bases
+        # to the member so that the value is validated.
+        for c in cls.__mro__:
+            if name in c.__dict__:
+                attr = c.__dict__[name]
+                if isinstance(attr, ClassMember):
+                    attr.set_value(value)
+                    return
+                break
+        super().__setattr__(name, value)
+
     def __call__(self, *args, **kwds):
         return _maybe_freeze_instance_after_call(super().__call__(*args, **kwds))
 
+    def instances(cls) -> list[Any]:
+        """Return the live instances of the class and of its subclasses."""
+        return _get_ators_instances(cls)
+
     @property
     def __abstractmethods__(cls) -> frozenset:
         return _get_ators_abstract_methods(cls)
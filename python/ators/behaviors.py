@@ -29,8 +29,10 @@ if TYPE_CHECKING:
     from . import Ators
 
 from ators._ators import (
+    ChangeEvent,
     DefaultBehavior as Default,
     DelattrBehavior as DelAttr,
+    ObserverBehavior as Observer,
     PostGetattrBehavior as PostGetAttr,
     PostSetattrBehavior as PostSetAttr,
     PreGetattrBehavior as PreGetAttr,
@@ -38,7 +40,7 @@ from ators._ators import (
     member,
 )
 
-from .validators import Coercer, ValueValidator
+from .validators import Coerce, Coercer, Normalizer, ValueValidator
 
 # Reporting the error at call site is sufficient since users will be pointed
 # to exact problematic behavior.
@@ -140,7 +142,7 @@ def default(
 
 
 def preget(
-    member_builder: member,
+    member_builder: member, *, compute: bool = False
 ) -> Callable[[Callable[[Ators, member], Any]], Callable[[Ators, member], Any]]:
     """Return a decorator that registers a pre-get hook.
 
@@ -152,6 +154,9 @@ def preget(
     ----------
     member_builder : ators._ators.member
         The member builder to which the pre-get hook will be attached.
+    compute : bool, optional
+        If True, the value returned by the method is used as the member value
+        and the value stored on the object (or its default) is not accessed.
 
     Returns
     -------
@@ -166,7 +171,10 @@ def preget(
     ) -> Callable[[Ators, member], Any]:
         st = inspect.stack(1)
         _validate_use_and_sig(st, "preget", func, ("self", "member"))
-        member_builder.preget(PreGetAttr.ObjectMethod(func.__name__))
+        if compute:
+            member_builder.preget(PreGetAttr.ComputeObjectMethod(func.__name__))
+        else:
+            member_builder.preget(PreGetAttr.ObjectMethod(func.__name__))
         return func
 
     return decorator
@@ -277,6 +285,43 @@ def postset(
     return decorator
 
 
+def observer(
+    member_builder: member,
+) -> Callable[
+    [Callable[[Ators, ChangeEvent], Any]], Callable[[Ators, ChangeEvent], Any]
+]:
+    """Return a decorator that registers a static observer.
+
+    The decorated method must have the signature ``(self, change)`` and is
+    invoked with a ``ChangeEvent`` after a new value is stored by an explicit
+    assignment or the value is deleted, when notifications are enabled on the
+    object. Contrary to the observers registered using ``observe``, it is
+    shared by all instances.
+
+    Parameters
+    ----------
+    member_builder : ators._ators.member
+        The member builder to which the static observer will be attached.
+
+    Returns
+    -------
+    callable
+        A decorator that registers the static observer and returns the
+        original function.
+
+    """
+
+    def decorator(
+        func: Callable[[Ators, ChangeEvent], Any],
+    ) -> Callable[[Ators, ChangeEvent], Any]:
+        st = inspect.stack(1)
+        _validate_use_and_sig(st, "observer", func, ("self", "change"))
+        member_builder.observe(Observer.ObjectMethod(func.__name__))
+        return func
+
+    return decorator
+
+
 def coerce(
     member_builder: member,
 ) -> Callable[
@@ -398,15 +443,94 @@ def append_value_validator(
     return decorator
 
 
+def append_normalizer(
+    member_builder: member,
+) -> Callable[
+    [Callable[[Ators, member, Any], Any]], Callable[[Ators, member, Any], Any]
+]:
+    """Return a decorator that appends a normalizer to a member.
+
+    The decorated method must have the signature ``(self, member, value)``.
+    The method will be wrapped as an object-method normalizer and
+    appended to the member's normalizers. Normalizers receive the value once
+    its type has been validated and return the value to store.
+
+    Parameters
+    ----------
+    member_builder : ators._ators.member
+        The member builder to which the normalizer will be appended.
+
+    Returns
+    -------
+    callable
+        A decorator that appends the normalizer and returns the original
+        function.
+
+    """
+
+    def decorator(
+        func: Callable[[Ators, member, Any], Any],
+    ) -> Callable[[Ators, member, Any], Any]:
+        st = inspect.stack(1)
+        _validate_use_and_sig(
+            st, "append_normalizer", func, ("self", "member", "value")
+        )
+        member_builder.append_normalizer(Normalizer.ObjectMethod(func.__name__))
+        return func
+
+    return decorator
+
+
+def invariant(
+    *member_names: str,
+) -> Callable[[Callable[[Ators], Any]], Callable[[Ators], Any]]:
+    """Return a decorator declaring an invariant spanning several members.
+
+    The decorated method must have the signature ``(self)`` and either raise
+    or return a false value (other than None) when the invariant does not
+    hold. The invariant is checked once an instance is initialized and after
+    any of the named members changes, the change being reverted if the
+    invariant is violated.
+
+    Parameters
+    ----------
+    *member_names : str
+        Names of the members involved in the invariant. They are checked to
+        exist when the class is created.
+
+    Returns
+    -------
+    callable
+        A decorator that marks the method as an invariant and returns the
+        original function.
+
+    """
+    if not member_names or not all(isinstance(n, str) for n in member_names):
+        raise TypeError("'invariant' expects one or more member names as str.")
+
+    def decorator(func: Callable[[Ators], Any]) -> Callable[[Ators], Any]:
+        st = inspect.stack(1)
+        _validate_use_and_sig(st, "invariant", func, ("self",))
+        func.__ators_invariant__ = member_names  # type: ignore[attr-defined]
+        return func
+
+    return decorator
+
+
 __all__ = [
+    "Coerce",
     "Default",
     "DelAttr",
+    "Observer",
     "PostGetAttr",
     "PostSetAttr",
     "PreGetAttr",
     "PreSetAttr",
+    "append_normalizer",
     "append_value_validator",
     "default",
+    "invariant",
+    "observer",
     "postget",
     "postset",
     "preget",
@@ -7,10 +7,30 @@
 # --------------------------------------------------------------------------------------
 """Validator and coercer re-exports for Ators.
 
-This module provides the public Python names for value/type validators and
-coercion strategies implemented in the Rust extension.
+This module provides the public Python names for value/type validators,
+normalizers and coercion strategies implemented in the Rust extension, and
+the helper building the validator matching a type annotation.
 """
 
-from ators._ators import Coercer, TypeValidator, ValueValidator
+from ators._ators import (
+    Coercer,
+    Normalizer,
+    TypeValidator,
+    Validator,
+    ValueValidator,
+    validator_for,
+)
 
-__all__ = ["Coercer", "TypeValidator", "ValueValidator"]
+#: Marker enabling the type inferred coercion of a member when found in the
+#: metadata of its annotation, e.g. ``x: Annotated[int, Coerce]``.
+Coerce = Coercer.TypeInferred()
+
+__all__ = [
+    "Coerce",
+    "Coercer",
+    "Normalizer",
+    "TypeValidator",
+    "Validator",
+    "ValueValidator",
+    "validator_for",
+]
@@ -7,12 +7,13 @@
 |----------------------------------------------------------------------------*/
 /// Tools to manipulate and extract information from type annotations.
 use pyo3::{
-    Bound, PyAny, PyErr, PyResult, PyTypeInfo, Python, intern, pyclass,
+    Bound, PyAny, PyErr, PyResult, Python, intern, pyclass, pyfunction,
     sync::critical_section::with_critical_section,
     types::{
-        PyAnyMethods, PyBool, PyBytes, PyComplex, PyDict, PyDictMethods, PyFloat, PyFrozenSet,
-        PyInt, PyList, PyListMethods, PyMapping, PyMappingMethods, PySet, PyString,
-        PyStringMethods, PyTuple, PyTupleMethods, PyType, PyTypeMethods,
+        PyAnyMethods, PyBool, PyByteArray, PyBytes, PyComplex, PyDict, PyDictMethods, PyFloat,
+        PyFrozenSet, PyInt, PyList, PyListMethods, PyMapping, PyMappingMethods, PyMemoryView,
+        PyModuleMethods, PySet, PyString, PyStringMethods, PyTuple, PyTupleMethods, PyType,
+        PyTypeMethods,
     },
 };
 use std::collections::HashMap;
@@ -21,10 +22,13 @@ use std::ffi::CString;
 use crate::{
     event::EventBuilder,
     get_generic_attributes_map,
-    member::{DefaultBehavior, DelattrBehavior, Member, MemberBuilder, PreSetattrBehavior},
-    utils::err_with_cause,
+    member::{
+        ClassMemberBuilder, DefaultBehavior, DelattrBehavior, Member, MemberBuilder,
+        PreSetattrBehavior,
+    },
+    utils::{err_with_cause, get_decimal_type},
     validators::{
-        TypeValidator, ValidValues, Validator, ValueValidator,
+        Coercer, LiteralValues, RangeBound, TypeValidator, Validator, ValueValidator,
         types::{BoxedValidator, LateResolvedValidator},
     },
 };
@@ -47,8 +51,11 @@ impl ValidatorBuildInfo {
 pub(crate) struct PyTypes<'py> {
     object: Bound<'py, PyAny>,
     any: Bound<'py, PyAny>,
+    never: Bound<'py, PyAny>,
+    no_return: Bound<'py, PyAny>,
     class_var: Bound<'py, PyAny>,
     final_: Bound<'py, PyAny>,
+    annotated: Bound<'py, PyAny>,
     union_: Bound<'py, PyAny>,
     type_var: Bound<'py, PyAny>,
     new_type: Bound<'py, PyAny>,
@@ -56,8 +63,19 @@ pub(crate) struct PyTypes<'py> {
     literal: Bound<'py, PyAny>,
     type_alias: Bound<'py, PyAny>,
     unpack: Bound<'py, PyAny>,
-    // sequence: Bound<'py, PyAny>,
-    // mapping: Bound<'py, PyAny>,
+    required: Bound<'py, PyAny>,
+    not_required: Bound<'py, PyAny>,
+    read_only: Bound<'py, PyAny>,
+    flag: Bound<'py, PyAny>,
+    enum_: Bound<'py, PyAny>,
+    callable: Bound<'py, PyAny>,
+    buffer: Bound<'py, PyAny>,
+    iterable: Bound<'py, PyAny>,
+    iterator: Bound<'py, PyAny>,
+    sequence: Bound<'py, PyAny>,
+    mapping: Bound<'py, PyAny>,
+    /// The deprecated typing and collections.abc ByteString, when they exist.
+    byte_strings: Vec<Bound<'py, PyAny>>,
     // FIXME defaultdict
 }
 
@@ -65,6 +83,8 @@ pub(crate) struct PyTypes<'py> {
 pub(crate) struct TypeTools<'py> {
     get_origin: Bound<'py, PyAny>,
     get_args: Bound<'py, PyAny>,
+    is_typeddict: Bound<'py, PyAny>,
+    get_annotations: Bound<'py, PyAny>,
     call_evaluate_function: Bound<'py, PyAny>,
     forwardref_format: Bound<'py, PyAny>,
     types: PyTypes<'py>,
@@ -74,6 +94,8 @@ pub(crate) fn get_type_tools<'py>(py: Python<'py>) -> Result<TypeTools<'py>, PyE
     let annotationlib = py.import(intern!(py, "annotationlib"))?;
 
     let builtins_mod = py.import(intern!(py, "builtins"))?;
+    let collections_abc_mod = py.import(intern!(py, "collections.abc"))?;
+    let enum_mod = py.import(intern!(py, "enum"))?;
     let types_mod = py.import(intern!(py, "types"))?;
     let typing_mod = py.import(intern!(py, "typing"))?;
 
@@ -88,6 +110,8 @@ pub(crate) fn get_type_tools<'py>(py: Python<'py>) -> Result<TypeTools<'py>, PyE
     Ok(TypeTools {
         get_args: typing_mod.getattr(intern!(py, "get_args"))?,
         get_origin: typing_mod.getattr(intern!(py, "get_origin"))?,
+        is_typeddict: typing_mod.getattr(intern!(py, "is_typeddict"))?,
+        get_annotations: annotationlib.getattr(intern!(py, "get_annotations"))?,
         call_evaluate_function: annotationlib.getattr(intern!(py, "call_evaluate_function"))?,
         forwardref_format: annotationlib
             .getattr(intern!(py, "Format"))?
@@ -95,8 +119,11 @@ pub(crate) fn get_type_tools<'py>(py: Python<'py>) -> Result<TypeTools<'py>, PyE
         types: PyTypes {
             object: builtins_mod.getattr(intern!(py, "object"))?,
             any: typing_mod.getattr(intern!(py, "Any"))?,
+            never: typing_mod.getattr(intern!(py, "Never"))?,
+            no_return: typing_mod.getattr(intern!(py, "NoReturn"))?,
             class_var: typing_mod.getattr(intern!(py, "ClassVar"))?,
             final_: typing_mod.getattr(intern!(py, "Final"))?,
+            annotated: typing_mod.getattr(intern!(py, "Annotated"))?,
             union_: types_mod.getattr(intern!(py, "UnionType"))?,
             type_var: typing_mod.getattr(intern!(py, "TypeVar"))?,
             new_type: typing_mod.getattr(intern!(py, "NewType"))?,
@@ -104,8 +131,24 @@ pub(crate) fn get_type_tools<'py>(py: Python<'py>) -> Result<TypeTools<'py>, PyE
             literal: typing_mod.getattr(intern!(py, "Literal"))?,
             type_alias: typing_mod.getattr(intern!(py, "TypeAliasType"))?,
             unpack: typing_mod.getattr(intern!(py, "Unpack"))?,
-            // sequence: builtins_mod.getattr(intern!(py, "tuple"))?,
-            // mapping: builtins_mod.getattr(intern!(py, "tuple"))?,
+            required: typing_mod.getattr(intern!(py, "Required"))?,
+            not_required: typing_mod.getattr(intern!(py, "NotRequired"))?,
+            read_only: typing_mod.getattr(intern!(py, "ReadOnly"))?,
+            flag: enum_mod.getattr(intern!(py, "Flag"))?,
+            enum_: enum_mod.getattr(intern!(py, "Enum"))?,
+            callable: collections_abc_mod.getattr(intern!(py, "Callable"))?,
+            buffer: collections_abc_mod.getattr(intern!(py, "Buffer"))?,
+            iterable: collections_abc_mod.getattr(intern!(py, "Iterable"))?,
+            iterator: collections_abc_mod.getattr(intern!(py, "Iterator"))?,
+            sequence: collections_abc_mod.getattr(intern!(py, "Sequence"))?,
+            mapping: collections_abc_mod.getattr(intern!(py, "Mapping"))?,
+            // Look ByteString up in the module namespaces to avoid triggering
+            // the deprecation warning emitted on attribute access.
+            byte_strings: [&typing_mod, &collections_abc_mod]
+                .into_iter()
+                .map(|m| m.dict().get_item(intern!(py, "ByteString")))
+                .filter_map(|r| r.transpose())
+                .collect::<PyResult<Vec<_>>>()?,
         },
     })
 }
@@ -168,6 +211,23 @@ pub fn build_validator_from_annotation<'py>(
             ctx_provider,
             typevar_bindings,
         );
+    } else if ann.is(&tools.types.buffer) || tools.types.byte_strings.iter().any(|b| ann.is(b)) {
+        // Checked before the origin since typing.ByteString is a generic alias.
+        return Ok((
+            Validator::new(
+                TypeValidator::Buffer {
+                    format: None,
+                    min_size: None,
+                    max_size: None,
+                },
+                None,
+                None,
+                None,
+            ),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ));
     }
 
     let py = name.py();
@@ -177,6 +237,33 @@ pub fn build_validator_from_annotation<'py>(
     // some generics such as Literal use specific private classes.
     let origin = tools.get_origin.call1((&ann,))?;
 
+    // The annotated-types constraints of nested Annotated types (such as the
+    // items of a container) apply to the values validated by the nested
+    // validator. Top-level metadata are handled by the callers.
+    if origin.is(&tools.types.annotated) {
+        let (validator, info) = build_validator_from_annotation(
+            name,
+            &ann.getattr(intern!(py, "__origin__"))?,
+            type_containers,
+            tools,
+            ctx_provider,
+            typevar_bindings,
+        )?;
+        let constraints = annotated_value_validators(&ann)?;
+        if constraints.is_empty() {
+            return Ok((validator, info));
+        }
+        return Ok((
+            Validator {
+                value_validators: [validator.value_validators.as_ref(), constraints.as_slice()]
+                    .concat()
+                    .into_boxed_slice(),
+                ..validator
+            },
+            info,
+        ));
+    }
+
     // In 3.14, Union[int, float] and int | float share the same type
     if !origin.is_none() {
         // FIXME extract in a dedicated function since it will be expanded
@@ -186,13 +273,8 @@ pub fn build_validator_from_annotation<'py>(
             Ok((
                 Validator::new(
                     TypeValidator::Any {},
-                    Some(vec![ValueValidator::Values {
-                        values: ValidValues(
-                            PyFrozenSet::type_object(py)
-                                .call1((args,))?
-                                .cast_into()?
-                                .unbind(),
-                        ),
+                    Some(vec![ValueValidator::Literal {
+                        values: LiteralValues::new(&args)?,
                     }]),
                     None,
                     None,
@@ -243,6 +325,7 @@ pub fn build_validator_from_annotation<'py>(
                     Validator::new(
                         TypeValidator::VarTuple {
                             item: Some(BoxedValidator::from(item_validator)),
+                            allow_subclasses: false,
                         },
                         None,
                         None,
@@ -269,7 +352,15 @@ pub fn build_validator_from_annotation<'py>(
                     items.push(item_validator);
                 }
                 Ok((
-                    Validator::new(TypeValidator::Tuple { items }, None, None, None),
+                    Validator::new(
+                        TypeValidator::Tuple {
+                            items,
+                            allow_subclasses: false,
+                        },
+                        None,
+                        None,
+                        None,
+                    ),
                     ValidatorBuildInfo { requires_owner },
                 ))
             }
@@ -320,7 +411,7 @@ pub fn build_validator_from_annotation<'py>(
                 Validator::new(TypeValidator::Set { item: item_val }, None, None, None),
                 ValidatorBuildInfo { requires_owner },
             ))
-        } else if origin.is(py.get_type::<PyList>()) {
+        } else if origin.is(py.get_type::<PyList>()) || origin.is(&tools.types.sequence) {
             let (item_val, requires_owner) = if let Ok(item_arg) = args.get_item(0) {
                 let (item_validator, item_info) = build_validator_from_annotation(
                     PyString::new(py, &format!("{name}-item")).cast()?,
@@ -337,11 +428,17 @@ pub fn build_validator_from_annotation<'py>(
             } else {
                 (None, false)
             };
+            // Abstract sequences are validated in place rather than copied.
+            let type_validator = if origin.is(&tools.types.sequence) {
+                TypeValidator::Sequence { item: item_val }
+            } else {
+                TypeValidator::List { item: item_val }
+            };
             Ok((
-                Validator::new(TypeValidator::List { item: item_val }, None, None, None),
+                Validator::new(type_validator, None, None, None),
                 ValidatorBuildInfo { requires_owner },
             ))
-        } else if origin.is(py.get_type::<PyDict>()) {
+        } else if origin.is(py.get_type::<PyDict>()) || origin.is(&tools.types.mapping) {
             let (items_validator, requires_owner) = if let Ok((key_arg, val_arg)) = args.extract() {
                 let (key_validator, key_info) = build_validator_from_annotation(
                     PyString::new(py, &format!("{name}-key")).cast()?,
@@ -369,18 +466,54 @@ pub fn build_validator_from_annotation<'py>(
             } else {
                 (None, false)
             };
+            let type_validator = if origin.is(&tools.types.mapping) {
+                TypeValidator::Mapping {
+                    items: items_validator,
+                }
+            } else {
+                TypeValidator::Dict {
+                    items: items_validator,
+                }
+            };
             Ok((
-                Validator::new(
-                    TypeValidator::Dict {
-                        items: items_validator,
-                    },
-                    None,
-                    None,
-                    None,
-                ),
+                Validator::new(type_validator, None, None, None),
                 ValidatorBuildInfo { requires_owner },
             ))
         } else if origin.is(&tools.types.union_) {
+            // Unions of None and a single other type are common enough to use a
+            // dedicated validator accepting None without trying the other type.
+            let none_type = py.None().into_bound(py).get_type();
+            if args.len() == 2 {
+                let (first, second) = (args.get_item(0)?, args.get_item(1)?);
+                let other = if second.is(&none_type) {
+                    Some(first)
+                } else if first.is(&none_type) {
+                    Some(second)
+                } else {
+                    None
+                };
+                if let Some(other) = other {
+                    let (inner, info) = build_validator_from_annotation(
+                        name,
+                        &other,
+                        type_containers,
+                        tools,
+                        ctx_provider,
+                        typevar_bindings,
+                    )?;
+                    return Ok((
+                        Validator::new(
+                            TypeValidator::Optional {
+                                inner: inner.into(),
+                            },
+                            None,
+                            None,
+                            None,
+                        ),
+                        info,
+                    ));
+                }
+            }
             // FIXME: low priority
             // merge Typed/Instance together if relevant
             let mut members = Vec::new();
@@ -401,6 +534,67 @@ pub fn build_validator_from_annotation<'py>(
                 Validator::new(TypeValidator::Union { members }, None, None, None),
                 ValidatorBuildInfo { requires_owner },
             ))
+        } else if origin.is(&tools.types.callable) {
+            // The last argument is the return type, and the first one the list
+            // of parameters whose length is the arity. Ellipsis, ParamSpec and
+            // Concatenate do not constrain the arity.
+            let returns = if args.is_empty() {
+                None
+            } else {
+                Some(args.get_item(args.len() - 1)?.unbind())
+            };
+            let arity = match args.get_item(0) {
+                Ok(params) if args.len() == 2 => {
+                    params.cast::<PyList>().ok().map(|params| params.len())
+                }
+                _ => None,
+            };
+            Ok((
+                Validator::new(
+                    TypeValidator::Callable {
+                        returns,
+                        check_returns: false,
+                        arity,
+                    },
+                    None,
+                    None,
+                    None,
+                ),
+                ValidatorBuildInfo {
+                    requires_owner: false,
+                },
+            ))
+        } else if origin.is(&tools.types.iterable) || origin.is(&tools.types.iterator) {
+            let (item_val, requires_owner) = if let Ok(item_arg) = args.get_item(0) {
+                let (item_validator, item_info) = build_validator_from_annotation(
+                    PyString::new(py, &format!("{name}-item")).cast()?,
+                    &item_arg,
+                    type_containers,
+                    tools,
+                    ctx_provider,
+                    typevar_bindings,
+                )?;
+                (
+                    Some(BoxedValidator::from(item_validator)),
+                    item_info.requires_owner,
+                )
+            } else {
+                (None, false)
+            };
+            // Iterables are validated lazily by default since materializing
+            // them would consume single use iterables.
+            let type_validator = if origin.is(&tools.types.iterable) {
+                TypeValidator::Iterable {
+                    item: item_val,
+                    materialize: false,
+                }
+            } else {
+                TypeValidator::Iterator { item: item_val }
+            };
+            Ok((
+                Validator::new(type_validator, None, None, None),
+                ValidatorBuildInfo { requires_owner },
+            ))
         } else if origin.is(&tools.types.unpack) {
             Err(pyo3::exceptions::PyTypeError::new_err("Unsupported Unpack")) // FIXME
         } else {
@@ -471,8 +665,11 @@ pub fn build_validator_from_annotation<'py>(
             }
         }
     } else if ann.is_instance(&tools.types.type_var)? {
+        // A TypeVar left unresolved by a partial specialization may be bound to
+        // itself (e.g. `A[int, U]` for `class A(Ators, Generic[T, U])`).
         if let Some(bindings) = typevar_bindings
             && let Some(bound_ann) = bindings.get_item(&ann)?
+            && !bound_ann.is(&ann)
         {
             return build_validator_from_annotation(
                 name,
@@ -544,6 +741,13 @@ pub fn build_validator_from_annotation<'py>(
                 requires_owner: false,
             },
         ))
+    } else if ann.is(&tools.types.never) || ann.is(&tools.types.no_return) {
+        Ok((
+            Validator::new(TypeValidator::Never {}, None, None, None),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
     } else if ann.is(py.get_type::<PyBool>()) {
         Ok((
             Validator::new(TypeValidator::Bool {}, None, None, None),
@@ -565,6 +769,13 @@ pub fn build_validator_from_annotation<'py>(
                 requires_owner: false,
             },
         ))
+    } else if ann.is(get_decimal_type(py)) {
+        Ok((
+            Validator::new(TypeValidator::Decimal {}, None, None, None),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
     } else if ann.is(py.get_type::<PyComplex>()) {
         Ok((
             Validator::new(TypeValidator::Complex {}, None, None, None),
@@ -579,6 +790,20 @@ pub fn build_validator_from_annotation<'py>(
                 requires_owner: false,
             },
         ))
+    } else if ann.is(py.get_type::<PyByteArray>()) {
+        Ok((
+            Validator::new(TypeValidator::ByteArray {}, None, None, None),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
+    } else if ann.is(py.get_type::<PyMemoryView>()) {
+        Ok((
+            Validator::new(TypeValidator::MemoryView {}, None, None, None),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
     } else if ann.is(py.get_type::<PyString>()) {
         Ok((
             Validator::new(TypeValidator::Str {}, None, None, None),
@@ -586,13 +811,98 @@ pub fn build_validator_from_annotation<'py>(
                 requires_owner: false,
             },
         ))
+    } else if let Ok(flag_type) = ann.cast::<PyType>()
+        && flag_type.is_subclass(&tools.types.flag)?
+    {
+        Ok((
+            Validator::new(
+                TypeValidator::FlagEnum {
+                    enum_type: flag_type.clone().unbind(),
+                },
+                None,
+                None,
+                None,
+            ),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
+    } else if let Ok(enum_type) = ann.cast::<PyType>()
+        && enum_type.is_subclass(&tools.types.enum_)?
+    {
+        Ok((
+            Validator::new(
+                TypeValidator::Enum {
+                    enum_type: enum_type.clone().unbind(),
+                },
+                None,
+                None,
+                None,
+            ),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
     } else if ann.is(py.get_type::<PyTuple>()) {
         Ok((
-            Validator::new(TypeValidator::VarTuple { item: None }, None, None, None),
+            Validator::new(
+                TypeValidator::VarTuple {
+                    item: None,
+                    allow_subclasses: false,
+                },
+                None,
+                None,
+                None,
+            ),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
+    } else if ann.is(&tools.types.iterable) {
+        Ok((
+            Validator::new(
+                TypeValidator::Iterable {
+                    item: None,
+                    materialize: false,
+                },
+                None,
+                None,
+                None,
+            ),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
+    } else if ann.is(&tools.types.iterator) {
+        Ok((
+            Validator::new(TypeValidator::Iterator { item: None }, None, None, None),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
+    } else if ann.is(&tools.types.sequence) {
+        Ok((
+            Validator::new(TypeValidator::Sequence { item: None }, None, None, None),
             ValidatorBuildInfo {
                 requires_owner: false,
             },
         ))
+    } else if ann.is(&tools.types.mapping) {
+        Ok((
+            Validator::new(TypeValidator::Mapping { items: None }, None, None, None),
+            ValidatorBuildInfo {
+                requires_owner: false,
+            },
+        ))
+    } else if tools.is_typeddict.call1((&ann,))?.is_truthy()? {
+        build_typed_dict_validator(
+            name,
+            &ann,
+            type_containers,
+            tools,
+            ctx_provider,
+            typevar_bindings,
+        )
     } else if ann.is(py.get_type::<PyType>()) {
         // Bare type annotation (not type[X]) - accept any type object (subclass of object)
         let object_type = tools.types.object.clone().cast_into::<PyType>()?;
@@ -625,6 +935,81 @@ pub fn build_validator_from_annotation<'py>(
     }
 }
 
+/// Build a validator for a TypedDict class.
+///
+/// Each field is required if the class `__required_keys__` says so, unless its
+/// annotation is wrapped in `Required[T]`/`NotRequired[T]` which take precedence
+/// over the class level `total`.
+fn build_typed_dict_validator<'py>(
+    name: &Bound<'py, PyString>,
+    ann: &Bound<'py, PyAny>,
+    type_containers: i64,
+    tools: &TypeTools<'py>,
+    ctx_provider: Option<&Bound<'py, PyAny>>,
+    typevar_bindings: Option<&Bound<'py, PyDict>>,
+) -> PyResult<(Validator, ValidatorBuildInfo)> {
+    let py = name.py();
+    let required_keys = ann.getattr(intern!(py, "__required_keys__"))?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(intern!(py, "format"), &tools.forwardref_format)?;
+    let annotations = tools
+        .get_annotations
+        .call((ann,), Some(&kwargs))?
+        .cast_into::<PyDict>()?;
+
+    let mut fields = Vec::with_capacity(annotations.len());
+    let mut requires_owner = false;
+    for (key, field_ann) in annotations.iter() {
+        let field_name = key.extract::<String>()?;
+        let mut field_ann = field_ann;
+        let mut required = None;
+        // Qualifiers may be nested (e.g. ReadOnly[NotRequired[int]]) so unwrap
+        // them until we reach the actual type.
+        loop {
+            let origin = tools.get_origin.call1((&field_ann,))?;
+            if origin.is(&tools.types.required) {
+                required = Some(true);
+            } else if origin.is(&tools.types.not_required) {
+                required = Some(false);
+            } else if !origin.is(&tools.types.read_only) {
+                break;
+            }
+            field_ann = tools
+                .get_args
+                .call1((&field_ann,))?
+                .cast_into::<PyTuple>()?
+                .get_item(0)?;
+        }
+        let required = match required {
+            Some(r) => r,
+            None => required_keys.contains(&key)?,
+        };
+        let (field_validator, field_info) = build_validator_from_annotation(
+            PyString::new(py, &format!("{name}-{field_name}")).cast()?,
+            &field_ann,
+            type_containers,
+            tools,
+            ctx_provider,
+            typevar_bindings,
+        )?;
+        requires_owner = requires_owner || field_info.requires_owner;
+        fields.push((field_name, field_validator, required));
+    }
+
+    Ok((
+        Validator::new(
+            TypeValidator::TypedDict {
+                type_: ann.clone().cast_into::<PyType>()?.unbind(),
+                fields,
+            },
+            None,
+            None,
+            None,
+        ),
+        ValidatorBuildInfo { requires_owner },
+    ))
+}
+
 pub fn build_function_argument_or_return_validator<'py>(
     name: &Bound<'py, PyString>,
     ann: &Bound<'py, PyAny>,
@@ -650,6 +1035,236 @@ pub fn build_function_argument_or_return_validator<'py>(
     Ok(validator)
 }
 
+/// Build the validator Ators would use for an annotation outside of a class.
+///
+/// The metadata of a top-level Annotated type contribute their coercer and
+/// annotated-types constraints, as they do for members. Forward references
+/// are resolved lazily, on first validation, in the provided namespace which
+/// is hence required when the annotation contains any.
+#[pyfunction]
+#[pyo3(signature = (annotation, *, namespace=None, type_containers=-1))]
+pub fn validator_for<'py>(
+    annotation: &Bound<'py, PyAny>,
+    namespace: Option<&Bound<'py, PyDict>>,
+    type_containers: i64,
+) -> PyResult<Validator> {
+    let py = annotation.py();
+    let tools = get_type_tools(py)?;
+    let class_var = &tools.types.class_var;
+    let origin = tools.get_origin.call1((annotation,))?;
+    if origin.is(class_var) || annotation.is(class_var) {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot build a validator for {}: ClassVar is only meaningful in a class body.",
+            annotation.repr()?
+        )));
+    }
+
+    let (coercer, value_validators, ann) = if origin.is(&tools.types.annotated) {
+        (
+            annotated_coercer(annotation, &tools)?,
+            annotated_value_validators(annotation)?,
+            annotation.getattr(intern!(py, "__origin__"))?,
+        )
+    } else {
+        (None, Vec::new(), annotation.clone())
+    };
+
+    // The namespace is copied on resolution so that the bindings of generic
+    // type variables cannot leak into it.
+    let ctx_provider = namespace
+        .map(|ns| ns.getattr(intern!(py, "copy")))
+        .transpose()?;
+    let (validator, build_info) = build_validator_from_annotation(
+        intern!(py, "value"),
+        &ann,
+        type_containers,
+        &tools,
+        ctx_provider.as_ref(),
+        None,
+    )?;
+    if build_info.requires_owner && namespace.is_none() {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot build a validator for {} which contains forward references \
+             without a namespace to resolve them in.",
+            annotation.repr()?
+        )));
+    }
+
+    Ok(Validator {
+        value_validators: [
+            validator.value_validators.as_ref(),
+            value_validators.as_slice(),
+        ]
+        .concat()
+        .into_boxed_slice(),
+        coercer: coercer.or(validator.coercer),
+        ..validator
+    })
+}
+
+/// Extract the coercer specified in the metadata of an Annotated type.
+///
+/// Returns None if the annotation is not an Annotated type or if its metadata
+/// do not contain any Coercer. Specifying multiple coercers is an error.
+fn annotated_coercer<'py>(
+    ann: &Bound<'py, PyAny>,
+    tools: &TypeTools<'py>,
+) -> PyResult<Option<Coercer>> {
+    if !tools.get_origin.call1((ann,))?.is(&tools.types.annotated) {
+        return Ok(None);
+    }
+    let mut coercer = None;
+    for m in ann.getattr(intern!(ann.py(), "__metadata__"))?.try_iter()? {
+        let m = m?;
+        if let Ok(c) = m.cast::<Coercer>() {
+            if coercer.is_some() {
+                return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Annotated type {} specifies multiple coercers.",
+                    ann.repr()?
+                )));
+            }
+            coercer = Some(c.as_any().extract()?);
+        }
+    }
+    Ok(coercer)
+}
+
+/// Extract the documentation of a PEP 727 Doc object found in the metadata of
+/// an Annotated type.
+///
+/// Doc objects (typing_extensions.Doc) are identified by their type name and
+/// documentation attribute. Other metadata are ignored. Specifying multiple
+/// Doc objects is an error.
+fn annotated_doc<'py>(ann: &Bound<'py, PyAny>) -> PyResult<Option<String>> {
+    let py = ann.py();
+    let mut doc = None;
+    for m in ann.getattr(intern!(py, "__metadata__"))?.try_iter()? {
+        let m = m?;
+        if m.get_type().name()? != "Doc" {
+            continue;
+        }
+        let Ok(documentation) = m.getattr(intern!(py, "documentation")) else {
+            continue;
+        };
+        let Ok(documentation) = documentation.extract::<String>() else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Doc object in Annotated type {} should have a str documentation, got {}.",
+                ann.repr()?,
+                documentation.repr()?
+            )));
+        };
+        if doc.is_some() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Annotated type {} specifies multiple Doc objects.",
+                ann.repr()?
+            )));
+        }
+        doc = Some(documentation);
+    }
+    Ok(doc)
+}
+
+/// Build the value validators corresponding to an annotated-types constraint
+/// marker. Unsupported markers and bounds result in an error, turned into a
+/// warning by the caller.
+fn constraint_value_validators(marker: &Bound<'_, PyAny>) -> PyResult<Vec<ValueValidator>> {
+    let py = marker.py();
+    let attr = |name: &str| -> PyResult<Option<Bound<'_, PyAny>>> {
+        let v = marker.getattr(name)?;
+        Ok(if v.is_none() { None } else { Some(v) })
+    };
+    let bound = |name: &str| -> PyResult<Option<RangeBound>> {
+        attr(name)?.map(|v| v.extract::<RangeBound>()).transpose()
+    };
+    let length = |name: &str| -> PyResult<Option<usize>> {
+        attr(name)?.map(|v| v.extract::<usize>()).transpose()
+    };
+    let mut validators = Vec::new();
+    match marker.get_type().name()?.to_str()? {
+        // Interval and the single bound markers share their attribute names.
+        "Gt" | "Ge" | "Lt" | "Le" | "Interval" => {
+            for (name, inclusive, is_min) in [
+                ("gt", false, true),
+                ("ge", true, true),
+                ("lt", false, false),
+                ("le", true, false),
+            ] {
+                if !marker.hasattr(name)? {
+                    continue;
+                }
+                if let Some(b) = bound(name)? {
+                    let (min, max) = if is_min {
+                        (Some(b), None)
+                    } else {
+                        (None, Some(b))
+                    };
+                    validators.push(ValueValidator::Range {
+                        min,
+                        max,
+                        inclusive,
+                    });
+                }
+            }
+        }
+        "MinLen" => validators.push(ValueValidator::Length {
+            min: length("min_length")?,
+            max: None,
+        }),
+        "MaxLen" => validators.push(ValueValidator::Length {
+            min: None,
+            max: length("max_length")?,
+        }),
+        "Len" => validators.push(ValueValidator::Length {
+            min: length("min_length")?,
+            max: length("max_length")?,
+        }),
+        "MultipleOf" => validators.push(ValueValidator::MultipleOf {
+            multiple_of: marker.getattr(intern!(py, "multiple_of"))?.unbind(),
+        }),
+        "Predicate" => validators.push(ValueValidator::Predicate {
+            callable: marker.getattr(intern!(py, "func"))?.extract()?,
+        }),
+        _ => {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "Unsupported constraint marker",
+            ));
+        }
+    }
+    Ok(validators)
+}
+
+/// Build the value validators corresponding to the annotated-types constraint
+/// markers found in the metadata of an Annotated type.
+///
+/// Markers are identified by their module so that the annotated-types library
+/// is not required. Markers which cannot be mapped to a value validator are
+/// ignored with a warning, other metadata are ignored silently.
+fn annotated_value_validators(ann: &Bound<'_, PyAny>) -> PyResult<Vec<ValueValidator>> {
+    let py = ann.py();
+    let mut validators = Vec::new();
+    for m in ann.getattr(intern!(py, "__metadata__"))?.try_iter()? {
+        let m = m?;
+        if m.get_type().module()? != "annotated_types" {
+            continue;
+        }
+        match constraint_value_validators(&m) {
+            Ok(vvs) => validators.extend(vvs),
+            Err(_) => PyErr::warn(
+                py,
+                &py.get_type::<pyo3::exceptions::PyUserWarning>(),
+                CString::new(format!(
+                    "Ignoring unsupported constraint {} in annotation {}",
+                    m.repr()?,
+                    ann.repr()?
+                ))?
+                .as_c_str(),
+                0,
+            )?,
+        }
+    }
+    Ok(validators)
+}
+
 fn configure_member_builder_from_annotation<'py>(
     builder: &mut MemberBuilder,
     name: &Bound<'py, PyString>,
@@ -661,6 +1276,32 @@ fn configure_member_builder_from_annotation<'py>(
 ) -> PyResult<()> {
     let origin = tools.get_origin.call1((ann,))?;
 
+    // For Annotated types, use the metadata to configure the builder and
+    // analyze the underlying type.
+    if origin.is(&tools.types.annotated) {
+        if let Some(coercer) = annotated_coercer(ann, tools)? {
+            builder.set_annotation_coercer(coercer);
+        }
+        if let Some(doc) = annotated_doc(ann)? {
+            builder.set_doc(doc);
+        }
+        let constraints = annotated_value_validators(ann)?;
+        if !constraints.is_empty() {
+            let mut vvs = builder.take_value_validators().unwrap_or_default();
+            vvs.extend(constraints);
+            builder.set_value_validators(vvs);
+        }
+        return configure_member_builder_from_annotation(
+            builder,
+            name,
+            &ann.getattr(intern!(name.py(), "__origin__"))?,
+            type_containers,
+            tools,
+            final_annotated,
+            typevar_bindings,
+        );
+    }
+
     // If the type is annotated Final, ensure the behaviors match
     // If the builder already set the member as constant we ignore it.
     // Finally ensure a member that has a ReadOnly or Constant pre set behavior
@@ -706,6 +1347,27 @@ fn configure_member_builder_from_annotation<'py>(
         };
     }
 
+    // A type validator explicitly specified using member().validate(...)
+    // bypasses the inference from the annotation. Type guards are expected to
+    // be paired with the annotation of the guarded type and do not warn.
+    if let Some(tv) = builder.type_validator() {
+        if !matches!(tv, TypeValidator::Guard { .. }) {
+            PyErr::warn(
+                name.py(),
+                &name.py().get_type::<pyo3::exceptions::PyUserWarning>(),
+                CString::new(format!(
+                    "Member {} specifies both an annotation ({}) and an explicit type \
+                     validator. The explicit type validator is used.",
+                    name,
+                    ann.repr()?
+                ))?
+                .as_c_str(),
+                0,
+            )?;
+        }
+        return Ok(());
+    }
+
     // Next analyze the annotation to build the validators (Final is not
     // permitted within container or generic).
     let (new, build_info) = match build_validator_from_annotation(
@@ -752,12 +1414,41 @@ fn configure_member_builder_from_annotation<'py>(
     Ok(())
 }
 
+/// Reject the class body entries which are neither annotated, dunders, member
+/// or event builders, nor callables or descriptors (methods, properties, ...).
+fn check_strict_class_body<'py>(
+    name: &Bound<'py, PyString>,
+    dct: &Bound<'py, PyDict>,
+    annotations: &Bound<'py, PyMapping>,
+) -> PyResult<()> {
+    let py = name.py();
+    for (k, v) in dct.iter() {
+        let k_str: String = k.extract()?;
+        if (k_str.starts_with("__") && k_str.ends_with("__"))
+            || annotations.contains(&k)?
+            || v.is_instance_of::<MemberBuilder>()
+            || v.is_instance_of::<EventBuilder>()
+            || v.is_instance_of::<ClassMemberBuilder>()
+            || v.is_callable()
+            || v.get_type().hasattr(intern!(py, "__get__"))?
+        {
+            continue;
+        }
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Attribute '{k_str}' of {name} is neither a member, a method nor a ClassVar \
+             annotated attribute, which is not allowed when strict_body=True."
+        )));
+    }
+    Ok(())
+}
+
 pub fn generate_member_builders_from_cls_namespace<'py>(
     name: &Bound<'py, PyString>,
     dct: &Bound<'py, PyDict>,
     type_containers: i64,
     typevar_bindings: Option<&Bound<'py, PyDict>>,
     validate_attr: bool,
+    strict_body: bool,
 ) -> PyResult<(
     HashMap<String, MemberBuilder>,
     HashMap<String, EventBuilder>,
@@ -790,6 +1481,10 @@ pub fn generate_member_builders_from_cls_namespace<'py>(
         }
     };
 
+    if strict_body {
+        check_strict_class_body(name, dct, &annotations)?;
+    }
+
     let typing_mod = py.import(intern!(py, "typing"))?;
     let class_var = typing_mod.getattr(intern!(py, "ClassVar"))?;
 
@@ -807,6 +1502,52 @@ pub fn generate_member_builders_from_cls_namespace<'py>(
         // Get the origin of the type annotation — computed once, used by both branches.
         let origin = tools.get_origin.call1((&ann,))?;
 
+        // Class members are stored on the class and do not use any slot. Their
+        // validator is built from the annotation, unwrapping ClassVar if needed.
+        if let Some(value) = dct.get_item(&attr_key)?
+            && let Ok(builder) = value.cast::<ClassMemberBuilder>()
+        {
+            let type_ann = if origin.is(&class_var) {
+                tools
+                    .get_args
+                    .call1((&ann,))?
+                    .cast_into::<PyTuple>()?
+                    .iter()
+                    .next()
+            } else if ann.is(&class_var) {
+                None
+            } else {
+                Some(ann.clone())
+            };
+            let validator = match type_ann {
+                Some(t) if validate_attr => {
+                    build_validator_from_annotation(
+                        attr_key.cast()?,
+                        &t,
+                        type_containers,
+                        &tools,
+                        None,
+                        typevar_bindings,
+                    )
+                    .map_err(|err| {
+                        err_with_cause(
+                            py,
+                            pyo3::exceptions::PyTypeError::new_err(format!(
+                                "Failed to build validator for class member {attr_key} \
+                                 from annotation {ann:?}"
+                            )),
+                            err,
+                        )
+                    })?
+                    .0
+                }
+                _ => Validator::default(),
+            };
+            let class_member = builder.get().build(py, attr_key.extract()?, validator)?;
+            dct.set_item(&attr_key, class_member)?;
+            continue;
+        }
+
         // Check we are not dealing with a ClassVar (parameterized ClassVar[T] or
         // bare ClassVar).  Both cases must be skipped even when validate_attr=False.
         if origin.is(&class_var) || ann.is(&class_var) {
@@ -912,6 +1653,10 @@ pub fn generate_member_builders_from_cls_namespace<'py>(
             }
             if let Ok(mb) = value.cast::<MemberBuilder>() {
                 mb.clone().extract()?
+            } else if value.is(py.Ellipsis()) {
+                // `x: T = ...` marks the member as required (no default)
+                // rather than defaulting to Ellipsis.
+                MemberBuilder::default()
             } else {
                 let mut mb = MemberBuilder::default();
                 mb.set_default(DefaultBehavior::Static {
@@ -968,7 +1713,9 @@ pub fn generate_member_builders_from_cls_namespace<'py>(
             effective_ann = args.get_item(0)?;
             ann_replaced = true;
         } else {
-            if has_coerce {
+            // A coercer in the metadata of an Annotated type makes the
+            // annotation a valid target for coercion.
+            if has_coerce && annotated_coercer(&ann, &tools)?.is_none() {
                 return Err(pyo3::exceptions::PyTypeError::new_err(format!(
                     "Attribute '{attr_name}': coerced RHS member requires a \
                      Member[T1, T2] annotation."
@@ -0,0 +1,117 @@
+/*-----------------------------------------------------------------------------
+| Copyright (c) 2025-2026, Ators contributors, see git history for details
+|
+| Distributed under the terms of the Modified BSD License.
+|
+| The full license is in the file LICENSE, distributed with this software.
+|----------------------------------------------------------------------------*/
+/// C API giving native extensions access to member validation.
+///
+/// The API is a table of function pointers stored in the ators._ators._C_API
+/// capsule. Fields are only ever appended to the table and the version is
+/// incremented when doing so, so that consumers can check the fields they
+/// need are available.
+use pyo3::{
+    Bound, PyAny, PyResult, Python, ffi,
+    panic::PanicException,
+    types::{PyAnyMethods, PyCapsule},
+};
+use std::{
+    ffi::{CStr, c_void},
+    panic::{AssertUnwindSafe, catch_unwind},
+    ptr::NonNull,
+};
+
+use crate::class::base::AtorsBase;
+use crate::member::Member;
+
+/// Name of the capsule, matching its location in the module namespace.
+pub(crate) const CAPSULE_NAME: &CStr = c"ators._ators._C_API";
+
+/// Version of the API table.
+pub(crate) const C_API_VERSION: u32 = 1;
+
+/// Table of the functions exposed to native extensions.
+#[repr(C)]
+pub(crate) struct AtorsCApi {
+    /// Version of the table.
+    pub version: u32,
+    /// Validate a value against a member.
+    ///
+    /// Takes borrowed references to the member, the object owning the member
+    /// (which may be NULL or None when no object is available) and the value.
+    /// Returns a new reference to the validated (and possibly coerced) value,
+    /// or NULL with an exception set. The caller must be attached to the
+    /// interpreter.
+    pub validate: unsafe extern "C" fn(
+        *mut ffi::PyObject,
+        *mut ffi::PyObject,
+        *mut ffi::PyObject,
+    ) -> *mut ffi::PyObject,
+}
+
+static C_API: AtorsCApi = AtorsCApi {
+    version: C_API_VERSION,
+    validate: validate_member_value,
+};
+
+/// Validate a value against a member, as done when setting the member but
+/// without storing the value.
+fn validate<'py>(
+    member: &Bound<'py, PyAny>,
+    object: Option<&Bound<'py, PyAny>>,
+    value: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let member = member.cast::<Member>()?.get();
+    let object = object
+        .filter(|o| !o.is_none())
+        .map(|o| o.cast::<AtorsBase>())
+        .transpose()?;
+    member
+        .validator()
+        .validate(Some(member.name()), object, value)
+}
+
+unsafe extern "C" fn validate_member_value(
+    member: *mut ffi::PyObject,
+    object: *mut ffi::PyObject,
+    value: *mut ffi::PyObject,
+) -> *mut ffi::PyObject {
+    // SAFETY: the caller is required to be attached to the interpreter.
+    let py = unsafe { Python::assume_attached() };
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        if member.is_null() || value.is_null() {
+            return Err(pyo3::exceptions::PySystemError::new_err(
+                "NULL member or value passed to the ators validate function",
+            ));
+        }
+        // SAFETY: the pointers are non-NULL borrowed references.
+        let (member, value) = unsafe {
+            (
+                Bound::from_borrowed_ptr(py, member),
+                Bound::from_borrowed_ptr(py, value),
+            )
+        };
+        let object = unsafe { Bound::from_borrowed_ptr_or_opt(py, object) };
+        validate(&member, object.as_ref(), &value)
+    }))
+    .unwrap_or_else(|_| {
+        Err(PanicException::new_err(
+            "Panic while validating a value through the ators C API",
+        ))
+    });
+    match result {
+        Ok(v) => v.into_ptr(),
+        Err(err) => {
+            err.restore(py);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Create the capsule exposing the C API.
+pub(crate) fn create_capsule(py: Python<'_>) -> PyResult<Bound<'_, PyCapsule>> {
+    let pointer = NonNull::from(&C_API).cast::<c_void>();
+    // SAFETY: the table is a static which is never mutated.
+    unsafe { PyCapsule::new_with_pointer(py, pointer, CAPSULE_NAME) }
+}
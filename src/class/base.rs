@@ -7,22 +7,29 @@
 |----------------------------------------------------------------------------*/
 /// Core Ators object and related utilities.
 use pyo3::{
-    Bound, IntoPyObjectExt, Py, PyAny, PyErr, PyResult, intern, pyclass, pyfunction, pymethods,
-    sync::critical_section::with_critical_section,
-    types::{PyAnyMethods, PyDict, PyDictMethods, PyString, PyType, PyTypeMethods},
+    Bound, IntoPyObjectExt, Py, PyAny, PyErr, PyResult, Python, intern, pyclass, pyfunction,
+    pymethods,
+    sync::{PyOnceLock, critical_section::with_critical_section},
+    types::{
+        PyAnyMethods, PyDict, PyDictMethods, PyIterator, PyList, PyString, PyStringMethods, PyType,
+        PyTypeMethods,
+    },
 };
 use std::cell::UnsafeCell;
+use std::collections::HashSet;
 
-use crate::class::info::{ClassMutability, get_class_info};
+use crate::class::info::{AtorsClassInfo, ClassMutability, get_class_info};
+use crate::errors::new_validation_error;
 use crate::event::{Event, EventCustomizationTool};
 use crate::get_type_mutability_map;
 use crate::member::{Member, MemberCustomizationTool, member_coerce_init};
 use crate::observers::{AtorsChange, ObserverPool};
 use crate::utils::Mutability;
+use crate::validators::Validator;
 
 /// Resolve the class for a given object, which may be either an instance or a class.
 #[inline]
-fn resolve_class_for_obj<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyType>> {
+pub(crate) fn resolve_class_for_obj<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyType>> {
     let cls = if let Ok(cls) = obj.cast::<PyType>() {
         cls.clone()
     } else {
@@ -51,7 +58,13 @@ struct InnerAtors {
     /// Set once at construction time and never mutated thereafter;
     /// it may therefore be read without holding the critical section.
     is_observable: bool,
+    /// Whether the class this instance belongs to declares invariants.
+    /// Set once at construction time and never mutated thereafter.
+    has_invariants: bool,
     slots: Box<[Option<Py<PyAny>>]>,
+    /// Validators specific to this instance, keyed by member name. The dict
+    /// is only allocated when the first override is set.
+    instance_validators: Option<Py<PyDict>>,
 }
 
 #[pyclass(module = "ators._ators", subclass, frozen)]
@@ -132,6 +145,73 @@ fn set_init_value_after_setattr_error<'py>(
     }
 }
 
+/// Replace the member aliases used as keys of the keyword arguments by the
+/// names of the members they refer to.
+pub(crate) fn resolve_member_aliases<'py>(
+    kwargs: &Bound<'py, PyDict>,
+    class_info: &crate::class::info::AtorsClassInfo,
+) -> PyResult<Bound<'py, PyDict>> {
+    let aliases = class_info.member_aliases();
+    if aliases.is_empty() {
+        return Ok(kwargs.clone());
+    }
+    let py = kwargs.py();
+    let resolved = PyDict::new(py);
+    for (key, value) in kwargs.iter() {
+        let name = match key.extract::<&str>().ok().and_then(|k| aliases.get(k)) {
+            Some(name) => name.bind(py).clone().into_any(),
+            None => key.clone(),
+        };
+        if resolved.contains(&name)? || (!name.is(&key) && kwargs.contains(&name)?) {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Got multiple values for member '{name}' (passed as '{key}')"
+            )));
+        }
+        resolved.set_item(name, value)?;
+    }
+    Ok(resolved)
+}
+
+/// Set the init members of an object from the keyword arguments passed to __init__.
+fn set_init_values<'py>(slf: &Bound<'py, AtorsBase>, kwargs: &Bound<'py, PyDict>) -> PyResult<()> {
+    let class_info = get_class_info(&slf.get_type())?;
+    let kwargs = &resolve_member_aliases(kwargs, &class_info)?;
+
+    let mut consumed = 0usize;
+    for required_name in class_info.required_init_member_names() {
+        let required_key = required_name.bind(slf.py());
+        if let Some(value) = kwargs.get_item(required_key)? {
+            if let Err(err) = slf.setattr(required_key, value.clone()) {
+                set_init_value_after_setattr_error(slf, &class_info, required_key, &value, err)?;
+            }
+            consumed += 1;
+        } else {
+            return Err(init_kwargs_error(kwargs, &class_info));
+        }
+    }
+    if consumed != kwargs.len() {
+        for optional_name in class_info.optional_init_member_names() {
+            let optional_key = optional_name.bind(slf.py());
+            if let Some(value) = kwargs.get_item(optional_key)? {
+                if let Err(err) = slf.setattr(optional_key, value.clone()) {
+                    set_init_value_after_setattr_error(
+                        slf,
+                        &class_info,
+                        optional_key,
+                        &value,
+                        err,
+                    )?;
+                }
+                consumed += 1;
+            }
+        }
+    }
+    if consumed != kwargs.len() {
+        return Err(init_kwargs_error(kwargs, &class_info));
+    }
+    Ok(())
+}
+
 #[pymethods]
 impl AtorsBase {
     #[new]
@@ -166,6 +246,16 @@ impl AtorsBase {
         // We can revisit this later if needed.
         let total_slots = slots_count + usize::from(is_observable);
         let mut slots: Box<[Option<Py<PyAny>>]> = (0..total_slots).map(|_| None).collect();
+        // Catch layout regressions (for example when inheritance reshuffles the
+        // slot indexes) early on, rather than through an out of bounds access.
+        debug_assert!(
+            class_info
+                .members_by_name_ref(py)
+                .iter()
+                .all(|(_, m)| usize::from(m.get().index()) < slots.len()),
+            "A member of {cls} has a slot index exceeding the {} slots of its instances",
+            slots.len()
+        );
         if is_observable {
             let pool = Bound::new(py, ObserverPool::new())?.into_any().unbind();
             slots[0] = Some(pool);
@@ -175,58 +265,37 @@ impl AtorsBase {
                 frozen: false,
                 notification_enabled: is_observable,
                 is_observable,
+                has_invariants: !class_info.invariants().is_empty(),
                 slots,
+                instance_validators: None,
             }),
         })
     }
 
+    /// Set the members from the keyword arguments and then call the
+    /// __post_init__ method of the object, if any, so that it can inspect the
+    /// complete state of the object (and raise to abort the construction).
+    ///
+    /// Invariants are not checked while the members are set and are all
+    /// checked once __post_init__ has run.
     #[pyo3(signature = (**kwargs))]
     pub fn __init__(
         slf: &Bound<'_, AtorsBase>,
         kwargs: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let class_info = get_class_info(&slf.get_type())?;
-        let Some(kwargs) = kwargs else {
-            return Ok(());
-        };
-
-        let mut consumed = 0usize;
-        for required_name in class_info.required_init_member_names() {
-            let required_key = required_name.bind(slf.py());
-            if let Some(value) = kwargs.get_item(required_key)? {
-                if let Err(err) = slf.setattr(required_key, value.clone()) {
-                    set_init_value_after_setattr_error(
-                        slf,
-                        &class_info,
-                        required_key,
-                        &value,
-                        err,
-                    )?;
-                }
-                consumed += 1;
-            } else {
-                return Err(init_kwargs_error(kwargs, &class_info));
+        let has_invariants = instance_has_invariants(slf);
+        {
+            let _guard = has_invariants.then(|| InitializationGuard::enter(slf));
+            if let Some(kwargs) = kwargs {
+                set_init_values(slf, kwargs)?;
             }
-        }
-        if consumed != kwargs.len() {
-            for optional_name in class_info.optional_init_member_names() {
-                let optional_key = optional_name.bind(slf.py());
-                if let Some(value) = kwargs.get_item(optional_key)? {
-                    if let Err(err) = slf.setattr(optional_key, value.clone()) {
-                        set_init_value_after_setattr_error(
-                            slf,
-                            &class_info,
-                            optional_key,
-                            &value,
-                            err,
-                        )?;
-                    }
-                    consumed += 1;
-                }
+            let py = slf.py();
+            if slf.get_type().hasattr(intern!(py, "__post_init__"))? {
+                slf.call_method0(intern!(py, "__post_init__"))?;
             }
         }
-        if consumed != kwargs.len() {
-            return Err(init_kwargs_error(kwargs, &class_info));
+        if has_invariants {
+            check_invariants(slf, None)?;
         }
         Ok(())
     }
@@ -238,6 +307,9 @@ impl AtorsBase {
         for slot in inner.slots.iter().flatten() {
             visit.call(slot)?;
         }
+        if let Some(validators) = &inner.instance_validators {
+            visit.call(validators)?;
+        }
         Ok(())
     }
 
@@ -248,6 +320,7 @@ impl AtorsBase {
         for o in inner.slots.iter_mut() {
             *o = None;
         }
+        inner.instance_validators = None;
     }
 
     pub fn __getstate__<'py>(slf: &Bound<'py, AtorsBase>) -> PyResult<Bound<'py, PyDict>> {
@@ -280,6 +353,9 @@ impl AtorsBase {
         let cls = slf.get_type();
         let class_info = get_class_info(&cls)?;
 
+        // Unpickled instances bypass the metaclass __call__ so register them here.
+        class_info.register_instance(slf.as_any())?;
+
         // Restore values
         for (key, value) in state.iter() {
             let key_str: String = key.extract()?;
@@ -337,6 +413,158 @@ impl AtorsBase {
 
         Ok(())
     }
+
+    /// Create a new instance with some members replaced (used by copy.replace).
+    ///
+    /// The class is called with the values of the set init members updated with
+    /// the changes, so that the changes are validated. Members which cannot be
+    /// set at init cannot be replaced and keep their default value. The new
+    /// instance is frozen if the original one is.
+    #[pyo3(signature = (**changes))]
+    pub fn __replace__<'py>(
+        slf: &Bound<'py, AtorsBase>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = slf.py();
+        let cls = slf.get_type();
+        let class_info = get_class_info(&cls)?;
+
+        let kwargs = PyDict::new(py);
+        {
+            let members = class_info.members_by_name_ref(py);
+            for name in class_info
+                .required_init_member_names()
+                .iter()
+                .chain(class_info.optional_init_member_names())
+            {
+                let name = name.bind(py);
+                if let Some(member) = members.get(name.to_str()?)
+                    && let Some(value) = get_slot_owned(slf, member.bind(py).get().index())
+                {
+                    kwargs.set_item(name, value)?;
+                }
+            }
+        }
+
+        if let Some(changes) = changes {
+            let changes = resolve_member_aliases(changes, &class_info)?;
+            for key in changes.keys() {
+                if let Ok(key) = key.extract::<&str>()
+                    && class_info.members_by_name_ref(py).contains_key(key)
+                    && !class_info.is_init_member_name(py, key)
+                {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Member '{key}' of {} cannot be set at init and hence cannot be replaced",
+                        cls.name()?
+                    )));
+                }
+            }
+            kwargs.update(changes.as_mapping())?;
+        }
+
+        let new = cls.call((), Some(&kwargs))?;
+        if is_frozen(slf)
+            && let Ok(instance) = new.cast::<AtorsBase>()
+            && !is_frozen(instance)
+        {
+            freeze(instance)?;
+        }
+        Ok(new)
+    }
+
+    /// Return a mapping view over the members of this object.
+    ///
+    /// Getting, setting and iterating the view goes through the members so
+    /// that values are validated as for attribute access.
+    pub fn as_mapping(slf: &Bound<'_, AtorsBase>) -> MemberValuesMapping {
+        MemberValuesMapping {
+            obj: slf.clone().unbind(),
+        }
+    }
+}
+
+/// Mapping view over the member values of an Ators object.
+///
+/// view[name] and view[name] = value are equivalent to getattr and setattr
+/// for the member name, and iterating the view yields the member names in slot
+/// order. Names which are not members raise KeyError.
+#[pyclass(module = "ators._ators", mapping, frozen)]
+pub struct MemberValuesMapping {
+    obj: Py<AtorsBase>,
+}
+
+impl MemberValuesMapping {
+    /// Retrieve the member of the viewed object named by key.
+    fn member<'py>(
+        &self,
+        py: Python<'py>,
+        key: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, Member>> {
+        let info = get_class_info(&self.obj.bind(py).get_type())?;
+        let member = key.extract::<&str>().ok().and_then(|name| {
+            info.members_by_name_ref(py)
+                .get(name)
+                .map(|m| m.clone_ref(py))
+        });
+        match member {
+            Some(m) => Ok(m.into_bound(py)),
+            None => Err(pyo3::exceptions::PyKeyError::new_err(key.clone().unbind())),
+        }
+    }
+}
+
+#[pymethods]
+impl MemberValuesMapping {
+    fn __len__(&self, py: Python<'_>) -> PyResult<usize> {
+        Ok(get_class_info(&self.obj.bind(py).get_type())?
+            .members_by_name_ref(py)
+            .len())
+    }
+
+    fn __contains__<'py>(&self, py: Python<'py>, key: &Bound<'py, PyAny>) -> bool {
+        self.member(py, key).is_ok()
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        key: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let member = self.member(py, key)?;
+        Member::__get__(member.borrow(), self.obj.bind(py).as_any())
+    }
+
+    fn __setitem__<'py>(
+        &self,
+        py: Python<'py>,
+        key: &Bound<'py, PyAny>,
+        value: &Bound<'py, PyAny>,
+    ) -> PyResult<()> {
+        let member = self.member(py, key)?;
+        Member::__set__(member.borrow(), self.obj.bind(py).as_any(), value)
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        self.keys(py)?.try_iter()
+    }
+
+    /// Member names in slot order, allowing to use the view with dict(view)
+    /// and **view.
+    fn keys<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let info = get_class_info(&self.obj.bind(py).get_type())?;
+        let names: Vec<String> = members_in_slot_order(py, &info)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        PyList::new(py, names)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "MemberValuesMapping({})",
+            self.obj.bind(py).repr()?
+        ))
+    }
 }
 
 /// Get a reference to the value stored in the slot at index if any.
@@ -369,6 +597,52 @@ pub(crate) fn get_slot_owned<'py>(object: &Bound<'py, AtorsBase>, index: u8) ->
     })
 }
 
+/// Get owned clones of the values stored in all the slots, in index order.
+pub(crate) fn get_slots_owned(object: &Bound<'_, AtorsBase>) -> Vec<Option<Py<PyAny>>> {
+    let py = object.py();
+    with_critical_section(object.as_any(), || {
+        // Safety: we hold the critical section lock on this object.
+        let inner = unsafe { &*object.get().inner.get() };
+        inner
+            .slots
+            .iter()
+            .map(|slot| slot.as_ref().map(|value| value.clone_ref(py)))
+            .collect()
+    })
+}
+
+/// Overwrite all the slots of an object with previously captured values.
+///
+/// Frozen objects are left untouched, the frozen state being checked in the
+/// same critical section as the write. The replaced (or discarded) values are
+/// dropped once the critical section is released.
+pub(crate) fn restore_slots(object: &Bound<'_, AtorsBase>, values: Vec<Option<Py<PyAny>>>) {
+    let _replaced: Vec<Option<Py<PyAny>>> = with_critical_section(object.as_any(), || {
+        let inner = object.get().inner.get();
+        // Safety: we hold the critical section lock on this object. We write through the
+        // raw pointer instead of creating a &mut T to avoid relying on Rust aliasing rules.
+        if unsafe { (*inner).frozen } {
+            return values;
+        }
+        let slots = unsafe { &mut (*inner).slots };
+        slots
+            .iter_mut()
+            .zip(values)
+            .map(|(slot, value)| std::mem::replace(slot, value))
+            .collect()
+    });
+}
+
+/// Debugging helper returning the raw content of the slots of an object.
+///
+/// The list is indexed by slot index rather than by member name, unset slots
+/// being reported as None, which helps diagnosing slot index conflicts.
+#[pyfunction]
+#[pyo3(name = "_dump_slots")]
+pub fn dump_slots<'py>(obj: &Bound<'py, AtorsBase>) -> PyResult<Bound<'py, PyList>> {
+    PyList::new(obj.py(), get_slots_owned(obj))
+}
+
 /// Set the slot at index to the specified value
 #[inline]
 pub(crate) fn set_slot<'py>(object: &Bound<'py, AtorsBase>, index: u8, value: &Bound<'py, PyAny>) {
@@ -451,6 +725,92 @@ pub(crate) fn instance_is_observable(obj: &Bound<'_, AtorsBase>) -> bool {
     unsafe { (*obj.get().inner.get()).is_observable }
 }
 
+/// Check whether an instance belongs to a class declaring invariants.
+///
+/// As for `instance_is_observable`, the flag is set once at construction time
+/// and can hence be read without acquiring the critical section.
+#[inline]
+pub(crate) fn instance_has_invariants(obj: &Bound<'_, AtorsBase>) -> bool {
+    // Safety: has_invariants is written exactly once (in py_new) and never
+    // modified afterwards.
+    unsafe { (*obj.get().inner.get()).has_invariants }
+}
+
+thread_local! {
+    /// Addresses of the objects whose __init__ is running on this thread.
+    static INITIALIZING: std::cell::RefCell<Vec<usize>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Mark an object as being initialized until the guard is dropped.
+struct InitializationGuard;
+
+impl InitializationGuard {
+    fn enter(obj: &Bound<'_, AtorsBase>) -> Self {
+        INITIALIZING.with_borrow_mut(|i| i.push(obj.as_ptr().addr()));
+        InitializationGuard
+    }
+}
+
+impl Drop for InitializationGuard {
+    fn drop(&mut self) {
+        INITIALIZING.with_borrow_mut(|i| {
+            i.pop();
+        });
+    }
+}
+
+/// Check the invariants of an object involving a member, or all of them when
+/// no member is specified.
+///
+/// Invariants are not checked while the object is being initialized. An
+/// invariant is violated if its method raises an Exception or returns a false
+/// value other than None. Other errors (e.g. KeyboardInterrupt) propagate as
+/// is.
+pub(crate) fn check_invariants(obj: &Bound<'_, AtorsBase>, member: Option<&str>) -> PyResult<()> {
+    let address = obj.as_ptr().addr();
+    if member.is_some() && INITIALIZING.with_borrow(|i| i.contains(&address)) {
+        return Ok(());
+    }
+    let py = obj.py();
+    let info = get_class_info(&obj.get_type())?;
+    for invariant in info.invariants() {
+        if member.is_some_and(|m| !invariant.members.iter().any(|n| n == m)) {
+            continue;
+        }
+        let meth_name = invariant.meth_name.bind(py);
+        let context = match member {
+            Some(m) => format!(" after setting '{m}'"),
+            None => String::new(),
+        };
+        match obj.call_method0(meth_name) {
+            Ok(r) if r.is_none() || r.is_truthy()? => {}
+            Ok(_) => {
+                return Err(new_validation_error(
+                    py,
+                    format!(
+                        "Invariant '{meth_name}' of {} is violated{context}",
+                        obj.repr()?
+                    ),
+                ));
+            }
+            Err(err) if !err.is_instance_of::<pyo3::exceptions::PyException>(py) => {
+                return Err(err);
+            }
+            Err(err) => {
+                return Err(crate::utils::err_with_cause(
+                    py,
+                    new_validation_error(
+                        py,
+                        format!("Invariant '{meth_name}' of {} failed{context}", obj.repr()?),
+                    ),
+                    err,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn notifications_enabled(obj: &Bound<'_, AtorsBase>) -> bool {
     with_critical_section(obj.as_any(), || {
         // Safety: we hold the critical section lock on this object.
@@ -603,6 +963,9 @@ pub fn freeze<'py>(obj: &Bound<'py, AtorsBase>) -> PyResult<()> {
 /// Return the object after applying class-level post-construction freezing, and
 /// enforce the abstract-class contract.
 ///
+/// Instances of classes created with `track_instances=True` are also added to
+/// the class registry of live instances.
+///
 /// A single `get_class_info` call serves both checks, avoiding duplicate
 /// lookups and keeping the approach consistent with how all other class-level
 /// flags (e.g. `frozen`) are handled in ators.
@@ -628,6 +991,7 @@ pub fn maybe_freeze_instance_after_call<'py>(
         if class_info.frozen() {
             freeze(instance)?;
         }
+        class_info.register_instance(&obj)?;
     }
     Ok(obj)
 }
@@ -641,7 +1005,118 @@ pub fn is_frozen<'py>(obj: &Bound<'py, AtorsBase>) -> bool {
     })
 }
 
+/// Set or remove (by passing None) the validator specific to an instance for
+/// one of its members.
+///
+/// Assigning the member first validates the value using the instance
+/// validator and then the member validator, so that an instance can only
+/// tighten the constraints of its class. The current value is not validated
+/// again. Instances without overrides only store an empty pointer while the
+/// first override allocates a dict holding them. Overrides are neither
+/// pickled nor copied and cannot be changed once the object is frozen.
+#[pyfunction]
+pub fn set_instance_validator<'py>(
+    obj: &Bound<'py, AtorsBase>,
+    name: &str,
+    validator: Option<Bound<'py, Validator>>,
+) -> PyResult<()> {
+    let py = obj.py();
+    let cls = obj.get_type();
+    if !get_class_info(&cls)?
+        .members_by_name_ref(py)
+        .contains_key(name)
+    {
+        return Err(unknown_member_error(&cls, name));
+    }
+    // The dict of overrides is never mutated once published: an updated copy
+    // replaces it, in the critical section checking the frozen state, provided
+    // no other thread replaced it in the meantime.
+    loop {
+        let current = with_critical_section(obj.as_any(), || {
+            // Safety: we hold the critical section lock on this object.
+            let inner = unsafe { &*obj.get().inner.get() };
+            inner.instance_validators.as_ref().map(|v| v.clone_ref(py))
+        });
+        let updated = match &current {
+            Some(current) => current.bind(py).copy()?,
+            None => PyDict::new(py),
+        };
+        match &validator {
+            Some(v) => updated.set_item(name, v)?,
+            None => {
+                if updated.contains(name)? {
+                    updated.del_item(name)?;
+                }
+            }
+        }
+        let replaced = with_critical_section(obj.as_any(), || {
+            // Safety: we hold the critical section lock on this object. We write through
+            // the raw pointer instead of creating a &mut T to avoid relying on Rust
+            // aliasing rules.
+            let inner = obj.get().inner.get();
+            if unsafe { (*inner).frozen } {
+                return Err(());
+            }
+            let validators = unsafe { &mut (*inner).instance_validators };
+            if validators.as_ref().map(|v| v.as_ptr()) != current.as_ref().map(|v| v.as_ptr()) {
+                return Ok(None);
+            }
+            Ok(Some(validators.replace(updated.unbind())))
+        });
+        match replaced {
+            // The previous dict is dropped outside of the critical section.
+            Ok(Some(_previous)) => return Ok(()),
+            Ok(None) => continue,
+            Err(()) => {
+                return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Cannot modify {} which is frozen.",
+                    obj.repr()?
+                )));
+            }
+        }
+    }
+}
+
+/// Retrieve the validator specific to an instance for a member, if any.
+pub(crate) fn get_instance_validator<'py>(
+    obj: &Bound<'py, AtorsBase>,
+    name: &str,
+) -> PyResult<Option<Bound<'py, Validator>>> {
+    let py = obj.py();
+    let validators = with_critical_section(obj.as_any(), || {
+        // Safety: we hold the critical section lock on this object.
+        let inner = unsafe { &*obj.get().inner.get() };
+        inner
+            .instance_validators
+            .as_ref()
+            .map(|validators| validators.clone_ref(py))
+    });
+    match validators {
+        Some(validators) => Ok(validators
+            .bind(py)
+            .get_item(name)?
+            .map(|v| v.cast_into::<Validator>())
+            .transpose()?),
+        None => Ok(None),
+    }
+}
+
+/// Error raised by the functions looking up a member by name when the class
+/// has no such member.
+///
+/// As for attribute access, an AttributeError is used. Mapping views over the
+/// members raise KeyError instead.
+pub(crate) fn unknown_member_error(cls: &Bound<'_, PyType>, name: &str) -> PyErr {
+    let cls_name = cls
+        .name()
+        .map(|n| n.to_string())
+        .unwrap_or_else(|_| "<unknown>".to_string());
+    pyo3::exceptions::PyAttributeError::new_err(format!("{cls_name} has no member '{name}'"))
+}
+
 /// Retrieve a single Member from an Ators object by name.
+///
+/// Raises an AttributeError if the class has no such member.
 #[pyfunction]
 pub fn get_member<'py>(
     obj: Bound<'py, PyAny>,
@@ -653,12 +1128,70 @@ pub fn get_member<'py>(
     info.members_by_name_ref(obj.py())
         .get(&name)
         .map(|m| m.bind(obj.py()).clone())
-        .ok_or_else(|| {
-            pyo3::exceptions::PyAttributeError::new_err(format!("Unknown member '{name}'"))
-        })
+        .ok_or_else(|| unknown_member_error(&cls, &name))
+}
+
+/// Check a value against the validators of a member without raising.
+///
+/// The class (or an instance, which is then available to the validators
+/// using the object) and the member name identify the member. The returned
+/// list holds a human-readable message for each problem found, type errors
+/// being prefixed with "Type error: " and value errors with "Value error: ",
+/// and is empty if the value is valid. The value is never coerced. Errors
+/// other than TypeError and ValueError raised by the validators propagate.
+#[pyfunction]
+pub fn check_value<'py>(
+    cls: &Bound<'py, PyAny>,
+    member_name: &str,
+    value: &Bound<'py, PyAny>,
+) -> PyResult<Vec<String>> {
+    let py = cls.py();
+    let resolved = resolve_class_for_obj(cls)?;
+    let info = get_class_info(&resolved)?;
+    let object = cls.cast::<AtorsBase>().ok();
+    match info.members_by_name_ref(py).get(member_name) {
+        Some(m) => m
+            .get()
+            .validator()
+            .check_value(Some(member_name), object, value),
+        None => Err(unknown_member_error(&resolved, member_name)),
+    }
+}
+
+/// Collect the names of the members of a class whose validator satisfies a predicate.
+fn member_names_matching<'py>(
+    cls: &Bound<'py, PyAny>,
+    predicate: impl Fn(&Validator) -> bool,
+) -> PyResult<HashSet<String>> {
+    let py = cls.py();
+    let info = get_class_info(&resolve_class_for_obj(cls)?)?;
+    Ok(info
+        .members_by_name_ref(py)
+        .iter()
+        .filter(|(_, m)| predicate(m.get().validator()))
+        .map(|(name, _)| name.clone())
+        .collect())
+}
+
+/// Retrieve the names of the members coercing the values assigned to them.
+///
+/// Those members have a coercer and may hence transform (rather than reject)
+/// values of an invalid type. Coercers only used at init are not considered.
+#[pyfunction]
+pub fn coercing_members<'py>(cls: &Bound<'py, PyAny>) -> PyResult<HashSet<String>> {
+    member_names_matching(cls, |v| v.coercer.is_some())
+}
+
+/// Retrieve the names of the members coercing the values passed at init.
+#[pyfunction]
+pub fn init_coercing_members<'py>(cls: &Bound<'py, PyAny>) -> PyResult<HashSet<String>> {
+    member_names_matching(cls, |v| v.init_coercer.is_some())
 }
 
 /// Retrieve all members from an Ators object.
+///
+/// The members are returned as a read-only mapping, which raises a KeyError for
+/// names which are not members.
 #[pyfunction]
 pub fn get_members<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
     let cls = resolve_class_for_obj(obj)?;
@@ -666,7 +1199,297 @@ pub fn get_members<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>>
     Ok(info.members_by_name().bind(obj.py()).clone().into_any())
 }
 
+/// Sentinel reported by `iter_member_values` for members whose value is unset.
+#[pyclass(module = "ators._ators", name = "UnsetType", frozen)]
+pub struct UnsetType;
+
+static UNSET: PyOnceLock<Py<UnsetType>> = PyOnceLock::new();
+
+/// Get the unique instance of UnsetType.
+pub(crate) fn unset(py: Python<'_>) -> &Py<UnsetType> {
+    UNSET.get_or_init(py, || {
+        Py::new(py, UnsetType).expect("Allocating the UNSET sentinel cannot fail")
+    })
+}
+
+#[pymethods]
+impl UnsetType {
+    fn __repr__(&self) -> &'static str {
+        "UNSET"
+    }
+
+    fn __bool__(&self) -> bool {
+        false
+    }
+
+    fn __reduce__(&self) -> &'static str {
+        "UNSET"
+    }
+}
+
+/// Collect the members of a class sorted by slot index rather than in hash map order.
+pub(crate) fn members_in_slot_order(
+    py: Python<'_>,
+    info: &AtorsClassInfo,
+) -> Vec<(String, Py<Member>)> {
+    let mut members: Vec<_> = info
+        .members_by_name_ref(py)
+        .iter()
+        .map(|(name, m)| (name.clone(), m.clone_ref(py)))
+        .collect();
+    members.sort_by_key(|(_, m)| m.get().index());
+    members
+}
+
+/// Iterate over the members of an Ators object and their current values.
+///
+/// Yields (name, member, value) tuples, value being UNSET for members whose
+/// value is unset. Slots are read directly so that no default is computed.
+#[pyfunction]
+pub fn iter_member_values<'py>(obj: &Bound<'py, AtorsBase>) -> PyResult<Bound<'py, PyIterator>> {
+    let py = obj.py();
+    let info = get_class_info(&obj.get_type())?;
+    let sentinel = unset(py).bind(py);
+    let members = members_in_slot_order(py, &info);
+    let mut items = Vec::with_capacity(members.len());
+    for (name, m) in members {
+        let value = match get_slot_owned(obj, m.get().index()) {
+            Some(v) => v.into_bound(py),
+            None => sentinel.clone().into_any(),
+        };
+        items.push((name, m, value));
+    }
+    PyList::new(py, items)?.try_iter()
+}
+
+/// Return a dict of the values of the set members of an Ators object.
+///
+/// Unset members are omitted (defaults are not computed). When by_alias is
+/// true, members having aliases are reported under their first alias.
+#[pyfunction]
+#[pyo3(signature = (obj, *, by_alias = false))]
+pub fn to_dict<'py>(obj: &Bound<'py, AtorsBase>, by_alias: bool) -> PyResult<Bound<'py, PyDict>> {
+    let py = obj.py();
+    let info = get_class_info(&obj.get_type())?;
+    let values = PyDict::new(py);
+    for (name, m) in members_in_slot_order(py, &info) {
+        if let Some(value) = get_slot_owned(obj, m.get().index()) {
+            let key = if by_alias {
+                m.get().aliases(py).into_iter().next().unwrap_or(name)
+            } else {
+                name
+            };
+            values.set_item(key, value)?;
+        }
+    }
+    Ok(values)
+}
+
+/// Compute the members whose values differ between two instances of the same class.
+///
+/// Returns a dict mapping the names of those members to (old, new) tuples, an
+/// unset value being reported as UNSET (defaults are not computed). Values are
+/// compared by identity and then using ==.
+#[pyfunction]
+pub fn diff<'py>(
+    a: &Bound<'py, AtorsBase>,
+    b: &Bound<'py, AtorsBase>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let py = a.py();
+    let cls = a.get_type();
+    if !b.get_type().is(&cls) {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot diff instances of different classes: {} and {}",
+            cls.name()?,
+            b.get_type().name()?
+        )));
+    }
+    let info = get_class_info(&cls)?;
+    let sentinel = unset(py).bind(py).as_any();
+    let changes = PyDict::new(py);
+    for (name, m) in members_in_slot_order(py, &info) {
+        let index = m.get().index();
+        let old = get_slot_owned(a, index).map(|v| v.into_bound(py));
+        let new = get_slot_owned(b, index).map(|v| v.into_bound(py));
+        let differ = match (&old, &new) {
+            (Some(o), Some(n)) => !o.is(n) && !o.eq(n)?,
+            (None, None) => false,
+            _ => true,
+        };
+        if differ {
+            changes.set_item(
+                name,
+                (
+                    old.unwrap_or_else(|| sentinel.clone()),
+                    new.unwrap_or_else(|| sentinel.clone()),
+                ),
+            )?;
+        }
+    }
+    Ok(changes)
+}
+
+/// Reset the members of an object to their default values.
+///
+/// The slots of the set members having a default, a Slot delattr behavior and
+/// no constant or read-only pre-setattr behavior are cleared so that the
+/// default is used on the next access. Returns the names of the set members
+/// which could not be reset, in slot order.
+#[pyfunction]
+pub fn reset_to_defaults<'py>(obj: &Bound<'py, AtorsBase>) -> PyResult<Vec<String>> {
+    let py = obj.py();
+    if is_frozen(obj) {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot modify {} which is frozen.",
+            obj.repr()?
+        )));
+    }
+    let info = get_class_info(&obj.get_type())?;
+    let mut skipped = Vec::new();
+    for (name, m) in members_in_slot_order(py, &info) {
+        let m = m.get();
+        let Some(old) = get_slot_owned(obj, m.index()) else {
+            continue;
+        };
+        if m.is_resettable() {
+            // Observers are notified as when deleting the member.
+            del_slot(obj, m.index());
+            m.notify_delete(obj, old)?;
+        } else {
+            skipped.push(name);
+        }
+    }
+    Ok(skipped)
+}
+
+/// Write a value directly into the slot of a member.
+///
+/// This bypasses validation, coercion, normalization and every member
+/// behavior (including read-only and constant members), as well as
+/// invariants and change notifications. Only the frozen state of the object
+/// is respected. This is meant for performance-critical code which already
+/// validated the value, and breaks any guarantee on the object state
+/// otherwise.
+#[pyfunction]
+pub fn set_unchecked<'py>(
+    obj: &Bound<'py, AtorsBase>,
+    name: &str,
+    value: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    let py = obj.py();
+    let cls = obj.get_type();
+    let info = get_class_info(&cls)?;
+    let index = match info.members_by_name_ref(py).get(name) {
+        Some(m) => m.get().index(),
+        None => return Err(unknown_member_error(&cls, name)),
+    };
+    // The frozen state is checked in the critical section performing the write.
+    match replace_slot(obj, index, value) {
+        Ok(_) => Ok(()),
+        Err(()) => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot set member '{}' on frozen {}.",
+            name,
+            obj.repr()?
+        ))),
+    }
+}
+
+/// Set several members of an object from a mapping of member names to values.
+///
+/// Unknown names are rejected before any member is set. Each value then goes
+/// through the regular validated set path and all the errors raised are
+/// collected in an ExceptionGroup, the members which could be set keeping
+/// their new value. Exceptions not deriving from Exception (such as
+/// KeyboardInterrupt) are raised immediately.
+#[pyfunction]
+pub fn update_validated<'py>(
+    obj: &Bound<'py, AtorsBase>,
+    mapping: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    let py = obj.py();
+    if is_frozen(obj) {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot modify {} which is frozen.",
+            obj.repr()?
+        )));
+    }
+    let cls = obj.get_type();
+    let info = get_class_info(&cls)?;
+    let items = PyDict::new(py);
+    items.update(&mapping.cast::<pyo3::types::PyMapping>()?.clone())?;
+
+    let mut unknown = Vec::new();
+    {
+        let members = info.members_by_name_ref(py);
+        let names = PyList::new(py, members.keys())?;
+        let get_close_matches = py
+            .import(intern!(py, "difflib"))?
+            .getattr(intern!(py, "get_close_matches"))?;
+        for key in items.keys() {
+            if let Ok(name) = key.extract::<&str>()
+                && members.contains_key(name)
+            {
+                continue;
+            }
+            let suggestions: Vec<String> = match key.cast::<PyString>() {
+                Ok(name) => get_close_matches.call1((name, &names))?.extract()?,
+                Err(_) => Vec::new(),
+            };
+            unknown.push(if suggestions.is_empty() {
+                key.repr()?.to_string()
+            } else {
+                format!(
+                    "{} (did you mean {}?)",
+                    key.repr()?,
+                    suggestions
+                        .iter()
+                        .map(|s| format!("'{s}'"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            });
+        }
+    }
+    if !unknown.is_empty() {
+        return Err(pyo3::exceptions::PyAttributeError::new_err(format!(
+            "{} has no member(s) {}",
+            cls.name()?,
+            unknown.join(", ")
+        )));
+    }
+
+    let mut errors = Vec::new();
+    for (key, value) in items.iter() {
+        if let Err(err) = obj.setattr(key.cast::<PyString>()?, value) {
+            // ExceptionGroup only accepts Exception instances, and errors such
+            // as KeyboardInterrupt should not be deferred anyway.
+            if !err.is_instance_of::<pyo3::exceptions::PyException>(py) {
+                return Err(err);
+            }
+            errors.push(err.into_value(py));
+        }
+    }
+    if !errors.is_empty() {
+        let exception_group = py
+            .import(intern!(py, "builtins"))?
+            .getattr(intern!(py, "ExceptionGroup"))?
+            .call1((
+                format!(
+                    "Failed to update {} member(s) of {}",
+                    errors.len(),
+                    cls.name()?
+                ),
+                errors,
+            ))?;
+        return Err(PyErr::from_value(exception_group));
+    }
+    Ok(())
+}
+
 /// Retrieve all members with a specific metadata key and the value associated with it.
+///
+/// Members without the tag are omitted, so that an empty dict is returned
+/// when no member has it.
 #[pyfunction]
 pub fn get_members_by_tag<'py>(
     obj: &Bound<'py, PyAny>,
@@ -688,11 +1511,17 @@ pub fn get_members_by_tag<'py>(
 }
 
 /// Retrieve all members with a specific metadata key and value.
+///
+/// When exact is true, the type of the tag value must also match the type of
+/// the queried value (so that a tag value of 1 does not match True). Errors
+/// raised while comparing values are propagated.
 #[pyfunction]
+#[pyo3(signature = (obj, tag, value, *, exact = false))]
 pub fn get_members_by_tag_and_value<'py>(
     obj: &Bound<'py, PyAny>,
     tag: String,
     value: &Bound<'py, PyAny>,
+    exact: bool,
 ) -> PyResult<Bound<'py, PyDict>> {
     let members = PyDict::new(obj.py());
     let py = obj.py();
@@ -701,11 +1530,15 @@ pub fn get_members_by_tag_and_value<'py>(
     for (name, member) in info.members_by_name_ref(py).iter() {
         let member = member.bind(py);
         if let Some(m) = member.get().metadata()
-            && m.contains_key(&tag)
-            // If comparison fails the member should not be included
-            && value.as_any().eq(&m[&tag]).unwrap_or(false)
+            && let Some(tag_value) = m.get(&tag)
         {
-            members.set_item(name, member)?;
+            let tag_value = tag_value.bind(py);
+            if exact && !value.get_type().is(tag_value.get_type()) {
+                continue;
+            }
+            if value.eq(tag_value)? {
+                members.set_item(name, member)?;
+            }
         }
     }
     Ok(members)
@@ -822,11 +1655,16 @@ pub fn get_event_customization_tool<'py>(
 /// Register an observer callback for a member on an observable object.
 ///
 /// The callback receives an `AtorsChange` whenever the member value changes.
+/// Callbacks are weakly referenced (so that observing does not keep them or
+/// the owner of a bound method alive) unless strong is true. Dead callbacks
+/// are discarded on the next notification.
 #[pyfunction]
+#[pyo3(signature = (obj, member_name, callback, *, strong = false))]
 pub fn observe<'py>(
     obj: &Bound<'py, AtorsBase>,
     member_name: String,
     callback: &Bound<'py, PyAny>,
+    strong: bool,
 ) -> PyResult<()> {
     if !instance_is_observable(obj) {
         return Err(pyo3::exceptions::PyTypeError::new_err(
@@ -840,13 +1678,11 @@ pub fn observe<'py>(
         .contains_key(&member_name)
         && !class_info.events_by_name().contains_key(&member_name)
     {
-        return Err(pyo3::exceptions::PyAttributeError::new_err(format!(
-            "Unknown member '{member_name}'"
-        )));
+        return Err(unknown_member_error(&obj.get_type(), &member_name));
     }
 
     let pool = get_observer_pool(obj);
-    ObserverPool::add(pool, &member_name, callback)
+    ObserverPool::add(pool, &member_name, callback, strong)
 }
 
 /// Unregister one observer callback for a member on an observable object.
@@ -904,3 +1740,143 @@ pub fn disable_notifications<'py>(obj: &Bound<'py, AtorsBase>) {
 pub fn is_notifications_enabled<'py>(obj: &Bound<'py, AtorsBase>) -> bool {
     notifications_enabled(obj)
 }
+
+/// Context manager enabling (or, if enabled is false, disabling) change
+/// notifications on an observable object for the duration of a block.
+///
+/// The state found on entry is restored on exit, even if an exception was
+/// raised, so that nested uses (including of the same context manager) are
+/// supported.
+#[pyclass(module = "ators._ators", name = "notifications_enabled")]
+pub struct NotificationsEnabled {
+    obj: Py<AtorsBase>,
+    enabled: bool,
+    previous: Vec<bool>,
+}
+
+#[pymethods]
+impl NotificationsEnabled {
+    #[new]
+    #[pyo3(signature = (obj, enabled = true))]
+    fn new(obj: &Bound<'_, AtorsBase>, enabled: bool) -> Self {
+        Self {
+            obj: obj.clone().unbind(),
+            enabled,
+            previous: Vec::new(),
+        }
+    }
+
+    fn __enter__<'py>(&mut self, py: pyo3::Python<'py>) -> PyResult<Bound<'py, AtorsBase>> {
+        let obj = self.obj.bind(py);
+        let previous = notifications_enabled(obj);
+        if self.enabled {
+            enable_notifications(obj)?;
+        } else {
+            disable_notifications(obj);
+        }
+        self.previous.push(previous);
+        Ok(obj.clone())
+    }
+
+    fn __exit__(
+        &mut self,
+        py: pyo3::Python<'_>,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        let obj = self.obj.bind(py);
+        match self.previous.pop() {
+            Some(true) => enable_notifications(obj)?,
+            Some(false) => disable_notifications(obj),
+            None => {}
+        }
+        Ok(false)
+    }
+}
+
+/// Context manager disabling change notifications on an object for the
+/// duration of a block, for example to perform bulk updates without firing
+/// observers.
+#[pyfunction]
+pub fn suppress_notifications(obj: &Bound<'_, AtorsBase>) -> NotificationsEnabled {
+    NotificationsEnabled::new(obj, false)
+}
+
+/// Context manager making a sequence of assignments to an object all-or-nothing.
+///
+/// The slots of the object are captured on entry and restored if the block
+/// raises, so that the object is left as it was before the block. Capturing
+/// copies a reference to every slot, which is linear in the number of members,
+/// and values mutated in place (such as containers) are not restored. The
+/// restoration writes the slots directly and hence emits no notification.
+/// Nested uses (including of the same context manager) are supported. Frozen
+/// objects are rejected on entry, and an object frozen within the block is not
+/// restored.
+#[pyclass(module = "ators._ators", name = "transaction")]
+pub struct Transaction {
+    // None only once cleared by the GC.
+    obj: Option<Py<AtorsBase>>,
+    snapshots: Vec<Vec<Option<Py<PyAny>>>>,
+}
+
+impl Transaction {
+    fn object<'py>(&self, py: pyo3::Python<'py>) -> PyResult<&Bound<'py, AtorsBase>> {
+        self.obj.as_ref().map(|o| o.bind(py)).ok_or_else(|| {
+            pyo3::exceptions::PyRuntimeError::new_err("The transaction has been cleared.")
+        })
+    }
+}
+
+#[pymethods]
+impl Transaction {
+    #[new]
+    fn new(obj: &Bound<'_, AtorsBase>) -> Self {
+        Self {
+            obj: Some(obj.clone().unbind()),
+            snapshots: Vec::new(),
+        }
+    }
+
+    fn __enter__<'py>(&mut self, py: pyo3::Python<'py>) -> PyResult<Bound<'py, AtorsBase>> {
+        let obj = self.object(py)?.clone();
+        if is_frozen(&obj) {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Cannot modify {} which is frozen.",
+                obj.repr()?
+            )));
+        }
+        self.snapshots.push(get_slots_owned(&obj));
+        Ok(obj)
+    }
+
+    fn __exit__(
+        &mut self,
+        py: pyo3::Python<'_>,
+        exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        if let Some(snapshot) = self.snapshots.pop()
+            && !exc_type.is_none()
+        {
+            restore_slots(self.object(py)?, snapshot);
+        }
+        Ok(false)
+    }
+
+    fn __traverse__(&self, visit: pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        if let Some(obj) = &self.obj {
+            visit.call(obj)?;
+        }
+        for value in self.snapshots.iter().flatten().flatten() {
+            visit.call(value)?;
+        }
+        Ok(())
+    }
+
+    fn __clear__(&mut self) {
+        self.obj = None;
+        self.snapshots.clear();
+    }
+}
@@ -10,8 +10,11 @@ use std::sync::{Arc, RwLock};
 
 use pyo3::{
     Bound, Py, PyAny, PyResult, intern, pyclass, pyfunction, pymethods,
-    sync::PyOnceLock,
-    types::{PyAnyMethods, PyDict, PyDictMethods, PyFrozenSet, PyString, PyTuple, PyType},
+    sync::{PyOnceLock, critical_section::with_critical_section},
+    types::{
+        PyAnyMethods, PyDict, PyDictMethods, PyFrozenSet, PyList, PyListMethods, PyString, PyTuple,
+        PyType, PyTypeMethods,
+    },
 };
 
 use crate::class::generic::wrap_ators_specialized_class;
@@ -78,11 +81,13 @@ impl MembersByNameMapping {
         py: pyo3::Python<'py>,
         key: &Bound<'py, PyAny>,
     ) -> PyResult<Bound<'py, Member>> {
-        let key_name: String = key.extract()?;
-        self.members_by_name
-            .get(&key_name)
+        // As for a dict, keys which are not member names (including non str
+        // keys) raise a KeyError.
+        key.extract::<&str>()
+            .ok()
+            .and_then(|name| self.members_by_name.get(name))
             .map(|member| member.bind(py).clone())
-            .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(key_name))
+            .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(key.clone().unbind()))
     }
 
     fn __iter__(&self, py: pyo3::Python<'_>) -> PyResult<Py<MembersByNameKeysIter>> {
@@ -131,6 +136,37 @@ impl MembersByNameMapping {
     }
 }
 
+/// Collect the aliases of the members, rejecting aliases which are used by
+/// several members or which are the name of another member.
+fn collect_member_aliases(
+    py: pyo3::Python<'_>,
+    members_by_name: &HashMap<String, Py<Member>>,
+) -> PyResult<HashMap<String, Py<PyString>>> {
+    let mut names: Vec<&String> = members_by_name.keys().collect();
+    names.sort();
+    let mut aliases: HashMap<String, Py<PyString>> = HashMap::new();
+    for name in names {
+        for alias in members_by_name[name].get().aliases(py) {
+            if &alias == name {
+                continue;
+            }
+            if members_by_name.contains_key(&alias) {
+                return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Alias '{alias}' of member '{name}' is the name of another member"
+                )));
+            }
+            if let Some(other) = aliases.get(&alias) {
+                return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Alias '{alias}' is used by both members '{}' and '{name}'",
+                    other.bind(py)
+                )));
+            }
+            aliases.insert(alias, PyString::new(py, name).unbind());
+        }
+    }
+    Ok(aliases)
+}
+
 pub(crate) struct AtorsGenericInfo {
     /// The type parameters of the generic class, if any.  This is used to
     /// support unspecialized generic classes
@@ -192,22 +228,35 @@ impl AtorsGenericInfo {
     }
 }
 
+/// Invariant spanning several members, declared using the invariant decorator.
+pub(crate) struct Invariant {
+    /// Name of the method checking the invariant.
+    pub(crate) meth_name: Py<PyString>,
+    /// Names of the members whose changes trigger a check of the invariant.
+    pub(crate) members: Vec<String>,
+}
+
 pub(crate) struct AtorsClassInfo {
     frozen: bool,
     observable: bool,
     pickle_policy: PicklePolicy,
     mutability: Option<ClassMutability>,
     members_by_name: Py<MembersByNameMapping>,
+    /// Mapping of the member aliases to the name of the member they refer to.
+    member_aliases: HashMap<String, Py<PyString>>,
     specific_member_names: HashSet<String>,
     optional_init_member_names: Vec<Py<PyString>>,
     required_init_member_names: Vec<Py<PyString>>,
     method_names: HashSet<String>,
     abstract_methods: HashSet<String>,
     generic: Option<AtorsGenericInfo>,
+    /// WeakSet of the live instances of the class when instance tracking is enabled.
+    instance_registry: Option<Py<PyAny>>,
     customizer_tool: Option<Py<MemberCustomizationTool>>,
     event_customizer_tool: Option<Py<EventCustomizationTool>>,
     events_by_name: HashMap<String, Py<Event>>,
     specific_event_names: HashSet<String>,
+    invariants: Vec<Invariant>,
 }
 
 impl AtorsClassInfo {
@@ -230,6 +279,7 @@ impl AtorsClassInfo {
         events_by_name: HashMap<String, Py<Event>>,
         specific_event_names: HashSet<String>,
     ) -> PyResult<Self> {
+        let member_aliases = collect_member_aliases(py, &members_by_name)?;
         let members_by_name = Py::new(
             py,
             MembersByNameMapping::from_member_lookup(members_by_name),
@@ -240,16 +290,19 @@ impl AtorsClassInfo {
             pickle_policy,
             mutability,
             members_by_name,
+            member_aliases,
             specific_member_names,
             optional_init_member_names,
             required_init_member_names,
             method_names,
             abstract_methods,
             generic,
+            instance_registry: None,
             customizer_tool,
             event_customizer_tool,
             events_by_name,
             specific_event_names,
+            invariants: Vec::new(),
         })
     }
 
@@ -262,12 +315,14 @@ impl AtorsClassInfo {
         py: pyo3::Python<'_>,
         members_by_name: HashMap<String, Py<Member>>,
     ) -> PyResult<Self> {
+        let member_aliases = collect_member_aliases(py, &members_by_name)?;
         let members_by_name = Py::new(
             py,
             MembersByNameMapping::from_member_lookup(members_by_name),
         )?;
         Ok(Self {
             members_by_name,
+            member_aliases,
             ..self
         })
     }
@@ -279,10 +334,25 @@ impl AtorsClassInfo {
         }
     }
 
+    pub(crate) fn with_instance_registry(self, instance_registry: Option<Py<PyAny>>) -> Self {
+        Self {
+            instance_registry,
+            ..self
+        }
+    }
+
     pub(crate) fn with_mutability(self, mutability: Option<ClassMutability>) -> Self {
         Self { mutability, ..self }
     }
 
+    pub(crate) fn with_invariants(self, invariants: Vec<Invariant>) -> Self {
+        Self { invariants, ..self }
+    }
+
+    pub(crate) fn invariants(&self) -> &[Invariant] {
+        &self.invariants
+    }
+
     pub(crate) fn customizer(&self) -> Option<&Py<MemberCustomizationTool>> {
         self.customizer_tool.as_ref()
     }
@@ -311,6 +381,21 @@ impl AtorsClassInfo {
         self.observable
     }
 
+    pub(crate) fn instance_registry(&self) -> Option<&Py<PyAny>> {
+        self.instance_registry.as_ref()
+    }
+
+    /// Add an instance to the registry of live instances if the class tracks them.
+    pub(crate) fn register_instance(&self, obj: &Bound<'_, PyAny>) -> PyResult<()> {
+        if let Some(registry) = &self.instance_registry {
+            let registry = registry.bind(obj.py());
+            with_critical_section(registry, || {
+                registry.call_method1(intern!(obj.py(), "add"), (obj,))
+            })?;
+        }
+        Ok(())
+    }
+
     pub(crate) fn mutability(&self) -> Option<&ClassMutability> {
         self.mutability.as_ref()
     }
@@ -330,6 +415,11 @@ impl AtorsClassInfo {
         self.members_by_name.bind(py).borrow()
     }
 
+    /// Mapping of the member aliases to the name of the member they refer to.
+    pub(crate) fn member_aliases(&self) -> &HashMap<String, Py<PyString>> {
+        &self.member_aliases
+    }
+
     pub(crate) fn specific_member_names(&self) -> &HashSet<String> {
         &self.specific_member_names
     }
@@ -417,6 +507,47 @@ pub fn get_ators_init_member_names<'py>(cls: &Bound<'py, PyType>) -> PyResult<Bo
     Ok(PyTuple::new(py, init_member_names)?.into_any())
 }
 
+/// Return the live instances of `cls` and of its subclasses.
+///
+/// This is the Rust-backed data source for `AtorsMeta.instances`; it requires
+/// the class to have been created with `track_instances=True`.
+#[pyfunction]
+pub fn get_ators_instances<'py>(cls: &Bound<'py, PyType>) -> PyResult<Bound<'py, PyList>> {
+    let py = cls.py();
+    if get_class_info(cls)?.instance_registry().is_none() {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Class '{}' does not track its instances. \
+             Add `track_instances=True` to the class definition.",
+            cls.name()?
+        )));
+    }
+
+    let instances = PyList::empty(py);
+    let mut seen = HashSet::new();
+    let mut to_visit = vec![cls.clone()];
+    while let Some(current) = to_visit.pop() {
+        if !seen.insert(current.as_ptr() as usize) {
+            continue;
+        }
+        if let Some(registry) = get_class_info(&current)?.instance_registry() {
+            let registry = registry.bind(py);
+            with_critical_section(registry, || -> PyResult<()> {
+                for obj in registry.try_iter()? {
+                    instances.append(obj?)?;
+                }
+                Ok(())
+            })?;
+        }
+        for sub in current
+            .call_method0(intern!(py, "__subclasses__"))?
+            .try_iter()?
+        {
+            to_visit.push(sub?.cast_into()?);
+        }
+    }
+    Ok(instances)
+}
+
 /// Return whether `cls` is configured as frozen.
 #[pyfunction]
 pub fn get_ators_frozen_flag(cls: &Bound<'_, PyType>) -> PyResult<bool> {
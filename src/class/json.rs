@@ -0,0 +1,574 @@
+/*-----------------------------------------------------------------------------
+| Copyright (c) 2026, Ators contributors, see git history for details
+|
+| Distributed under the terms of the Modified BSD License.
+|
+| The full license is in the file LICENSE, distributed with this software.
+|----------------------------------------------------------------------------*/
+/// JSON serialization of Ators objects.
+///
+/// Encoding is driven by the values, while decoding is driven by the type
+/// validators of the members so that values which have no JSON counterpart
+/// (datetimes, enums, nested Ators objects, ...) can be rebuilt. Types lacking
+/// a native mapping are handled by codecs registered using register_json_codec.
+use pyo3::{
+    Bound, Py, PyAny, PyErr, PyResult, Python, intern, pyfunction,
+    sync::PyOnceLock,
+    types::{
+        PyAnyMethods, PyBool, PyDict, PyDictMethods, PyFloat, PyFloatMethods, PyFrozenSet, PyInt,
+        PyList, PyListMethods, PySet, PyString, PyTuple, PyTupleMethods, PyType, PyTypeMethods,
+    },
+};
+
+use crate::class::base::{
+    AtorsBase, maybe_freeze_instance_after_call, members_in_slot_order, resolve_member_aliases,
+};
+use crate::class::info::get_class_info;
+use crate::member::Member;
+use crate::utils::{err_with_cause, get_decimal_type};
+use crate::validators::TypeValidator;
+
+/// Registry mapping types to the (encode, decode) pair used for their values.
+static JSON_CODECS: PyOnceLock<Py<PyDict>> = PyOnceLock::new();
+
+fn json_codecs(py: Python<'_>) -> Bound<'_, PyDict> {
+    JSON_CODECS
+        .get_or_init(py, || PyDict::new(py).unbind())
+        .bind(py)
+        .clone()
+}
+
+/// Retrieve the codec registered for a type or its closest base, if any.
+fn find_codec<'py>(type_: &Bound<'py, PyType>) -> PyResult<Option<Bound<'py, PyTuple>>> {
+    let codecs = json_codecs(type_.py());
+    if codecs.is_empty() {
+        return Ok(None);
+    }
+    for base in type_.mro().iter() {
+        if let Some(codec) = codecs.get_item(base)? {
+            return Ok(Some(codec.cast_into::<PyTuple>()?));
+        }
+    }
+    Ok(None)
+}
+
+fn enum_type(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    py.import(intern!(py, "enum"))?.getattr(intern!(py, "Enum"))
+}
+
+/// Register the functions used to convert the values of a type to and from JSON.
+///
+/// encode receives a value of the type (or of a subclass) and must return a
+/// JSON compatible value. decode receives that JSON value and must return an
+/// instance of the type. Registering a codec for a type already having one
+/// replaces it.
+#[pyfunction]
+pub fn register_json_codec<'py>(
+    type_: &Bound<'py, PyType>,
+    encode: &Bound<'py, PyAny>,
+    decode: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    if !encode.is_callable() || !decode.is_callable() {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "The codec of {} expects callables to encode and decode values, got {} and {}",
+            type_.name()?,
+            encode.repr()?,
+            decode.repr()?
+        )));
+    }
+    json_codecs(type_.py()).set_item(type_, (encode, decode))
+}
+
+/// Convert values to objects which the json module can serialize.
+struct Encoder {
+    /// Name of the class of the serialized object, used in error messages.
+    root: String,
+    /// Addresses of the objects and containers being encoded, used to detect
+    /// reference cycles.
+    visiting: Vec<usize>,
+}
+
+impl Encoder {
+    fn error(&self, path: &str, problem: String) -> PyErr {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot serialize the member '{path}' of {} to JSON: {problem}",
+            self.root
+        ))
+    }
+
+    /// Encode a value, keeping track of the containers being visited.
+    fn encode_container<'py>(
+        &mut self,
+        value: &Bound<'py, PyAny>,
+        path: &str,
+        encode: impl FnOnce(&mut Self) -> PyResult<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let key = value.as_ptr() as usize;
+        if self.visiting.contains(&key) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Circular reference detected at the member '{path}' of {}",
+                self.root
+            )));
+        }
+        self.visiting.push(key);
+        let encoded = encode(self);
+        self.visiting.pop();
+        encoded
+    }
+
+    fn encode_object<'py>(
+        &mut self,
+        object: &Bound<'py, AtorsBase>,
+        path: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = object.py();
+        let info = get_class_info(&object.get_type())?;
+        let encoded = PyDict::new(py);
+        for (name, m) in members_in_slot_order(py, &info) {
+            if let Some(value) = crate::class::base::get_slot_owned(object, m.get().index()) {
+                let member_path = if path.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{path}.{name}")
+                };
+                encoded.set_item(&name, self.encode(value.bind(py), &member_path)?)?;
+            }
+        }
+        Ok(encoded.into_any())
+    }
+
+    /// Encode a dict key, which JSON requires to be a str (the json module
+    /// converting numbers, booleans and None).
+    fn encode_key<'py>(
+        &mut self,
+        key: &Bound<'py, PyAny>,
+        path: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let encoded = self.encode(key, path)?;
+        if encoded.is_none()
+            || encoded.is_instance_of::<PyString>()
+            || encoded.is_instance_of::<PyInt>()
+            || encoded.is_instance_of::<PyFloat>()
+        {
+            Ok(encoded)
+        } else {
+            Err(self.error(
+                path,
+                format!(
+                    "the key {} cannot be used as a JSON object key",
+                    key.repr()?
+                ),
+            ))
+        }
+    }
+
+    /// Encode a float, rejecting NaN and infinities which JSON cannot represent.
+    fn encode_float<'py>(
+        &self,
+        value: &Bound<'py, PyFloat>,
+        path: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if value.value().is_finite() {
+            Ok(value.clone().into_any())
+        } else {
+            Err(self.error(
+                path,
+                format!("the float {} is not a valid JSON number", value.repr()?),
+            ))
+        }
+    }
+
+    fn encode<'py>(
+        &mut self,
+        value: &Bound<'py, PyAny>,
+        path: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        if value.is_none()
+            || value.is_exact_instance_of::<PyBool>()
+            || value.is_exact_instance_of::<PyInt>()
+            || value.is_exact_instance_of::<PyString>()
+        {
+            return Ok(value.clone());
+        }
+        if let Ok(float) = value.cast_exact::<PyFloat>() {
+            return self.encode_float(float, path);
+        }
+        if let Ok(object) = value.cast::<AtorsBase>() {
+            return self.encode_container(value, path, |e| e.encode_object(object, path));
+        }
+        if value.is_instance(&enum_type(py)?)? {
+            return self.encode(&value.getattr(intern!(py, "value"))?, path);
+        }
+        if let Some(codec) = find_codec(&value.get_type())? {
+            let type_name = value.get_type().qualname()?;
+            return codec.get_item(0)?.call1((value,)).map_err(|err| {
+                err_with_cause(
+                    py,
+                    self.error(path, format!("the codec failed to encode {type_name}")),
+                    err,
+                )
+            });
+        }
+        if let Ok(float) = value.cast::<PyFloat>() {
+            return self.encode_float(float, path);
+        }
+        if value.is_instance_of::<PyInt>() || value.is_instance_of::<PyString>() {
+            return Ok(value.clone());
+        }
+        if let Ok(dict) = value.cast::<PyDict>() {
+            return self.encode_container(value, path, |e| {
+                let encoded = PyDict::new(py);
+                for (k, v) in dict.iter() {
+                    let item_path = format!("{path}[{}]", k.repr()?);
+                    encoded.set_item(e.encode_key(&k, &item_path)?, e.encode(&v, &item_path)?)?;
+                }
+                Ok(encoded.into_any())
+            });
+        }
+        if value.is_instance_of::<PyList>()
+            || value.is_instance_of::<PyTuple>()
+            || value.is_instance_of::<PySet>()
+            || value.is_instance_of::<PyFrozenSet>()
+        {
+            return self.encode_container(value, path, |e| {
+                let encoded = PyList::empty(py);
+                for (i, item) in value.try_iter()?.enumerate() {
+                    encoded.append(e.encode(&item?, &format!("{path}[{i}]"))?)?;
+                }
+                Ok(encoded.into_any())
+            });
+        }
+        Err(self.error(
+            path,
+            format!(
+                "no JSON codec is registered for {}",
+                value.get_type().qualname()?
+            ),
+        ))
+    }
+}
+
+/// Serialize an Ators object to a JSON string.
+///
+/// The set members are serialized under their names, unset members being
+/// omitted (defaults are not computed). Nested Ators objects become JSON
+/// objects, tuples and sets become arrays, enums are represented by their
+/// values and the other types use the codecs registered using
+/// register_json_codec. A TypeError naming the member is raised for values
+/// which cannot be serialized.
+#[pyfunction]
+#[pyo3(signature = (obj, *, indent = None))]
+pub fn to_json<'py>(
+    obj: &Bound<'py, AtorsBase>,
+    indent: Option<usize>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = obj.py();
+    let mut encoder = Encoder {
+        root: obj.get_type().name()?.to_string(),
+        visiting: Vec::new(),
+    };
+    let encoded = encoder.encode(obj.as_any(), "")?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(intern!(py, "indent"), indent)?;
+    // Values produced by codecs are not checked by the encoder.
+    kwargs.set_item(intern!(py, "allow_nan"), false)?;
+    py.import(intern!(py, "json"))?
+        .getattr(intern!(py, "dumps"))?
+        .call((encoded,), Some(&kwargs))
+}
+
+/// Convert JSON values to the values expected by the type validators.
+struct Decoder {
+    /// Name of the class being deserialized, used in error messages.
+    root: String,
+}
+
+impl Decoder {
+    fn error(&self, py: Python<'_>, path: &str, data: &Bound<'_, PyAny>, err: PyErr) -> PyErr {
+        let repr = data
+            .repr()
+            .map(|r| r.to_string())
+            .unwrap_or_else(|_| "<unrepresentable>".to_string());
+        err_with_cause(
+            py,
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Cannot deserialize the JSON value {repr} of the member '{path}' of {}",
+                self.root
+            )),
+            err,
+        )
+    }
+
+    /// Build an instance of an Ators class from a JSON object.
+    ///
+    /// Init members are passed to the class and the other members are set
+    /// afterwards, so that all the values are validated.
+    fn decode_object<'py>(
+        &self,
+        cls: &Bound<'py, PyType>,
+        data: &Bound<'py, PyDict>,
+        path: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = cls.py();
+        let info = get_class_info(cls)?;
+        let data = resolve_member_aliases(data, &info)?;
+        let kwargs = PyDict::new(py);
+        let mut later = Vec::new();
+        for (key, value) in data.iter() {
+            let member = key
+                .extract::<&str>()
+                .ok()
+                .and_then(|k| info.members_by_name_ref(py).get(k).map(|m| m.clone_ref(py)));
+            let Some(member) = member else {
+                kwargs.set_item(key, value)?;
+                continue;
+            };
+            let m: &Member = member.get();
+            let member_path = if path.is_empty() {
+                m.name().to_string()
+            } else {
+                format!("{path}.{}", m.name())
+            };
+            let decoded = self.decode(&m.validator().type_validator, &value, &member_path)?;
+            if info.is_init_member_name(py, m.name()) {
+                kwargs.set_item(key, decoded)?;
+            } else {
+                later.push((key, decoded));
+            }
+        }
+        if later.is_empty() {
+            return cls.call((), Some(&kwargs));
+        }
+        // Members excluded from __init__ are set before the post-construction
+        // steps of the metaclass call (which freeze frozen classes), so that
+        // frozen objects can be decoded.
+        let object = py
+            .get_type::<PyType>()
+            .getattr(intern!(py, "__call__"))?
+            .call((cls,), Some(&kwargs))?;
+        for (key, value) in later {
+            object.setattr(key.cast_into::<PyString>()?, value)?;
+        }
+        maybe_freeze_instance_after_call(object)
+    }
+
+    fn decode_items<'py>(
+        &self,
+        item: Option<&TypeValidator>,
+        data: &Bound<'py, PyList>,
+        path: &str,
+    ) -> PyResult<Vec<Bound<'py, PyAny>>> {
+        data.iter()
+            .enumerate()
+            .map(|(i, v)| match item {
+                Some(item) => self.decode(item, &v, &format!("{path}[{i}]")),
+                None => Ok(v),
+            })
+            .collect()
+    }
+
+    /// Decode a JSON object key, which is always a str, for a key validator
+    /// expecting numbers.
+    fn decode_key<'py>(
+        &self,
+        key_validator: &TypeValidator,
+        key: &Bound<'py, PyAny>,
+        path: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = key.py();
+        let number_type = match key_validator {
+            TypeValidator::Int {} => Some(py.get_type::<PyInt>()),
+            TypeValidator::Float {} => Some(py.get_type::<PyFloat>()),
+            _ => None,
+        };
+        match number_type {
+            Some(t) if key.is_instance_of::<PyString>() => t
+                .call1((key,))
+                .map_err(|err| self.error(py, path, key, err)),
+            _ => self.decode(key_validator, key, path),
+        }
+    }
+
+    fn decode<'py>(
+        &self,
+        type_validator: &TypeValidator,
+        data: &Bound<'py, PyAny>,
+        path: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = data.py();
+        match type_validator {
+            TypeValidator::Typed { type_ } | TypeValidator::GenericAttributes { type_, .. } => {
+                let type_ = type_.bind(py);
+                if type_.is_subclass_of::<AtorsBase>()?
+                    && let Ok(dict) = data.cast::<PyDict>()
+                {
+                    return self.decode_object(type_, dict, path);
+                }
+                self.decode_with_codec(type_, data, path)
+            }
+            TypeValidator::Bytes {} => {
+                self.decode_with_codec(&py.get_type::<pyo3::types::PyBytes>(), data, path)
+            }
+            TypeValidator::ByteArray {} => {
+                self.decode_with_codec(&py.get_type::<pyo3::types::PyByteArray>(), data, path)
+            }
+            TypeValidator::MemoryView {} => {
+                self.decode_with_codec(&py.get_type::<pyo3::types::PyMemoryView>(), data, path)
+            }
+            TypeValidator::Decimal {} => self.decode_with_codec(get_decimal_type(py), data, path),
+            TypeValidator::Enum { enum_type } | TypeValidator::FlagEnum { enum_type } => {
+                let enum_type = enum_type.bind(py);
+                if data.is_instance(enum_type)? {
+                    return Ok(data.clone());
+                }
+                enum_type
+                    .call1((data,))
+                    .map_err(|err| self.error(py, path, data, err))
+            }
+            TypeValidator::Tuple { items, .. } => match data.cast::<PyList>() {
+                Ok(list) => {
+                    let decoded = list
+                        .iter()
+                        .enumerate()
+                        .map(|(i, v)| match items.get(i) {
+                            Some(item) => {
+                                self.decode(&item.type_validator, &v, &format!("{path}[{i}]"))
+                            }
+                            None => Ok(v),
+                        })
+                        .collect::<PyResult<Vec<_>>>()?;
+                    Ok(PyTuple::new(py, decoded)?.into_any())
+                }
+                Err(_) => Ok(data.clone()),
+            },
+            TypeValidator::VarTuple { item, .. }
+            | TypeValidator::List { item }
+            | TypeValidator::Set { item }
+            | TypeValidator::FrozenSet { item }
+            | TypeValidator::Iterable { item, .. }
+            | TypeValidator::Sequence { item } => {
+                let Ok(list) = data.cast::<PyList>() else {
+                    return Ok(data.clone());
+                };
+                let decoded =
+                    self.decode_items(item.as_ref().map(|i| &i.type_validator), list, path)?;
+                Ok(match type_validator {
+                    TypeValidator::VarTuple { .. } => PyTuple::new(py, decoded)?.into_any(),
+                    TypeValidator::Set { .. } => PySet::new(py, decoded)?.into_any(),
+                    TypeValidator::FrozenSet { .. } => PyFrozenSet::new(py, decoded)?.into_any(),
+                    _ => PyList::new(py, decoded)?.into_any(),
+                })
+            }
+            TypeValidator::Dict { items } | TypeValidator::Mapping { items } => {
+                let (Some((key_validator, value_validator)), Ok(dict)) =
+                    (items, data.cast::<PyDict>())
+                else {
+                    return Ok(data.clone());
+                };
+                let decoded = PyDict::new(py);
+                for (k, v) in dict.iter() {
+                    let item_path = format!("{path}[{}]", k.repr()?);
+                    decoded.set_item(
+                        self.decode_key(&key_validator.type_validator, &k, &item_path)?,
+                        self.decode(&value_validator.type_validator, &v, &item_path)?,
+                    )?;
+                }
+                Ok(decoded.into_any())
+            }
+            TypeValidator::TypedDict { fields, .. } => {
+                let Ok(dict) = data.cast::<PyDict>() else {
+                    return Ok(data.clone());
+                };
+                let decoded = PyDict::new(py);
+                for (k, v) in dict.iter() {
+                    let field = k
+                        .extract::<&str>()
+                        .ok()
+                        .and_then(|k| fields.iter().find(|(key, ..)| key == k));
+                    match field {
+                        Some((key, validator, _)) => decoded.set_item(
+                            &k,
+                            self.decode(
+                                &validator.type_validator,
+                                &v,
+                                &format!("{path}[{key:?}]"),
+                            )?,
+                        )?,
+                        None => decoded.set_item(k, v)?,
+                    }
+                }
+                Ok(decoded.into_any())
+            }
+            // The first member for which the decoded value is valid wins.
+            TypeValidator::Union { members } => {
+                for m in members {
+                    if let Ok(decoded) = self.decode(&m.type_validator, data, path)
+                        && m.validate(None, None, &decoded).is_ok()
+                    {
+                        return Ok(decoded);
+                    }
+                }
+                Ok(data.clone())
+            }
+            TypeValidator::Optional { inner } if !data.is_none() => {
+                self.decode(&inner.type_validator, data, path)
+            }
+            TypeValidator::ForwardValidator { late_validator } => {
+                self.decode(late_validator.get_validator(py)?.get(), data, path)
+            }
+            _ => Ok(data.clone()),
+        }
+    }
+
+    /// Decode a value using the codec registered for the expected type, if any.
+    fn decode_with_codec<'py>(
+        &self,
+        type_: &Bound<'py, PyType>,
+        data: &Bound<'py, PyAny>,
+        path: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = data.py();
+        match find_codec(type_)? {
+            Some(codec) if !data.is_instance(type_)? => codec
+                .get_item(1)?
+                .call1((data,))
+                .map_err(|err| self.error(py, path, data, err)),
+            _ => Ok(data.clone()),
+        }
+    }
+}
+
+/// Build an instance of an Ators class from a JSON string.
+///
+/// The JSON values are converted using the type validators of the members
+/// (nested Ators classes, containers, enums and types having a registered
+/// codec) and then go through the usual validation when the object is built.
+#[pyfunction]
+pub fn from_json<'py>(
+    cls: &Bound<'py, PyType>,
+    s: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = cls.py();
+    if !cls.is_subclass_of::<AtorsBase>()? {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Expected an Ators class, got {}",
+            cls.name()?
+        )));
+    }
+    let data = py
+        .import(intern!(py, "json"))?
+        .getattr(intern!(py, "loads"))?
+        .call1((s,))?;
+    let Ok(dict) = data.cast::<PyDict>() else {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Expected a JSON object to build an instance of {}, got {}",
+            cls.name()?,
+            data.repr()?
+        )));
+    };
+    let decoder = Decoder {
+        root: cls.name()?.to_string(),
+    };
+    decoder.decode_object(cls, dict, "")
+}
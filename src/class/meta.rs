@@ -23,18 +23,19 @@ use crate::{
     class::base::AtorsBase,
     class::generic::get_generic_params_obj,
     class::info::{
-        AtorsClassInfo, AtorsGenericInfo, ClassMutability, PicklePolicy, get_class_info,
+        AtorsClassInfo, AtorsGenericInfo, ClassMutability, Invariant, PicklePolicy, get_class_info,
         insert_definitive_class_info, insert_temp_class_info, pop_temp_class_info,
         take_pending_specialization_bindings_for_inputs,
     },
     event::{Event, EventBuilder, EventCustomizationTool},
     member::PreGetattrBehavior,
+    member::{ClassMemberBuilder, MemberBuilder, MemberCustomizationTool},
     member::{
-        DefaultBehavior, Member, PostGetattrBehavior, PostSetattrBehavior, PreSetattrBehavior,
+        DefaultBehavior, Member, ObserverBehavior, PostGetattrBehavior, PostSetattrBehavior,
+        PreSetattrBehavior,
     },
-    member::{MemberBuilder, MemberCustomizationTool},
     utils::Mutability,
-    validators::{Coercer, ValueValidator},
+    validators::{Coercer, Normalizer, Validator, ValueValidator},
 };
 
 fn mro_from_bases<'py>(bases: &Bound<'py, PyTuple>) -> PyResult<Vec<Bound<'py, PyType>>> {
@@ -123,6 +124,133 @@ fn make_unknown_method_error<'py>(
     ))
 }
 
+/// Find the function implementing a behavior method, looking first at the
+/// class namespace and then at the classes of the mro.
+///
+/// Only plain functions are returned since they are the only objects for
+/// which the method collection logic records a name.
+fn find_behavior_method<'py>(
+    dct: &Bound<'py, PyDict>,
+    mro: &[Bound<'py, PyType>],
+    meth_name: &Bound<'py, PyString>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = dct.py();
+    if let Some(f) = dct.get_item(meth_name)? {
+        return Ok(f.is_exact_instance_of::<PyFunction>().then_some(f));
+    }
+    for base in mro {
+        let base_dict = base
+            .getattr(intern!(py, "__dict__"))?
+            .cast_into::<PyMapping>()?;
+        if base_dict.contains(meth_name)? {
+            let f = base_dict.get_item(meth_name)?;
+            return Ok(f.is_exact_instance_of::<PyFunction>().then_some(f));
+        }
+    }
+    Ok(None)
+}
+
+/// Ensure a method referenced by an ObjectMethod behavior exists and can be
+/// called with the arguments the behavior will pass to it.
+///
+/// `signature` lists the names of the expected parameters (including self) and
+/// is used both to check the method and to build the error message.
+fn check_behavior_method<'py>(
+    member_name: &String,
+    behavior_name: &str,
+    meth_name: &Py<PyString>,
+    signature: &[&str],
+    methods: &Bound<'py, PySet>,
+    dct: &Bound<'py, PyDict>,
+    mro: &[Bound<'py, PyType>],
+) -> PyResult<()> {
+    let py = dct.py();
+    let meth_name_b = meth_name.bind(py);
+    if !methods.contains(meth_name_b)? {
+        return Err(make_unknown_method_error(
+            member_name,
+            behavior_name,
+            meth_name,
+            methods,
+        ));
+    }
+    let Some(method) = find_behavior_method(dct, mro, meth_name_b)? else {
+        return Ok(());
+    };
+    let method_sig = py
+        .import(intern!(py, "inspect"))?
+        .getattr(intern!(py, "signature"))?
+        .call1((&method,))?;
+    let args = PyTuple::new(py, signature.iter().map(|_| py.None()))?;
+    if let Err(err) = method_sig.call_method1(intern!(py, "bind"), args) {
+        if !err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) {
+            return Err(err);
+        }
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Member {member_name} {behavior_name} behavior reference method {} \
+            whose signature {} is incompatible with the expected signature ({}).",
+            meth_name_b.repr()?,
+            method_sig.str()?,
+            signature.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Collect the invariants declared on a class and its bases using the
+/// invariant decorator, checking that they only reference existing members.
+///
+/// Methods are looked up following the mro so that overriding a method
+/// without decorating it removes the invariant.
+fn collect_invariants<'py>(
+    cls: &Bound<'py, PyType>,
+    members_by_name: &HashMap<String, Py<Member>>,
+) -> PyResult<Vec<Invariant>> {
+    let py = cls.py();
+    let marker = intern!(py, "__ators_invariant__");
+    let mut seen = HashSet::new();
+    let mut invariants = Vec::new();
+    for base in cls.mro().iter() {
+        let base_dict = base
+            .getattr(intern!(py, "__dict__"))?
+            .cast_into::<PyMapping>()?;
+        for item in base_dict.items()?.iter() {
+            let (meth_name, method) =
+                item.extract::<(Bound<'py, PyString>, Bound<'py, PyAny>)>()?;
+            if !seen.insert(meth_name.to_string()) || !method.is_exact_instance_of::<PyFunction>() {
+                continue;
+            }
+            let Some(names) = method.getattr_opt(marker)? else {
+                continue;
+            };
+            let names: Vec<String> = names.extract()?;
+            let mut unknown: Vec<&str> = names
+                .iter()
+                .filter(|n| !members_by_name.contains_key(n.as_str()))
+                .map(String::as_str)
+                .collect();
+            if !unknown.is_empty() {
+                unknown.sort();
+                let mut known: Vec<&str> = members_by_name.keys().map(String::as_str).collect();
+                known.sort();
+                return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Invariant {} of {} references member(s) {} which do not exist. \
+                    Known members are {}",
+                    meth_name.repr()?,
+                    cls.name()?,
+                    unknown.join(", "),
+                    known.join(", ")
+                )));
+            }
+            invariants.push(Invariant {
+                meth_name: meth_name.unbind(),
+                members: names,
+            });
+        }
+    }
+    Ok(invariants)
+}
+
 /// Return `true` if `obj` is marked as abstract via `__isabstractmethod__ == True`.
 ///
 /// This handles plain functions/methods and also inspects the wrapped callable
@@ -173,6 +301,157 @@ fn is_abstract_member(obj: &Bound<'_, PyAny>) -> bool {
     false
 }
 
+/// Check whether instances of a type can be weakly referenced.
+fn supports_weakrefs(ty: &Bound<'_, PyType>) -> bool {
+    ty.getattr(intern!(ty.py(), "__weakrefoffset__"))
+        .and_then(|offset| offset.extract::<isize>())
+        .is_ok_and(|offset| offset != 0)
+}
+
+/// Check whether a type was created from Python (rather than being a native type).
+fn is_heap_type(ty: &Bound<'_, PyType>) -> bool {
+    // Safety: ty is a valid type object.
+    unsafe { pyo3::ffi::PyType_GetFlags(ty.as_type_ptr()) & pyo3::ffi::Py_TPFLAGS_HEAPTYPE != 0 }
+}
+
+/// Check that the non-Ators classes of the mro can share the instance layout
+/// of Ators classes.
+///
+/// Compatible bases are Python classes without __slots__ or whose __slots__
+/// only contain '__dict__' and '__weakref__', and native classes without
+/// instance data of their own. Other bases would make the class creation fail
+/// with an obscure instance lay-out conflict so a clearer error is raised.
+fn check_base_layouts(
+    name: &Bound<'_, PyString>,
+    mro: &[Bound<'_, PyType>],
+    ators_base_ty: &Bound<'_, PyType>,
+) -> PyResult<()> {
+    let py = name.py();
+    let object_ty = py.get_type::<PyAny>();
+    let basicsize = intern!(py, "__basicsize__");
+    let object_size = object_ty.getattr(basicsize)?.extract::<isize>()?;
+    for ty in mro {
+        if ty.is(&object_ty) || ty.is_subclass(ators_base_ty)? {
+            continue;
+        }
+        let slots = ty
+            .getattr(intern!(py, "__dict__"))?
+            .call_method1(intern!(py, "get"), (intern!(py, "__slots__"),))?;
+        if !slots.is_none() {
+            let names = if slots.is_instance_of::<PyString>() {
+                vec![slots.extract::<String>()?]
+            } else {
+                slots
+                    .try_iter()?
+                    .map(|n| n?.extract::<String>())
+                    .collect::<PyResult<Vec<_>>>()?
+            };
+            let names = names
+                .into_iter()
+                .filter(|n| n != "__dict__" && n != "__weakref__")
+                .map(|n| format!("'{n}'"))
+                .collect::<Vec<_>>();
+            if !names.is_empty() {
+                return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Cannot create the Ators class {name} since its base {} defines the slots \
+                     {} which conflict with the instance layout of Ators classes. Only the \
+                     '__dict__' and '__weakref__' slots are supported on non-Ators bases.",
+                    ty.qualname()?,
+                    names.join(", ")
+                )));
+            }
+        } else if !is_heap_type(ty) && ty.getattr(basicsize)?.extract::<isize>()? != object_size {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Cannot create the Ators class {name} since its base {} has an instance \
+                 layout of its own which conflicts with the one of Ators classes.",
+                ty.qualname()?
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Builders for the members and events declared through annotations on the
+/// non-Ators bases of a class.
+type PlainBaseBuilders = (
+    HashMap<String, MemberBuilder>,
+    HashMap<String, EventBuilder>,
+);
+
+/// Build members from the annotations of the non-Ators classes found in the mro.
+///
+/// Plain classes do not reserve any slot for their annotated attributes so the
+/// generated members are handled as members newly defined on the class being
+/// created. Bases are visited in mro order so that the first annotation of a
+/// name wins, and names already used by a member of an Ators base are skipped
+/// since those members already own a slot.
+fn generate_member_builders_from_plain_bases<'py>(
+    mro: &[Bound<'py, PyType>],
+    ators_base_ty: &Bound<'py, PyType>,
+    type_containers: i64,
+    typevar_bindings: Option<&Bound<'py, PyDict>>,
+    validate_attr: bool,
+) -> PyResult<PlainBaseBuilders> {
+    let Some(first) = mro.first() else {
+        return Ok(Default::default());
+    };
+    let py = first.py();
+    let ators_member_names = mro
+        .iter()
+        .filter(|b| b.is_subclass(ators_base_ty).unwrap_or(false) && !b.is(ators_base_ty))
+        .map(|b| {
+            get_class_info(b).map(|info| {
+                info.members_by_name_ref(py)
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<HashSet<_>>();
+
+    let mut member_builders = HashMap::new();
+    let mut event_builders = HashMap::new();
+    for base in mro {
+        if base.is_subclass(ators_base_ty)? {
+            continue;
+        }
+        // Work on a copy of the namespace since generating the builders
+        // removes the consumed entries.
+        let namespace = PyDict::new(py);
+        for item in base
+            .getattr(intern!(py, "__dict__"))?
+            .cast_into::<PyMapping>()?
+            .items()?
+            .iter()
+        {
+            let (k, v) = item.extract::<(Bound<'py, PyAny>, Bound<'py, PyAny>)>()?;
+            namespace.set_item(k, v)?;
+        }
+        let (mbs, ebs) = generate_member_builders_from_cls_namespace(
+            &base.name()?,
+            &namespace,
+            type_containers,
+            typevar_bindings,
+            validate_attr,
+            false,
+        )?;
+        for (k, mb) in mbs {
+            if !ators_member_names.contains(&k) && !event_builders.contains_key(&k) {
+                member_builders.entry(k).or_insert(mb);
+            }
+        }
+        for (k, eb) in ebs {
+            if !ators_member_names.contains(&k) && !member_builders.contains_key(&k) {
+                event_builders.entry(k).or_insert(eb);
+            }
+        }
+    }
+    Ok((member_builders, event_builders))
+}
+
 /// Create an Ators subclass from metaclass inputs.
 ///
 /// This computes member layout and inherited behaviors, enforces Ators class
@@ -190,6 +469,9 @@ pub fn create_ators_subclass<'py>(
     type_containers: i64,
     pickle_policy: Option<PicklePolicy>,
     validate_attr: bool,
+    track_instances: bool,
+    plain_base_members: bool,
+    strict_body: bool,
 ) -> PyResult<Bound<'py, PyAny>> {
     let py = name.py();
 
@@ -205,6 +487,7 @@ pub fn create_ators_subclass<'py>(
 
     let ators_base_ty = py.get_type::<AtorsBase>();
     let mro = mro_from_bases(&bases)?;
+    check_base_layouts(&name, &mro, &ators_base_ty)?;
     let is_observable = observable
         || mro.iter().any(|b| {
             b.cast::<PyType>()
@@ -212,6 +495,10 @@ pub fn create_ators_subclass<'py>(
                 .and_then(|base_ty| get_class_info(base_ty).ok())
                 .is_some_and(|info| info.observable())
         });
+    let tracks_instances = track_instances
+        || mro
+            .iter()
+            .any(|b| get_class_info(b).is_ok_and(|info| info.instance_registry().is_some()));
 
     // Resolve the pickle policy: honour an explicit value, then inherit from the first
     // base class that defines one; fall back to `ALL` (the default) if none does.
@@ -229,13 +516,9 @@ pub fn create_ators_subclass<'py>(
             .unwrap_or(PicklePolicy::All)
     };
 
-    // Since all classes deriving from Ators are slotted, we only need to check
-    // for non-empty slots to know if a base class supports weakrefs.
-    if enable_weakrefs
-        && !mro
-            .iter()
-            .any(|b| b.hasattr(slot_name).expect("Hasattr cannot fail."))
-    {
+    // Only add the __weakref__ slot if no base class already supports weakrefs
+    // (since all Ators classes are slotted, every base defines __slots__).
+    if enable_weakrefs && !mro.iter().any(supports_weakrefs) {
         dct.set_item(slot_name, (intern!(py, "__weakref__"),))?;
     } else {
         dct.set_item(slot_name, ())?;
@@ -251,8 +534,32 @@ pub fn create_ators_subclass<'py>(
         type_containers,
         typevar_bindings_ref,
         validate_attr,
+        strict_body,
     )?;
 
+    // Harvest the members declared on plain annotated bases if requested. The
+    // members and events declared by the class itself take precedence.
+    if plain_base_members {
+        let (plain_member_builders, plain_event_builders) =
+            generate_member_builders_from_plain_bases(
+                &mro,
+                &ators_base_ty,
+                type_containers,
+                typevar_bindings_ref,
+                validate_attr,
+            )?;
+        for (k, mb) in plain_member_builders {
+            if !dct.contains(&k)? && !event_builders.contains_key(&k) {
+                member_builders.entry(k).or_insert(mb);
+            }
+        }
+        for (k, eb) in plain_event_builders {
+            if !dct.contains(&k)? && !member_builders.contains_key(&k) {
+                event_builders.entry(k).or_insert(eb);
+            }
+        }
+    }
+
     // Collect the new members defined in this class that require the owning
     // class to be set to resolve ForwardRef
     let members_requiring_owner = member_builders
@@ -429,6 +736,7 @@ pub fn create_ators_subclass<'py>(
     let mut unannotated_event_builder_ids = HashMap::new();
     let mut declared_abstract_methods: HashSet<String> = HashSet::new();
     let mut concrete_names: HashSet<String> = HashSet::new();
+    let mut unannotated_class_members = Vec::new();
     for (k, v) in dct.iter() {
         let k_str: String = k.extract()?;
         if is_abstract_member(&v) {
@@ -457,6 +765,10 @@ pub fn create_ators_subclass<'py>(
                 });
             }
             member_builders.insert(k_str, mb.extract()?);
+        } else if let Ok(cmb) = v.cast::<ClassMemberBuilder>() {
+            // Bare class_member() builder (no annotation): no validation.
+            unannotated_class_members
+                .push((k.clone(), cmb.get().build(py, k_str, Validator::default())?));
         } else if let Ok(eb) = v.cast_into::<EventBuilder>() {
             // Bare event() builder (no annotation): only valid when inherit=True.
             let inherits = with_critical_section(eb.as_any(), || eb.borrow().should_inherit());
@@ -485,6 +797,9 @@ pub fn create_ators_subclass<'py>(
             event_builders.insert(k_str, eb.extract()?);
         }
     }
+    for (k, cm) in unannotated_class_members {
+        dct.set_item(k, cm)?;
+    }
 
     // Compute the final set of unresolved abstract methods:
     // start from inherited set, remove names overridden concretely in this class,
@@ -502,9 +817,17 @@ pub fn create_ators_subclass<'py>(
         specific_members.insert(k.clone());
 
         // Resolve the init flag: honour an explicit user value, then fall back
-        // to the name-based default (public → true, private → false).
-        if mb.init.is_none() {
-            mb.init = Some(!k.starts_with('_'));
+        // to the name-based default (public → true, private → false). Constant
+        // members can never be set and are hence never init members.
+        let constant = matches!(mb.pre_setattr(), Some(PreSetattrBehavior::Constant {}));
+        match mb.init {
+            None => mb.init = Some(!k.starts_with('_') && !constant),
+            Some(true) if constant => {
+                return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Member {k} of {name} is constant and hence cannot be set at init."
+                )));
+            }
+            Some(_) => (),
         }
         // Resolve the pickle flag: honour an explicit user value, then fall back
         // to the class policy.
@@ -540,78 +863,61 @@ pub fn create_ators_subclass<'py>(
             }
         }
 
-        // FIXME low prio (use a macro to reduce repetition)
-        // Ensure all the method the members are using do exist.
-        if let Some(PreGetattrBehavior::ObjectMethod { meth_name }) = mb.pre_getattr()
-            && !methods.contains(meth_name.bind(py))?
+        // Ensure all the methods the members are using do exist and accept
+        // the arguments the behaviors will pass to them.
+        let check = |behavior_name: &str, meth_name: &Py<PyString>, signature: &[&str]| {
+            check_behavior_method(k, behavior_name, meth_name, signature, &methods, &dct, &mro)
+        };
+        if let Some(
+            PreGetattrBehavior::ObjectMethod { meth_name }
+            | PreGetattrBehavior::ComputeObjectMethod { meth_name },
+        ) = mb.pre_getattr()
         {
-            return Err(make_unknown_method_error(
-                k,
-                "pre_getattr",
-                meth_name,
-                &methods,
-            ));
+            check("pre_getattr", meth_name, &["self", "member"])?;
         }
-        if let Some(PostGetattrBehavior::ObjectMethod { meth_name }) = mb.post_getattr()
-            && !methods.contains(meth_name.bind(py))?
-        {
-            return Err(make_unknown_method_error(
-                k,
-                "post_getattr",
-                meth_name,
-                &methods,
-            ));
+        if let Some(PreGetattrBehavior::Getter { meth_name }) = mb.pre_getattr() {
+            check("getter", meth_name, &["self"])?;
         }
-        if let Some(PreSetattrBehavior::ObjectMethod { meth_name }) = mb.pre_setattr()
-            && !methods.contains(meth_name.bind(py))?
-        {
-            return Err(make_unknown_method_error(
-                k,
-                "pre_setattr",
-                meth_name,
-                &methods,
-            ));
+        if let Some(PostGetattrBehavior::ObjectMethod { meth_name }) = mb.post_getattr() {
+            check("post_getattr", meth_name, &["self", "member", "value"])?;
         }
-        if let Some(PostSetattrBehavior::ObjectMethod { meth_name }) = mb.post_setattr()
-            && !methods.contains(meth_name.bind(py))?
-        {
-            return Err(make_unknown_method_error(
-                k,
-                "post_setattr",
-                meth_name,
-                &methods,
-            ));
+        if let Some(PreSetattrBehavior::ObjectMethod { meth_name }) = mb.pre_setattr() {
+            check("pre_setattr", meth_name, &["self", "member", "current"])?;
         }
-        if let Some(DefaultBehavior::ObjectMethod { meth_name }) = mb.default_behavior()
-            && !methods.contains(meth_name.bind(py))?
-        {
-            return Err(make_unknown_method_error(k, "default", meth_name, &methods));
+        if let Some(PostSetattrBehavior::ObjectMethod { meth_name }) = mb.post_setattr() {
+            check("post_setattr", meth_name, &["self", "member", "old", "new"])?;
         }
-        if let Some(Coercer::ObjectMethod { meth_name }) = mb.coercer()
-            && !methods.contains(meth_name.bind(py))?
-        {
-            return Err(make_unknown_method_error(k, "coerce", meth_name, &methods));
+        if let Some(PostSetattrBehavior::Setter { meth_name }) = mb.post_setattr() {
+            check("setter", meth_name, &["self", "value"])?;
         }
-        if let Some(Coercer::ObjectMethod { meth_name }) = mb.init_coercer()
-            && !methods.contains(meth_name.bind(py))?
-        {
-            return Err(make_unknown_method_error(
-                k,
+        if let Some(DefaultBehavior::ObjectMethod { meth_name }) = mb.default_behavior() {
+            check("default", meth_name, &["self", "member"])?;
+        }
+        if let Some(ObserverBehavior::ObjectMethod { meth_name }) = mb.observer() {
+            check("observer", meth_name, &["self", "change"])?;
+        }
+        if let Some(Coercer::ObjectMethod { meth_name }) = mb.coercer() {
+            check(
+                "coerce",
+                meth_name,
+                &["self", "member", "value", "is_init_coercion"],
+            )?;
+        }
+        if let Some(Coercer::ObjectMethod { meth_name }) = mb.init_coercer() {
+            check(
                 "coerce_init",
                 meth_name,
-                &methods,
-            ));
+                &["self", "member", "value", "is_init_coercion"],
+            )?;
         }
         for vv in mb.value_validators().map_or(&Vec::new(), |v| v) {
-            if let ValueValidator::ObjectMethod { meth_name } = vv.clone()
-                && !methods.contains(meth_name.bind(py))?
-            {
-                return Err(make_unknown_method_error(
-                    k,
-                    "value_validator",
-                    &meth_name,
-                    &methods,
-                ));
+            if let ValueValidator::ObjectMethod { meth_name } = vv {
+                check("value_validator", meth_name, &["self", "member", "value"])?;
+            }
+        }
+        for n in mb.normalizers().map_or(&Vec::new(), |v| v) {
+            if let Normalizer::ObjectMethod { meth_name } = n {
+                check("normalizer", meth_name, &["self", "member", "value"])?;
             }
         }
     }
@@ -701,7 +1007,10 @@ pub fn create_ators_subclass<'py>(
         let member = member.bind(py).get();
         if member.init {
             let n = PyString::new(py, member_name).unbind();
-            if member.has_default() {
+            // Computed members never read their slot and store backed members
+            // can read their value from the store, hence neither needs to be
+            // provided.
+            if member.has_default() || member.is_computed() || member.is_store_backed() {
                 optional_init_member_names.push(n);
             } else {
                 required_init_member_names.push(n);
@@ -743,6 +1052,24 @@ pub fn create_ators_subclass<'py>(
         }
     };
     let mut class_info = pop_temp_class_info(py, &fqname);
+
+    // Instances are tracked through weak references so the class must support them.
+    let instance_registry = if tracks_instances {
+        if !supports_weakrefs(&cls) {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Class '{name}' tracks its instances but does not support weak references. \
+                 Add `enable_weakrefs=True` to the class definition."
+            )));
+        }
+        Some(
+            py.import(intern!(py, "weakref"))?
+                .getattr(intern!(py, "WeakSet"))?
+                .call0()?
+                .unbind(),
+        )
+    } else {
+        None
+    };
     let mut updated_members_by_name = class_info
         .members_by_name_ref(py)
         .iter()
@@ -867,11 +1194,15 @@ pub fn create_ators_subclass<'py>(
         None
     };
 
+    let invariants = collect_invariants(&cls, &updated_members_by_name)?;
+
     let final_class_info = class_info
         .with_members(py, updated_members_by_name)?
         .with_events(updated_events_by_name)
         .with_generic(generic)
-        .with_mutability(Some(class_mutability));
+        .with_instance_registry(instance_registry)
+        .with_mutability(Some(class_mutability))
+        .with_invariants(invariants);
 
     insert_definitive_class_info(py, &cls, final_class_info);
 
@@ -0,0 +1,311 @@
+/*-----------------------------------------------------------------------------
+| Copyright (c) 2025-2026, Ators contributors, see git history for details
+|
+| Distributed under the terms of the Modified BSD License.
+|
+| The full license is in the file LICENSE, distributed with this software.
+|----------------------------------------------------------------------------*/
+/// Generation of JSON-Schema-like descriptions of Ators classes.
+use pyo3::{
+    Bound, PyAny, PyResult, Python, pyfunction,
+    types::{
+        PyAnyMethods, PyDict, PyDictMethods, PyFrozenSetMethods, PyList, PyListMethods,
+        PyStringMethods, PyTupleMethods, PyType, PyTypeMethods,
+    },
+};
+
+use crate::class::base::{AtorsBase, resolve_class_for_obj};
+use crate::class::info::get_class_info;
+use crate::member::DefaultBehavior;
+use crate::validators::{TypeValidator, Validator, ValueValidator};
+
+/// Keyword used to attach human readable notes to a schema fragment.
+const NOTE_KEY: &str = "$comment";
+
+/// Build the schema fragment describing a type validator.
+///
+/// `visiting` holds the Ators classes whose schema is currently being built
+/// and is used to break recursive class references.
+fn type_validator_schema<'py>(
+    py: Python<'py>,
+    type_validator: &TypeValidator,
+    visiting: &mut Vec<usize>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let schema = PyDict::new(py);
+    match type_validator {
+        TypeValidator::Any {} => {}
+        TypeValidator::None {} => schema.set_item("type", "null")?,
+        TypeValidator::Never {} => schema.set_item("not", PyDict::new(py))?,
+        TypeValidator::Bool {} => schema.set_item("type", "boolean")?,
+        TypeValidator::Int {} | TypeValidator::FlagEnum { .. } => {
+            schema.set_item("type", "integer")?
+        }
+        TypeValidator::Enum { enum_type } => {
+            let values = enum_type
+                .bind(py)
+                .try_iter()?
+                .map(|m| m?.getattr("value"))
+                .collect::<PyResult<Vec<_>>>()?;
+            schema.set_item("enum", PyList::new(py, values)?)?;
+        }
+        TypeValidator::Float {} => schema.set_item("type", "number")?,
+        TypeValidator::Str {} => schema.set_item("type", "string")?,
+        // Decimals are serialized as strings to preserve their precision.
+        TypeValidator::Decimal {} => {
+            schema.set_item("type", "string")?;
+            schema.set_item("format", "decimal")?;
+        }
+        TypeValidator::Bytes {}
+        | TypeValidator::ByteArray {}
+        | TypeValidator::MemoryView {}
+        | TypeValidator::Buffer { .. } => {
+            schema.set_item("type", "string")?;
+            schema.set_item("format", "binary")?;
+        }
+        TypeValidator::Tuple { items, .. } => {
+            schema.set_item("type", "array")?;
+            let prefix_items = PyList::empty(py);
+            for item in items {
+                prefix_items.append(validator_schema(py, item, visiting)?)?;
+            }
+            schema.set_item("prefixItems", prefix_items)?;
+            schema.set_item("minItems", items.len())?;
+            schema.set_item("maxItems", items.len())?;
+        }
+        TypeValidator::VarTuple { item, .. }
+        | TypeValidator::List { item }
+        | TypeValidator::Set { item }
+        | TypeValidator::FrozenSet { item }
+        | TypeValidator::Iterable { item, .. }
+        | TypeValidator::Sequence { item } => {
+            schema.set_item("type", "array")?;
+            if let Some(item) = item {
+                schema.set_item("items", validator_schema(py, item, visiting)?)?;
+            }
+            if matches!(
+                type_validator,
+                TypeValidator::Set { .. } | TypeValidator::FrozenSet { .. }
+            ) {
+                schema.set_item("uniqueItems", true)?;
+            }
+        }
+        TypeValidator::Dict { items } | TypeValidator::Mapping { items } => {
+            schema.set_item("type", "object")?;
+            if let Some((_, value)) = items {
+                schema.set_item(
+                    "additionalProperties",
+                    validator_schema(py, value, visiting)?,
+                )?;
+            }
+        }
+        TypeValidator::TypedDict { type_, fields } => {
+            schema.set_item("title", type_.bind(py).name()?)?;
+            schema.set_item("type", "object")?;
+            let properties = PyDict::new(py);
+            let required = PyList::empty(py);
+            for (key, validator, is_required) in fields {
+                properties.set_item(key, validator_schema(py, validator, visiting)?)?;
+                if *is_required {
+                    required.append(key)?;
+                }
+            }
+            schema.set_item("properties", properties)?;
+            schema.set_item("required", required)?;
+            schema.set_item("additionalProperties", false)?;
+        }
+        TypeValidator::Union { members } => {
+            let any_of = PyList::empty(py);
+            for m in members {
+                any_of.append(validator_schema(py, m, visiting)?)?;
+            }
+            schema.set_item("anyOf", any_of)?;
+        }
+        TypeValidator::Optional { inner } => {
+            let null = PyDict::new(py);
+            null.set_item("type", "null")?;
+            schema.set_item(
+                "anyOf",
+                PyList::new(py, [validator_schema(py, inner, visiting)?, null])?,
+            )?;
+        }
+        TypeValidator::ForwardValidator { late_validator } => {
+            return type_validator_schema(py, late_validator.get_validator(py)?.get(), visiting);
+        }
+        TypeValidator::Typed { type_ } | TypeValidator::GenericAttributes { type_, .. } => {
+            let type_ = type_.bind(py);
+            if type_.is(py.None().bind(py).get_type()) {
+                schema.set_item("type", "null")?;
+                return Ok(schema);
+            }
+            if type_.is_subclass_of::<AtorsBase>()? {
+                return class_schema(type_, visiting);
+            }
+            schema.set_item(
+                NOTE_KEY,
+                format!("No JSON schema mapping for type {}", type_.name()?),
+            )?;
+        }
+        TypeValidator::Instance { types } => {
+            let names = types
+                .iter(py)
+                .map(|t| t.name().map(|n| n.to_string()))
+                .collect::<PyResult<Vec<_>>>()?;
+            schema.set_item(
+                NOTE_KEY,
+                format!(
+                    "No JSON schema mapping for instances of {}",
+                    names.join(", ")
+                ),
+            )?;
+        }
+        TypeValidator::Complex {}
+        | TypeValidator::Subclass { .. }
+        | TypeValidator::Callable { .. }
+        | TypeValidator::Guard { .. }
+        | TypeValidator::Iterator { .. } => {
+            schema.set_item(
+                NOTE_KEY,
+                format!("No JSON schema mapping for {type_validator:?}"),
+            )?;
+        }
+    }
+    Ok(schema)
+}
+
+/// Build the schema fragment describing a validator, including the
+/// constraints expressed by its value validators.
+fn validator_schema<'py>(
+    py: Python<'py>,
+    validator: &Validator,
+    visiting: &mut Vec<usize>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let schema = type_validator_schema(py, &validator.type_validator, visiting)?;
+    let mut notes = Vec::new();
+    for vv in validator.value_validators.iter() {
+        match vv {
+            ValueValidator::Values { values } => {
+                schema.set_item("enum", PyList::new(py, values.0.bind(py).iter())?)?;
+            }
+            ValueValidator::Literal { values } => {
+                schema.set_item("enum", PyList::new(py, values.bind(py).iter())?)?;
+            }
+            ValueValidator::Range {
+                min,
+                max,
+                inclusive,
+            } => {
+                let (min_key, max_key) = if *inclusive {
+                    ("minimum", "maximum")
+                } else {
+                    ("exclusiveMinimum", "exclusiveMaximum")
+                };
+                if let Some(min) = min {
+                    schema.set_item(min_key, min.to_f64(py)?)?;
+                }
+                if let Some(max) = max {
+                    schema.set_item(max_key, max.to_f64(py)?)?;
+                }
+            }
+            ValueValidator::Length { min, max } => {
+                let (min_key, max_key) = match schema.get_item("type")? {
+                    Some(t) if t.eq("string")? => ("minLength", "maxLength"),
+                    Some(t) if t.eq("object")? => ("minProperties", "maxProperties"),
+                    _ => ("minItems", "maxItems"),
+                };
+                if let Some(min) = min {
+                    schema.set_item(min_key, min)?;
+                }
+                if let Some(max) = max {
+                    schema.set_item(max_key, max)?;
+                }
+            }
+            ValueValidator::MultipleOf { multiple_of } => {
+                schema.set_item("multipleOf", multiple_of)?;
+            }
+            ValueValidator::Regex { pattern } => {
+                // JSON Schema patterns are not anchored while the validator
+                // requires a full match.
+                let source = pattern.source(py)?;
+                match source.extract::<String>() {
+                    Ok(p) => schema.set_item("pattern", format!("^(?:{p})$"))?,
+                    Err(_) => notes.push("Value validated by a bytes pattern"),
+                }
+            }
+            ValueValidator::CallValue { .. }
+            | ValueValidator::CallNameObjectValue { .. }
+            | ValueValidator::Predicate { .. }
+            | ValueValidator::ObjectMethod { .. } => {
+                notes.push("Value validated by an arbitrary callable");
+            }
+        }
+    }
+    if !notes.is_empty() {
+        let note = match schema.get_item(NOTE_KEY)? {
+            Some(existing) => format!("{}; {}", existing, notes.join("; ")),
+            None => notes.join("; "),
+        };
+        schema.set_item(NOTE_KEY, note)?;
+    }
+    Ok(schema)
+}
+
+/// Build the schema describing an Ators class as an object.
+fn class_schema<'py>(
+    cls: &Bound<'py, PyType>,
+    visiting: &mut Vec<usize>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let py = cls.py();
+    let schema = PyDict::new(py);
+    schema.set_item("title", cls.name()?)?;
+    schema.set_item("type", "object")?;
+
+    let key = cls.as_ptr() as usize;
+    if visiting.contains(&key) {
+        schema.set_item(NOTE_KEY, "Recursive reference")?;
+        return Ok(schema);
+    }
+    visiting.push(key);
+
+    let info = get_class_info(cls)?;
+    let members_by_name = info.members_by_name_ref(py);
+    let mut members = members_by_name
+        .iter()
+        .map(|(_, m)| m.bind(py).get())
+        .collect::<Vec<_>>();
+    members.sort_by(|a, b| a.name().cmp(b.name()));
+
+    let required_names = info
+        .required_init_member_names()
+        .iter()
+        .map(|n| n.bind(py).to_str().map(str::to_owned))
+        .collect::<PyResult<Vec<_>>>()?;
+    let properties = PyDict::new(py);
+    let required = PyList::empty(py);
+    for m in members {
+        let property = validator_schema(py, m.validator(), visiting)?;
+        if let DefaultBehavior::Static { value } = m.default_behavior() {
+            property.set_item("default", value.bind(py))?;
+        }
+        properties.set_item(m.name(), property)?;
+        if required_names.iter().any(|n| n == m.name()) {
+            required.append(m.name())?;
+        }
+    }
+    schema.set_item("properties", properties)?;
+    schema.set_item("required", required)?;
+
+    visiting.pop();
+    Ok(schema)
+}
+
+/// Describe an Ators class (or the class of an instance) as a JSON-Schema-like dict.
+///
+/// Each member is described by a fragment derived from its type validator, with
+/// the constraints of its value validators and its static default if any.
+/// Validators that cannot be mapped (e.g. arbitrary callables) produce a generic
+/// fragment carrying a `$comment` note.
+#[pyfunction]
+pub fn json_schema<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    let cls = resolve_class_for_obj(obj)?;
+    class_schema(&cls, &mut Vec::new())
+}
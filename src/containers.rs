@@ -8,9 +8,11 @@
 /// Container types with validation and related utilities.
 mod common;
 pub mod dict;
+pub mod iter;
 pub mod list;
 pub mod set;
 
 pub use dict::*;
+pub use iter::*;
 pub use list::*;
 pub use set::*;
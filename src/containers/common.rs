@@ -5,10 +5,13 @@
 |
 | The full license is in the file LICENSE, distributed with this software.
 |----------------------------------------------------------------------------*/
-use pyo3::{Bound, Py};
+use pyo3::{
+    Bound, Py, PyAny, PyResult, PyTypeInfo, Python,
+    types::{PyAnyMethods, PyDict, PyString, PyTuple, PyTupleMethods, PyTypeMethods},
+};
 use std::cell::UnsafeCell;
 
-use crate::class::AtorsBase;
+use crate::class::{AtorsBase, is_frozen};
 
 pub(super) fn matches_assignment_context<'py>(
     member_name_cell: &UnsafeCell<Option<String>>,
@@ -25,3 +28,79 @@ pub(super) fn matches_assignment_context<'py>(
             _ => false,
         }
 }
+
+/// Error if the Ators object owning a container is frozen.
+///
+/// Containers stored in a member of a frozen object are part of its state and
+/// must not be mutated in place.
+pub(super) fn ensure_owner_not_frozen(
+    py: Python<'_>,
+    member_name_cell: &UnsafeCell<Option<String>>,
+    object_cell: &UnsafeCell<Option<Py<AtorsBase>>>,
+) -> PyResult<()> {
+    // Safety: same invariants as matches_assignment_context.
+    let Some(object) = (unsafe { &*object_cell.get() }) else {
+        return Ok(());
+    };
+    let object = object.bind(py);
+    if is_frozen(object) {
+        return Err(owner_frozen_error(
+            object,
+            unsafe { &*member_name_cell.get() }.as_deref(),
+        ));
+    }
+    Ok(())
+}
+
+#[cold]
+fn owner_frozen_error(object: &Bound<'_, AtorsBase>, member_name: Option<&str>) -> pyo3::PyErr {
+    let repr = object
+        .repr()
+        .map(|r| r.to_string())
+        .unwrap_or_else(|_| "<unknown>".to_string());
+    pyo3::exceptions::PyTypeError::new_err(format!(
+        "Cannot modify the value of member '{}' of {} which is frozen.",
+        member_name.unwrap_or("<unknown>"),
+        repr
+    ))
+}
+
+/// Call the implementation of a method on the builtin container a class extends.
+pub(super) fn call_base_method<'py, T: PyTypeInfo>(
+    obj: &Bound<'py, PyAny>,
+    name: &Bound<'py, PyString>,
+    args: &Bound<'py, PyTuple>,
+    kwargs: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = obj.py();
+    let full_args = PyTuple::new(
+        py,
+        std::iter::once(obj.clone())
+            .chain(args.iter())
+            .collect::<Vec<_>>(),
+    )?;
+    py.get_type::<T>().getattr(name)?.call(full_args, kwargs)
+}
+
+/// Call an in-place operator of the builtin container a class extends.
+///
+/// Since in-place operators implemented in Rust always return the container,
+/// an unsupported operand is reported by raising rather than by returning
+/// NotImplemented.
+pub(super) fn call_base_inplace_operator<'py, T: PyTypeInfo>(
+    obj: &Bound<'py, PyAny>,
+    name: &Bound<'py, PyString>,
+    symbol: &str,
+    value: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    let py = obj.py();
+    let result = call_base_method::<T>(obj, name, &PyTuple::new(py, [value])?, None)?;
+    if result.is(py.NotImplemented().bind(py)) {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "unsupported operand type(s) for {symbol}: '{}' and '{}'",
+            obj.get_type().name()?,
+            value.get_type().name()?
+        )));
+    }
+    Ok(())
+}
@@ -6,18 +6,71 @@
 | The full license is in the file LICENSE, distributed with this software.
 |----------------------------------------------------------------------------*/
 use pyo3::{
-    Bound, IntoPyObjectExt, Py, PyAny, PyResult, Python, intern, pyclass, pymethods,
+    Bound, IntoPyObjectExt, Py, PyAny, PyErr, PyResult, Python, intern, pyclass, pymethods,
     sync::critical_section::with_critical_section,
-    types::{PyAnyMethods, PyDict, PyDictMethods},
+    types::{PyAnyMethods, PyDict, PyDictMethods, PyTuple},
 };
 use std::cell::UnsafeCell;
 
 use crate::{
     class::AtorsBase,
-    containers::{AtorsList, AtorsSet, common::matches_assignment_context},
+    containers::{
+        AtorsList, AtorsSet,
+        common::{call_base_method, ensure_owner_not_frozen, matches_assignment_context},
+    },
+    errors::new_validation_error,
+    utils::err_with_cause,
     validators::Validator,
 };
 
+/// Wrap the error raised when validating a key, as done when validating a
+/// dict assigned to a member.
+fn key_validation_error(
+    key: &Bound<'_, PyAny>,
+    member_name: Option<&str>,
+    object: Option<&Bound<'_, AtorsBase>>,
+    err: PyErr,
+) -> PyResult<PyErr> {
+    let py = key.py();
+    let msg = match (member_name, object) {
+        (Some(m), Some(o)) => format!(
+            "Failed to validate key '{}' for the member {} of {}.",
+            key.repr()?,
+            m,
+            o.repr()?
+        ),
+        _ => format!("Failed to validate key '{}'.", key.repr()?),
+    };
+    Ok(err_with_cause(py, new_validation_error(py, msg), err))
+}
+
+/// Wrap the error raised when validating a value, as done when validating a
+/// dict assigned to a member.
+fn value_validation_error(
+    key: &Bound<'_, PyAny>,
+    value: &Bound<'_, PyAny>,
+    member_name: Option<&str>,
+    object: Option<&Bound<'_, AtorsBase>>,
+    err: PyErr,
+) -> PyResult<PyErr> {
+    let py = key.py();
+    let msg = match (member_name, object) {
+        (Some(m), Some(o)) => format!(
+            "Failed to validate value '{}' with key '{}' for the member {} of {}.",
+            value.repr()?,
+            key.repr()?,
+            m,
+            o.repr()?
+        ),
+        _ => format!(
+            "Failed to validate value '{}' with key '{}'.",
+            value.repr()?,
+            key.repr()?
+        ),
+    };
+    Ok(err_with_cause(py, new_validation_error(py, msg), err))
+}
+
 #[pyclass(module = "ators._ators", extends=PyDict, frozen)]
 pub struct AtorsDict {
     key_validator: UnsafeCell<Validator>,
@@ -53,6 +106,11 @@ impl AtorsDict {
         )
     }
 
+    /// Raise if the object owning the dict is frozen
+    fn ensure_mutable(&self, py: Python<'_>) -> PyResult<()> {
+        ensure_owner_not_frozen(py, &self.member_name, &self.object)
+    }
+
     /// Validate a key using the key_validator
     fn validate_key<'py>(
         &self,
@@ -63,20 +121,27 @@ impl AtorsDict {
         let key_validator = unsafe { &*self.key_validator.get() };
         let m = unsafe { &*self.member_name.get() }.as_deref();
         let o = unsafe { &*self.object.get() }.as_ref().map(|o| o.bind(py));
-        key_validator.validate(m, o, key)
+        match key_validator.validate(m, o, key) {
+            Ok(k) => Ok(k),
+            Err(err) => Err(key_validation_error(key, m, o, err)?),
+        }
     }
 
     /// Validate a value for insertion into the dict
     fn validate_value<'py>(
         &self,
         py: Python<'py>,
+        key: &Bound<'py, PyAny>,
         value: &Bound<'py, PyAny>,
     ) -> PyResult<Bound<'py, PyAny>> {
         // Safety: same as AtorsList::validate_item.
         let value_validator = unsafe { &*self.value_validator.get() };
         let m = unsafe { &*self.member_name.get() }.as_deref();
         let o = unsafe { &*self.object.get() }.as_ref().map(|o| o.bind(py));
-        value_validator.validate(m, o, value)
+        match value_validator.validate(m, o, value) {
+            Ok(v) => Ok(v),
+            Err(err) => Err(value_validation_error(key, value, m, o, err)?),
+        }
     }
 
     /// Validate both key and value for insertion into the dict
@@ -86,16 +151,63 @@ impl AtorsDict {
         key: &Bound<'py, PyAny>,
         value: &Bound<'py, PyAny>,
     ) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
-        // Safety: same as AtorsList::validate_item.
-        let key_validator = unsafe { &*self.key_validator.get() };
-        let value_validator = unsafe { &*self.value_validator.get() };
-        let m = unsafe { &*self.member_name.get() }.as_deref();
-        let o = unsafe { &*self.object.get() }.as_ref().map(|o| o.bind(py));
-        let valid_key = key_validator.validate(m, o, key)?;
-        let valid_value = value_validator.validate(m, o, value)?;
+        let valid_key = self.validate_key(py, key)?;
+        let valid_value = self.validate_value(py, key, value)?;
         Ok((valid_key, valid_value))
     }
 
+    /// Validate the entries of an update into a new dict.
+    fn validate_update<'py>(
+        self_: &Bound<'py, AtorsDict>,
+        other: Option<&Bound<'py, PyAny>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let py = self_.py();
+        // Ensure we do not do a partial update if invalid values are met
+        // halfway through the update, by first validating all items and only
+        // then applying the update to the dict.
+        let valid = PyDict::new(py);
+        if let Some(o) = other {
+            // Shortcut for dicts for which we can safely iterate over
+            if let Ok(od) = o.cast::<PyDict>() {
+                for (k, v) in od.iter() {
+                    let (valid_key, valid_value) = self_.get().validate_item(self_.py(), &k, &v)?;
+                    valid.set_item(valid_key, valid_value)?;
+                }
+            }
+            // Handle object providing keys() method
+            else if o.hasattr(intern!(self_.py(), "keys"))? {
+                let keys = o.call_method0(intern!(self_.py(), "keys"))?;
+                for key in keys.try_iter()? {
+                    let k = key?;
+                    let v = o
+                        .getattr(intern!(self_.py(), "__getitem__"))?
+                        .call1((&k,))?;
+                    let (valid_key, valid_value) = self_.get().validate_item(self_.py(), &k, &v)?;
+                    valid.set_item(valid_key, valid_value)?;
+                }
+            }
+            // Handle iterable of key-value pairs
+            else {
+                for t in o.try_iter()? {
+                    let (k, v) = t?.extract::<(Bound<'py, PyAny>, Bound<'py, PyAny>)>()?;
+                    let (valid_key, valid_value) = self_.get().validate_item(self_.py(), &k, &v)?;
+                    valid.set_item(valid_key, valid_value)?;
+                }
+            }
+        }
+
+        // Handle keyword arguments
+        if let Some(kw) = kwargs {
+            for (k, v) in kw.iter() {
+                let (valid_key, valid_value) = self_.get().validate_item(self_.py(), &k, &v)?;
+                valid.set_item(valid_key, valid_value)?;
+            }
+        }
+
+        Ok(valid)
+    }
+
     pub(crate) fn matches_assignment_context<'py>(
         &self,
         member_name: Option<&str>,
@@ -248,6 +360,7 @@ impl AtorsDict {
         value: &Bound<'py, PyAny>,
     ) -> PyResult<()> {
         let py = key.py();
+        self_.get().ensure_mutable(py)?;
         let (valid_key, valid_value) = self_.get().validate_item(py, key, value)?;
         let ndict = unsafe { self_.cast_unchecked::<PyDict>() };
         ndict.set_item(valid_key, valid_value)
@@ -259,10 +372,28 @@ impl AtorsDict {
         self_: &Bound<'py, AtorsDict>,
         key: &Bound<'py, PyAny>,
     ) -> PyResult<()> {
+        self_.get().ensure_mutable(self_.py())?;
         let ndict = unsafe { self_.cast_unchecked::<PyDict>() };
         ndict.del_item(key)
     }
 
+    /// Re-initialize the dict from mappings/iterables/kwargs after validating
+    /// all entries.
+    ///
+    /// The dict is left untouched when one item fails validation.
+    #[pyo3(signature = (other=None, **kwargs))]
+    pub fn __init__<'py>(
+        self_: &Bound<'py, AtorsDict>,
+        other: Option<&Bound<'py, PyAny>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        self_.get().ensure_mutable(self_.py())?;
+        let valid = AtorsDict::validate_update(self_, other, kwargs)?;
+        let ndict = unsafe { self_.cast_unchecked::<PyDict>() };
+        ndict.clear();
+        ndict.update(valid.as_mapping())
+    }
+
     #[pyo3(signature = (other=None, **kwargs))]
     /// Update the dict from mappings/iterables/kwargs after validating all entries.
     ///
@@ -273,51 +404,9 @@ impl AtorsDict {
         other: Option<&Bound<'py, PyAny>>,
         kwargs: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let py = self_.py();
+        self_.get().ensure_mutable(self_.py())?;
+        let valid = AtorsDict::validate_update(self_, other, kwargs)?;
         let ndict = unsafe { self_.cast_unchecked::<PyDict>() };
-
-        // Ensure we do not do a partial update if invalid values are met
-        // halfway through the update, by first validating all items and only
-        // then applying the update to the dict.
-        let valid = PyDict::new(py);
-        if let Some(o) = other {
-            // Shortcut for dicts for which we can safely iterate over
-            if let Ok(od) = o.cast::<PyDict>() {
-                for (k, v) in od.iter() {
-                    let (valid_key, valid_value) = self_.get().validate_item(self_.py(), &k, &v)?;
-                    valid.set_item(valid_key, valid_value)?;
-                }
-            }
-            // Handle object providing keys() method
-            else if o.hasattr(intern!(self_.py(), "keys"))? {
-                let keys = o.call_method0(intern!(self_.py(), "keys"))?;
-                for key in keys.try_iter()? {
-                    let k = key?;
-                    let v = o
-                        .getattr(intern!(self_.py(), "__getitem__"))?
-                        .call1((&k,))?;
-                    let (valid_key, valid_value) = self_.get().validate_item(self_.py(), &k, &v)?;
-                    valid.set_item(valid_key, valid_value)?;
-                }
-            }
-            // Handle iterable of key-value pairs
-            else {
-                for t in o.try_iter()? {
-                    let (k, v) = t?.extract::<(Bound<'py, PyAny>, Bound<'py, PyAny>)>()?;
-                    let (valid_key, valid_value) = self_.get().validate_item(self_.py(), &k, &v)?;
-                    valid.set_item(valid_key, valid_value)?;
-                }
-            }
-        }
-
-        // Handle keyword arguments
-        if let Some(kw) = kwargs {
-            for (k, v) in kw.iter() {
-                let (valid_key, valid_value) = self_.get().validate_item(self_.py(), &k, &v)?;
-                valid.set_item(valid_key, valid_value)?;
-            }
-        }
-
         ndict.update(valid.as_mapping())
     }
 
@@ -337,12 +426,13 @@ impl AtorsDict {
         }
 
         // The key does not exist, insert the default value
+        self_.get().ensure_mutable(py)?;
         let value = if let Some(def) = default {
             def
         } else {
             &py.None().into_bound(py)
         };
-        let valid_value = self_.get().validate_value(py, value)?;
+        let valid_value = self_.get().validate_value(py, key, value)?;
         ndict.set_item(&valid_key, &valid_value)?;
 
         Ok(valid_value)
@@ -352,6 +442,35 @@ impl AtorsDict {
         AtorsDict::update(self_, Some(other), None)
     }
 
+    // The following methods cannot add items and hence do not require
+    // validation but must be rejected once the owner is frozen.
+    #[pyo3(signature = (*args))]
+    pub fn pop<'py>(
+        self_: &Bound<'py, AtorsDict>,
+        args: &Bound<'py, PyTuple>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_method::<PyDict>(self_.as_any(), intern!(py, "pop"), args, None)
+    }
+
+    pub fn popitem<'py>(self_: &Bound<'py, AtorsDict>) -> PyResult<Bound<'py, PyAny>> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_method::<PyDict>(
+            self_.as_any(),
+            intern!(py, "popitem"),
+            &PyTuple::empty(py),
+            None,
+        )
+    }
+
+    pub fn clear<'py>(self_: &Bound<'py, AtorsDict>) -> PyResult<()> {
+        self_.get().ensure_mutable(self_.py())?;
+        unsafe { self_.cast_unchecked::<PyDict>() }.clear();
+        Ok(())
+    }
+
     // The traverse method of the parent class (PyDict) is called automatically and
     // the type is also traversed so we only need to visit our own references.
     pub fn __traverse__(&self, visit: pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
@@ -388,12 +507,16 @@ impl AtorsDict {
                     value_validators: Box::new([]),
                     coercer: None,
                     init_coercer: None,
+                    normalizers: Box::new([]),
+                    coerce_strict: false,
                 }),
                 value_validator: UnsafeCell::new(Validator {
                     type_validator: TypeValidator::Any {},
                     value_validators: Box::new([]),
                     coercer: None,
                     init_coercer: None,
+                    normalizers: Box::new([]),
+                    coerce_strict: false,
                 }),
                 member_name: UnsafeCell::new(None),
                 object: UnsafeCell::new(None),
@@ -0,0 +1,125 @@
+/*-----------------------------------------------------------------------------
+| Copyright (c) 2026, Ators contributors, see git history for details
+|
+| Distributed under the terms of the Modified BSD License.
+|
+| The full license is in the file LICENSE, distributed with this software.
+|----------------------------------------------------------------------------*/
+use pyo3::{
+    Bound, Py, PyAny, PyResult, Python, pyclass, pymethods,
+    types::{PyAnyMethods, PyIterator},
+};
+
+use crate::{errors::new_validation_error, utils::err_with_cause, validators::Validator};
+
+/// Validate an item produced by a lazily validated iterable or iterator.
+fn validate_item<'py>(
+    validator: &Validator,
+    member_name: Option<&str>,
+    index: usize,
+    item: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    validator
+        .validate(member_name, None, item)
+        .map_err(|cause| {
+            let py = item.py();
+            let msg = match member_name {
+                Some(m) => format!("Failed to validate item {index} produced for the member {m}."),
+                None => format!("Failed to validate item {index}."),
+            };
+            err_with_cause(py, new_validation_error(py, msg), cause)
+        })
+}
+
+/// Iterable wrapping another one and validating its items lazily, each
+/// iteration going through a new iterator of the wrapped iterable.
+#[pyclass(module = "ators._ators", frozen)]
+#[derive(Debug)]
+pub struct AtorsIterable {
+    iterable: Py<PyAny>,
+    validator: Validator,
+    member_name: Option<String>,
+}
+
+impl AtorsIterable {
+    pub(crate) fn new<'py>(
+        iterable: &Bound<'py, PyAny>,
+        validator: Validator,
+        member_name: Option<&str>,
+    ) -> PyResult<Bound<'py, AtorsIterable>> {
+        Bound::new(
+            iterable.py(),
+            AtorsIterable {
+                iterable: iterable.clone().unbind(),
+                validator,
+                member_name: member_name.map(|m| m.to_string()),
+            },
+        )
+    }
+}
+
+#[pymethods]
+impl AtorsIterable {
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, AtorsIterator>> {
+        AtorsIterator::new(
+            &self.iterable.bind(py).try_iter()?,
+            self.validator.clone(),
+            self.member_name.as_deref(),
+        )
+    }
+
+    /// The wrapped iterable.
+    #[getter]
+    fn wrapped<'py>(&self, py: Python<'py>) -> Bound<'py, PyAny> {
+        self.iterable.bind(py).clone()
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!("AtorsIterable({})", self.iterable.bind(py).repr()?))
+    }
+}
+
+/// Iterator wrapping another one and validating its items as they are
+/// consumed.
+#[pyclass(module = "ators._ators")]
+#[derive(Debug)]
+pub struct AtorsIterator {
+    iterator: Py<PyIterator>,
+    validator: Validator,
+    member_name: Option<String>,
+    index: usize,
+}
+
+impl AtorsIterator {
+    pub(crate) fn new<'py>(
+        iterator: &Bound<'py, PyIterator>,
+        validator: Validator,
+        member_name: Option<&str>,
+    ) -> PyResult<Bound<'py, AtorsIterator>> {
+        Bound::new(
+            iterator.py(),
+            AtorsIterator {
+                iterator: iterator.clone().unbind(),
+                validator,
+                member_name: member_name.map(|m| m.to_string()),
+                index: 0,
+            },
+        )
+    }
+}
+
+#[pymethods]
+impl AtorsIterator {
+    fn __iter__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __next__<'py>(&mut self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Some(item) = self.iterator.bind(py).clone().next() else {
+            return Ok(None);
+        };
+        let index = self.index;
+        self.index += 1;
+        validate_item(&self.validator, self.member_name.as_deref(), index, &item?).map(Some)
+    }
+}
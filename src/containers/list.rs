@@ -9,13 +9,19 @@ use pyo3::{
     Bound, IntoPyObjectExt, Py, PyAny, PyResult, PyTypeInfo, Python, ffi, intern, pyclass,
     pymethods,
     sync::critical_section::with_critical_section,
-    types::{PyAnyMethods, PyList, PyListMethods, PySlice},
+    types::{PyAnyMethods, PyDict, PyList, PyListMethods, PySlice, PyTuple},
 };
 use std::cell::UnsafeCell;
 
 use crate::{
     class::AtorsBase,
-    containers::{AtorsDict, AtorsSet, common::matches_assignment_context},
+    containers::{
+        AtorsDict, AtorsSet,
+        common::{
+            call_base_inplace_operator, call_base_method, ensure_owner_not_frozen,
+            matches_assignment_context,
+        },
+    },
     utils::error_on_minusone,
     validators::Validator,
 };
@@ -51,6 +57,10 @@ impl AtorsList {
         )
     }
 
+    fn ensure_mutable(&self, py: Python<'_>) -> PyResult<()> {
+        ensure_owner_not_frozen(py, &self.member_name, &self.object)
+    }
+
     fn validate_item<'py>(
         &self,
         py: Python<'py>,
@@ -183,12 +193,35 @@ impl AtorsList {
 // remove, pop, clear, sort, reverse and __imul__ do not need
 // item validation since they only remove or rearrange existing items.
 // append, insert, __setitem__, extend and __iadd__ need item validation
-// since they can add new items.
+// since they can add new items. All mutating methods are overridden to
+// reject mutations once the owner is frozen.
 #[pymethods]
 impl AtorsList {
+    /// Re-initialize the list from `iterable` after validating each item.
+    ///
+    /// The list is left untouched when one item fails validation.
+    #[pyo3(signature = (iterable=None))]
+    pub fn __init__<'py>(
+        self_: &Bound<'py, AtorsList>,
+        iterable: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        let valid = match iterable {
+            Some(it) => {
+                with_critical_section(self_.as_any(), || self_.get().validate_iterable(py, it))?
+            }
+            None => PyList::empty(py),
+        };
+        // SAFETY: AtorsList is declared as `extends=PyList`, so this cast is always valid.
+        let list = unsafe { self_.cast_unchecked::<PyList>() };
+        list.set_slice(0, usize::MAX, &valid)
+    }
+
     /// Append a value after validating it with the list item validator.
     pub fn append<'py>(self_: &Bound<'py, AtorsList>, value: &Bound<'py, PyAny>) -> PyResult<()> {
         let py = value.py();
+        self_.get().ensure_mutable(py)?;
         let valid = self_.get().validate_item(py, value)?;
         // SAFETY: AtorsList is declared as `extends=PyList`, so this cast is
         // always valid, and the resulting PyList is valid for calling append.
@@ -196,16 +229,27 @@ impl AtorsList {
     }
 
     /// Insert a value at `index` after validating it with the item validator.
+    ///
+    /// As for list, negative indices are relative to the end of the list and
+    /// out of range indices insert at the start or end of the list.
     pub fn insert<'py>(
         self_: &Bound<'py, AtorsList>,
-        index: usize,
+        index: isize,
         value: &Bound<'py, PyAny>,
     ) -> PyResult<()> {
         let py = value.py();
+        self_.get().ensure_mutable(py)?;
         let valid = self_.get().validate_item(py, value)?;
         // SAFETY: AtorsList is declared as `extends=PyList`, so this cast is
-        // always valid, and the resulting PyList is valid for calling append.
-        unsafe { self_.cast_unchecked::<PyList>() }.insert(index, &valid)
+        // always valid, and the resulting PyList is valid for calling insert.
+        let list = unsafe { self_.cast_unchecked::<PyList>() };
+        // Normalize negative indices, PyList_Insert clamping large ones.
+        let index = if index < 0 {
+            (index + list.len() as isize).max(0)
+        } else {
+            index
+        };
+        list.insert(index as usize, &valid)
     }
 
     pub fn __setitem__<'py>(
@@ -214,6 +258,7 @@ impl AtorsList {
         value: &Bound<'py, PyAny>,
     ) -> PyResult<()> {
         let py = index.py();
+        self_.get().ensure_mutable(py)?;
 
         // Cast once to PyList (AtorsList extends PyList). Use unchecked cast to avoid
         // an extra runtime check and to get access to PyList helper methods.
@@ -221,10 +266,13 @@ impl AtorsList {
 
         // Slice assignment path
         if index.is_instance_of::<PySlice>() {
-            // Validate the list on the RHS
+            // Validate the list on the RHS. All items are validated before
+            // the list is modified so that a failure leaves it untouched.
             let validated_list = self_.get().validate_iterable(py, value)?;
 
-            // Use direct slo access to use the proper PyList method (since we have no super)
+            // Use direct slo access to use the proper PyList method (since we have no super).
+            // It handles resizing for contiguous slices and enforces matching
+            // lengths for extended slices.
             return error_on_minusone(py, unsafe {
                 (*(*PyList::type_object_raw(py)).tp_as_mapping)
                     .mp_ass_subscript
@@ -262,6 +310,7 @@ impl AtorsList {
         index: &Bound<'py, PyAny>,
     ) -> PyResult<()> {
         let py = self_.py();
+        self_.get().ensure_mutable(py)?;
         error_on_minusone(py, unsafe {
             (*(*PyList::type_object_raw(py)).tp_as_mapping)
                 .mp_ass_subscript
@@ -275,6 +324,7 @@ impl AtorsList {
 
     /// Extend the list with values from `other` after validating each item.
     pub fn extend<'py>(self_: &Bound<'py, AtorsList>, other: &Bound<'py, PyAny>) -> PyResult<()> {
+        self_.get().ensure_mutable(self_.py())?;
         let valid = with_critical_section(self_.as_any(), || {
             self_.get().validate_iterable(other.py(), other)
         })?;
@@ -291,6 +341,58 @@ impl AtorsList {
         AtorsList::extend(self_, value)
     }
 
+    pub fn __imul__<'py>(self_: &Bound<'py, Self>, value: &Bound<'py, PyAny>) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_inplace_operator::<PyList>(self_.as_any(), intern!(py, "__imul__"), "*=", value)
+    }
+
+    #[pyo3(signature = (*args))]
+    pub fn pop<'py>(
+        self_: &Bound<'py, AtorsList>,
+        args: &Bound<'py, PyTuple>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_method::<PyList>(self_.as_any(), intern!(py, "pop"), args, None)
+    }
+
+    pub fn remove<'py>(self_: &Bound<'py, AtorsList>, value: &Bound<'py, PyAny>) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_method::<PyList>(
+            self_.as_any(),
+            intern!(py, "remove"),
+            &PyTuple::new(py, [value])?,
+            None,
+        )?;
+        Ok(())
+    }
+
+    pub fn clear<'py>(self_: &Bound<'py, AtorsList>) -> PyResult<()> {
+        self_.get().ensure_mutable(self_.py())?;
+        // SAFETY: AtorsList is declared as `extends=PyList`, so this cast is always valid.
+        unsafe { self_.cast_unchecked::<PyList>() }.del_slice(0, usize::MAX)
+    }
+
+    #[pyo3(signature = (*args, **kwargs))]
+    pub fn sort<'py>(
+        self_: &Bound<'py, AtorsList>,
+        args: &Bound<'py, PyTuple>,
+        kwargs: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_method::<PyList>(self_.as_any(), intern!(py, "sort"), args, kwargs)?;
+        Ok(())
+    }
+
+    pub fn reverse<'py>(self_: &Bound<'py, AtorsList>) -> PyResult<()> {
+        self_.get().ensure_mutable(self_.py())?;
+        // SAFETY: AtorsList is declared as `extends=PyList`, so this cast is always valid.
+        unsafe { self_.cast_unchecked::<PyList>() }.reverse()
+    }
+
     // The traverse method of the parent class (PyList) is called automatically and
     // the type is also traversed so we only need to visit our own references.
     pub fn __traverse__(&self, visit: pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
@@ -327,6 +429,8 @@ impl AtorsList {
                     value_validators: Box::new([]),
                     coercer: None,
                     init_coercer: None,
+                    normalizers: Box::new([]),
+                    coerce_strict: false,
                 }),
                 member_name: UnsafeCell::new(None),
                 object: UnsafeCell::new(None),
@@ -8,12 +8,17 @@
 use pyo3::{
     Bound, IntoPyObjectExt, Py, PyAny, PyResult, Python, intern, pyclass, pymethods,
     sync::critical_section::with_critical_section,
-    types::{PyAnyMethods, PySet, PySetMethods},
+    types::{PyAnyMethods, PySet, PySetMethods, PyTuple},
 };
 use std::cell::UnsafeCell;
 
 use crate::{
-    class::AtorsBase, containers::common::matches_assignment_context, validators::Validator,
+    class::AtorsBase,
+    containers::common::{
+        call_base_inplace_operator, call_base_method, ensure_owner_not_frozen,
+        matches_assignment_context,
+    },
+    validators::Validator,
 };
 
 #[pyclass(module = "ators._ators", extends=PySet, frozen)]
@@ -47,6 +52,10 @@ impl AtorsSet {
         )
     }
 
+    fn ensure_mutable(&self, py: Python<'_>) -> PyResult<()> {
+        ensure_owner_not_frozen(py, &self.member_name, &self.object)
+    }
+
     fn validate_set<'py>(
         &self,
         py: Python<'py>,
@@ -130,9 +139,54 @@ impl AtorsSet {
 // item validation since they can add items
 #[pymethods]
 impl AtorsSet {
+    /// Create a standalone set validating its items using the provided validator.
+    ///
+    /// The items are validated and added in `__init__` since the underlying set
+    /// is only allocated once this returns.
+    #[new]
+    #[pyo3(signature = (validator, items=None))]
+    pub fn py_new(validator: Validator, items: Option<&Bound<'_, PyAny>>) -> Self {
+        // Items are handled in __init__.
+        let _ = items;
+        AtorsSet {
+            validator: UnsafeCell::new(validator),
+            member_name: UnsafeCell::new(None),
+            object: UnsafeCell::new(None),
+        }
+    }
+
+    #[pyo3(signature = (validator, items=None))]
+    pub fn __init__<'py>(
+        self_: &Bound<'py, AtorsSet>,
+        validator: &Bound<'py, PyAny>,
+        items: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<()> {
+        // The validator is consumed in __new__.
+        let _ = validator;
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        // Like set.__init__, calling __init__ again replaces the items, which
+        // are all validated first so that the set is left untouched on failure.
+        let valid = match items {
+            Some(items) => {
+                let items = PySet::new(py, items.try_iter()?.collect::<PyResult<Vec<_>>>()?)?;
+                self_.get().validate_set(py, items.as_any())?
+            }
+            None => PySet::empty(py)?,
+        };
+        // SAFETY: AtorsSet is declared as `extends=PySet`, so this cast is always valid.
+        let set = unsafe { self_.cast_unchecked::<PySet>() };
+        set.clear();
+        for item in valid.iter() {
+            set.add(&item)?;
+        }
+        Ok(())
+    }
+
     /// Add one value to the set after validating it with the item validator.
     pub fn add<'py>(self_: &Bound<'py, AtorsSet>, value: Bound<'py, PyAny>) -> PyResult<()> {
         let py = value.py();
+        self_.get().ensure_mutable(py)?;
         // Safety: validator and member_name are effectively immutable; object is not modified
         // while live references exist (see struct-level safety comment).
         let valid = unsafe { &*self_.get().validator.get() }.validate(
@@ -150,6 +204,7 @@ impl AtorsSet {
 
     pub fn __ior__<'py>(self_: &Bound<'py, Self>, value: Bound<'py, PyAny>) -> PyResult<()> {
         let py = value.py();
+        self_.get().ensure_mutable(py)?;
         let valid = self_.get().validate_set(py, &value)?;
         // SAFETY: AtorsSet is declared as `extends=PySet`, so this cast is
         // always valid, and the resulting PySet is valid for calling add.
@@ -167,6 +222,7 @@ impl AtorsSet {
 
     pub fn __ixor__<'py>(self_: &Bound<'py, Self>, value: &Bound<'py, PyAny>) -> PyResult<()> {
         let py = value.py();
+        self_.get().ensure_mutable(py)?;
         let valid = self_.get().validate_set(py, value)?;
         let this = self_.cast::<PySet>()?;
         for item in valid.iter() {
@@ -186,6 +242,86 @@ impl AtorsSet {
         AtorsSet::__ixor__(self_, other)
     }
 
+    // The following methods cannot add items and hence do not require
+    // validation but must be rejected once the owner is frozen.
+    pub fn __isub__<'py>(self_: &Bound<'py, Self>, value: &Bound<'py, PyAny>) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_inplace_operator::<PySet>(self_.as_any(), intern!(py, "__isub__"), "-=", value)
+    }
+
+    pub fn __iand__<'py>(self_: &Bound<'py, Self>, value: &Bound<'py, PyAny>) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_inplace_operator::<PySet>(self_.as_any(), intern!(py, "__iand__"), "&=", value)
+    }
+
+    #[pyo3(signature = (*others))]
+    pub fn difference_update<'py>(
+        self_: &Bound<'py, AtorsSet>,
+        others: &Bound<'py, PyTuple>,
+    ) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_method::<PySet>(
+            self_.as_any(),
+            intern!(py, "difference_update"),
+            others,
+            None,
+        )?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (*others))]
+    pub fn intersection_update<'py>(
+        self_: &Bound<'py, AtorsSet>,
+        others: &Bound<'py, PyTuple>,
+    ) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_method::<PySet>(
+            self_.as_any(),
+            intern!(py, "intersection_update"),
+            others,
+            None,
+        )?;
+        Ok(())
+    }
+
+    pub fn remove<'py>(self_: &Bound<'py, AtorsSet>, value: &Bound<'py, PyAny>) -> PyResult<()> {
+        let py = self_.py();
+        self_.get().ensure_mutable(py)?;
+        call_base_method::<PySet>(
+            self_.as_any(),
+            intern!(py, "remove"),
+            &PyTuple::new(py, [value])?,
+            None,
+        )?;
+        Ok(())
+    }
+
+    pub fn discard<'py>(self_: &Bound<'py, AtorsSet>, value: &Bound<'py, PyAny>) -> PyResult<()> {
+        self_.get().ensure_mutable(self_.py())?;
+        // SAFETY: AtorsSet is declared as `extends=PySet`, so this cast is always valid.
+        unsafe { self_.cast_unchecked::<PySet>() }.discard(value)?;
+        Ok(())
+    }
+
+    pub fn pop<'py>(self_: &Bound<'py, AtorsSet>) -> PyResult<Bound<'py, PyAny>> {
+        self_.get().ensure_mutable(self_.py())?;
+        // SAFETY: AtorsSet is declared as `extends=PySet`, so this cast is always valid.
+        unsafe { self_.cast_unchecked::<PySet>() }
+            .pop()
+            .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err("pop from an empty set"))
+    }
+
+    pub fn clear<'py>(self_: &Bound<'py, AtorsSet>) -> PyResult<()> {
+        self_.get().ensure_mutable(self_.py())?;
+        // SAFETY: AtorsSet is declared as `extends=PySet`, so this cast is always valid.
+        unsafe { self_.cast_unchecked::<PySet>() }.clear();
+        Ok(())
+    }
+
     // The traverse method of the parent class (PySet) is called automatically and
     // the type is also traversed so we only need to visit our own references.
     pub fn __traverse__(&self, visit: pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
@@ -222,6 +358,8 @@ impl AtorsSet {
                     value_validators: Box::new([]),
                     coercer: None,
                     init_coercer: None,
+                    normalizers: Box::new([]),
+                    coerce_strict: false,
                 }),
                 member_name: UnsafeCell::new(None),
                 object: UnsafeCell::new(None),
@@ -0,0 +1,94 @@
+/*-----------------------------------------------------------------------------
+| Copyright (c) 2025-2026, Ators contributors, see git history for details
+|
+| Distributed under the terms of the Modified BSD License.
+|
+| The full license is in the file LICENSE, distributed with this software.
+|----------------------------------------------------------------------------*/
+/// Ators specific exceptions raised on validation and coercion failures.
+///
+/// Both exceptions subclass TypeError and ValueError so that code catching the
+/// builtin exceptions previously raised by validators keeps working.
+use pyo3::{
+    Bound, Py, PyErr, PyResult, PyTypeInfo, Python, intern,
+    sync::PyOnceLock,
+    types::{PyAnyMethods, PyDict, PyDictMethods, PyType},
+};
+
+static VALIDATION_ERROR: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+static COERCION_ERROR: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+/// Create an exception type deriving from both TypeError and ValueError.
+fn new_exception_type<'py>(py: Python<'py>, name: &str, doc: &str) -> PyResult<Bound<'py, PyType>> {
+    let dct = PyDict::new(py);
+    dct.set_item(intern!(py, "__module__"), "ators._ators")?;
+    dct.set_item(intern!(py, "__doc__"), doc)?;
+    Ok(PyType::type_object(py)
+        .call1((
+            name,
+            (
+                pyo3::exceptions::PyTypeError::type_object(py),
+                pyo3::exceptions::PyValueError::type_object(py),
+            ),
+            dct,
+        ))?
+        .cast_into()?)
+}
+
+/// Exception raised when a value fails type or value validation.
+pub(crate) fn validation_error_type(py: Python<'_>) -> &Bound<'_, PyType> {
+    VALIDATION_ERROR
+        .get_or_try_init(py, || {
+            new_exception_type(
+                py,
+                "AtorsValidationError",
+                "Raised when a value fails the type or value validation of a member.",
+            )
+            .map(Bound::unbind)
+        })
+        .expect("Creating the AtorsValidationError type should not fail.")
+        .bind(py)
+}
+
+/// Exception raised when a value cannot be coerced.
+pub(crate) fn coercion_error_type(py: Python<'_>) -> &Bound<'_, PyType> {
+    COERCION_ERROR
+        .get_or_try_init(py, || {
+            new_exception_type(
+                py,
+                "AtorsCoercionError",
+                "Raised when a value that failed validation cannot be coerced.",
+            )
+            .map(Bound::unbind)
+        })
+        .expect("Creating the AtorsCoercionError type should not fail.")
+        .bind(py)
+}
+
+/// Build an AtorsValidationError with the provided message.
+pub(crate) fn new_validation_error(py: Python<'_>, msg: impl Into<String>) -> PyErr {
+    PyErr::from_type(validation_error_type(py).clone(), msg.into())
+}
+
+/// Build an AtorsCoercionError with the provided message.
+pub(crate) fn new_coercion_error(py: Python<'_>, msg: impl Into<String>) -> PyErr {
+    PyErr::from_type(coercion_error_type(py).clone(), msg.into())
+}
+
+/// Turn any error raised while coercing a value into an AtorsCoercionError.
+///
+/// The message of the original error is preserved and the original error is
+/// attached as cause.
+#[cold]
+pub(crate) fn into_coercion_error(py: Python<'_>, err: PyErr) -> PyErr {
+    if err.is_instance(py, coercion_error_type(py)) {
+        return err;
+    }
+    let msg = err
+        .value(py)
+        .str()
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+    crate::utils::err_with_cause(py, new_coercion_error(py, msg), err)
+}
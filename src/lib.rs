@@ -10,15 +10,17 @@
 use pyo3::{
     Bound, Py, PyResult, Python, pymodule,
     sync::PyOnceLock,
-    types::{PyAnyMethods, PyType},
+    types::{PyAnyMethods, PyModule, PyModuleMethods, PyType},
 };
 
 use crate::utils::{GenericAttributesMap, TypeMutabilityMap};
 
 mod annotations;
 mod callable_validation;
+mod capi;
 mod class;
 mod containers;
+mod errors;
 mod event;
 mod member;
 mod observers;
@@ -55,41 +57,63 @@ mod _ators {
 
     #[pymodule_export]
     use self::class::{
-        AtorsBase, MembersByNameMapping, PicklePolicy, create_ators_specialized_alias,
-        create_ators_specialized_subclass, create_ators_subclass, disable_notifications,
-        drop_class_info, enable_notifications, freeze, get_ators_abstract_methods, get_ators_args,
-        get_ators_frozen_flag, get_ators_init_member_names, get_ators_members_by_name,
+        AtorsBase, MemberValuesMapping, MembersByNameMapping, NotificationsEnabled, PicklePolicy,
+        Transaction, UnsetType, check_value, coercing_members, create_ators_specialized_alias,
+        create_ators_specialized_subclass, create_ators_subclass, diff, disable_notifications,
+        drop_class_info, dump_slots, enable_notifications, freeze, from_json,
+        get_ators_abstract_methods, get_ators_args, get_ators_frozen_flag,
+        get_ators_init_member_names, get_ators_instances, get_ators_members_by_name,
         get_ators_origin, get_ators_specific_member_names, get_ators_type_params, get_event,
         get_event_customization_tool, get_events, get_events_by_tag, get_events_by_tag_and_value,
         get_member, get_member_customization_tool, get_members, get_members_by_tag,
-        get_members_by_tag_and_value, get_tracked_class_info_size, is_frozen,
-        is_notifications_enabled, maybe_freeze_instance_after_call, observe, unobserve,
+        get_members_by_tag_and_value, get_tracked_class_info_size, init_coercing_members,
+        is_frozen, is_notifications_enabled, iter_member_values, json_schema,
+        maybe_freeze_instance_after_call, observe, register_json_codec, reset_to_defaults,
+        set_instance_validator, set_unchecked, suppress_notifications, to_dict, to_json, unobserve,
+        update_validated,
     };
 
+    #[pymodule_export]
+    use self::annotations::validator_for;
+
     #[pymodule_export]
     use self::event::{Event, EventBuilder, EventCustomizationTool};
 
     #[pymodule_export]
     use self::member::{
-        DefaultBehavior, DelattrBehavior, Member, MemberBuilder, PostGetattrBehavior,
-        PostSetattrBehavior, PreGetattrBehavior, PreSetattrBehavior,
+        ClassMember, ClassMemberBuilder, DefaultBehavior, DelattrBehavior, Member, MemberBuilder,
+        ObserverBehavior, PostGetattrBehavior, PostSetattrBehavior, PreGetattrBehavior,
+        PreSetattrBehavior,
     };
 
     #[pymodule_export]
-    use self::validators::{Coercer, TypeValidator, Validator, ValueValidator};
+    use self::validators::{
+        Coercer, NoValidation, Normalizer, TypeValidator, Validator, ValueValidator,
+    };
 
     // Exported only to enable pickling
     #[pymodule_export]
     use self::containers::{AtorsDict, AtorsList, AtorsSet};
 
     #[pymodule_export]
-    use self::observers::AtorsChange;
+    use self::observers::{AtorsChange, ChangeEvent};
 
     #[pymodule_export]
     use self::callable_validation::{
         AsyncCallableValidator, SyncCallableValidator, ValidatedDecorator, validated,
     };
 
+    /// Register the Ators specific exceptions on the module.
+    #[pymodule_init]
+    fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        let py = m.py();
+        m.add("AtorsCoercionError", errors::coercion_error_type(py))?;
+        m.add("AtorsValidationError", errors::validation_error_type(py))?;
+        m.add("UNSET", class::base::unset(py))?;
+        m.add("_C_API", capi::create_capsule(py)?)?;
+        Ok(())
+    }
+
     #[pyfunction]
     /// Register generic attribute names for a Python type.
     ///
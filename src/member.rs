@@ -8,30 +8,39 @@
 /// Core descriptor class defining Ators members and related utilities.
 use crate::{
     class::base::{
-        AtorsBase, ReplaceSlotOutcome, get_slot, get_slot_owned, is_frozen, notify_member_change,
-        replace_slot, set_slot,
+        AtorsBase, ReplaceSlotOutcome, check_invariants, del_slot, get_instance_validator,
+        get_slot, get_slot_owned, instance_has_invariants, is_frozen, notifications_enabled,
+        notify_member_change, replace_slot, set_slot,
+    },
+    observers::ChangeEvent,
+    validators::{
+        Coercer, Normalizer, TypeValidator, Validator, ValueValidator, is_validation_disabled,
     },
-    validators::{Coercer, TypeValidator, Validator, ValueValidator},
 };
 use pyo3::{
-    Borrowed, Bound, FromPyObject, IntoPyObjectExt, Py, PyAny, PyRef, PyRefMut, PyResult, Python,
-    intern, pyclass, pymethods,
+    Borrowed, Bound, FromPyObject, IntoPyObject, IntoPyObjectExt, Py, PyAny, PyErr, PyRef,
+    PyRefMut, PyResult, Python, intern, pyclass, pymethods,
     types::{
         PyAnyMethods, PyDict, PyDictMethods, PyGenericAlias, PyListMethods, PyModuleMethods,
         PyString, PyTuple, PyTupleMethods,
     },
 };
-use std::{clone::Clone, collections::HashMap};
+use std::{cell::RefCell, clone::Clone, collections::HashMap};
 
+use crate::errors::new_validation_error;
 use crate::utils::err_with_cause;
 
+mod class_member;
 mod default;
 mod delattr;
 mod getattr;
+mod observer;
 mod setattr;
+pub use class_member::{ClassMember, ClassMemberBuilder};
 pub use default::DefaultBehavior;
 pub use delattr::DelattrBehavior;
 pub use getattr::{PostGetattrBehavior, PreGetattrBehavior};
+pub use observer::ObserverBehavior;
 pub use setattr::{PostSetattrBehavior, PreSetattrBehavior};
 
 /// Helper function to clone the metadata dictionary of a member when cloning
@@ -50,6 +59,68 @@ fn clone_metadata(
     })
 }
 
+fn clone_on_error(on_error: &Option<Py<PyAny>>) -> Option<Py<PyAny>> {
+    on_error
+        .as_ref()
+        .map(|c| Python::attach(|py| c.clone_ref(py)))
+}
+
+/// Name of a method specified either by name or as a function of the class
+/// body, which is looked up by name on the object so that subclasses can
+/// override it.
+fn method_name(method: &Bound<'_, PyAny>) -> PyResult<Py<PyString>> {
+    if let Ok(name) = method.cast::<PyString>() {
+        return Ok(name.clone().unbind());
+    }
+    match method.getattr(intern!(method.py(), "__name__")) {
+        Ok(name) => Ok(name.cast_into::<PyString>()?.unbind()),
+        Err(err) => Err(err_with_cause(
+            method.py(),
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "Expected a method name or a function, got {}",
+                method.repr()?
+            )),
+            err,
+        )),
+    }
+}
+
+/// Metadata attached to a member.
+///
+/// Exposed to Python as a read-only mapping (types.MappingProxyType) so that
+/// the metadata of a built member cannot be mutated in place.
+#[derive(Debug)]
+pub struct MemberMetadata(HashMap<String, Py<PyAny>>);
+
+impl Clone for MemberMetadata {
+    fn clone(&self) -> Self {
+        Python::attach(|py| {
+            Self(
+                self.0
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+                    .collect(),
+            )
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &MemberMetadata {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        let dict = PyDict::new(py);
+        for (k, v) in self.0.iter() {
+            dict.set_item(k, v)?;
+        }
+        py.import(intern!(py, "types"))?
+            .getattr(intern!(py, "MappingProxyType"))?
+            .call1((dict,))
+    }
+}
+
 /// Helper class to generate a callable from a list of module names.
 ///
 /// Used for forward reference environment creation.
@@ -74,7 +145,10 @@ impl ForwardRefEnvironmentCallable {
     }
 }
 
-/// A Python descriptor that defines a member of an Ators class.
+// A Python descriptor that defines a member of an Ators class.
+//
+// This is deliberately not a doc comment: a class docstring would shadow the
+// __doc__ getter exposing the documentation of each member.
 #[pyclass(module = "ators._ators", frozen, get_all)]
 #[derive(Debug)]
 pub struct Member {
@@ -86,12 +160,17 @@ pub struct Member {
     post_getattr: PostGetattrBehavior,
     pre_setattr: PreSetattrBehavior,
     post_setattr: PostSetattrBehavior,
+    observer: ObserverBehavior,
     delattr: DelattrBehavior,
     default: DefaultBehavior,
     validator: Validator,
     // Optional metadata dictionary that can be used to store arbitrary information
     // about the member.
-    metadata: Option<HashMap<String, Py<PyAny>>>,
+    metadata: Option<MemberMetadata>,
+    /// Documentation of the member, extracted from a PEP 727 Doc object found
+    /// in the metadata of an Annotated annotation.
+    #[pyo3(name = "__doc__")]
+    doc: Option<String>,
     /// Whether this member participates in `__init__`.
     /// Defaults to `True` for public names (not starting with `_`) and
     /// `False` for private names, unless explicitly overridden via `member(init=...)`.
@@ -103,6 +182,12 @@ pub struct Member {
     /// Whether the pickle behavior was explicitly configured by the user
     /// through `member().pickle(...)`.
     pub pickle_explicit: bool,
+    /// Whether a default value computed on read is stored in the slot. When
+    /// false the default is computed afresh on each read of an unset member.
+    pub materialize_default: bool,
+    /// Callable invoked when validating a value assigned to the member fails,
+    /// which may provide a replacement value.
+    on_error: Option<Py<PyAny>>,
 }
 
 impl Member {
@@ -114,13 +199,17 @@ impl Member {
             post_getattr: self.post_getattr.clone(),
             pre_setattr: self.pre_setattr.clone(),
             post_setattr: self.post_setattr.clone(),
+            observer: self.observer.clone(),
             delattr: self.delattr.clone(),
             default: self.default.clone(),
             validator: self.validator.clone(),
-            metadata: clone_metadata(&self.metadata),
+            metadata: self.metadata.clone(),
+            doc: self.doc.clone(),
             init: self.init,
             pickle: self.pickle,
             pickle_explicit: self.pickle_explicit,
+            materialize_default: self.materialize_default,
+            on_error: clone_on_error(&self.on_error),
         }
     }
 
@@ -132,13 +221,17 @@ impl Member {
             post_getattr: self.post_getattr.clone(),
             pre_setattr: self.pre_setattr.clone(),
             post_setattr: self.post_setattr.clone(),
+            observer: self.observer.clone(),
             delattr: self.delattr.clone(),
             default: self.default.clone(),
             validator: self.validator.clone(),
-            metadata: clone_metadata(&self.metadata),
+            metadata: self.metadata.clone(),
+            doc: self.doc.clone(),
             init: self.init,
             pickle: new_pickle,
             pickle_explicit: self.pickle_explicit,
+            materialize_default: self.materialize_default,
+            on_error: clone_on_error(&self.on_error),
         }
     }
 
@@ -150,18 +243,62 @@ impl Member {
         self.slot_index
     }
 
-    pub fn metadata(&self) -> &Option<HashMap<String, Py<PyAny>>> {
-        &self.metadata
+    pub fn metadata(&self) -> Option<&HashMap<String, Py<PyAny>>> {
+        self.metadata.as_ref().map(|m| &m.0)
+    }
+
+    /// Alternate names under which the member value can be passed at init,
+    /// stored in the metadata by `member().alias(...)`.
+    pub fn aliases(&self, py: Python<'_>) -> Vec<String> {
+        self.metadata()
+            .and_then(|m| m.get("aliases"))
+            .and_then(|a| a.bind(py).extract::<Vec<String>>().ok())
+            .unwrap_or_default()
+    }
+
+    /// Notify the static observer, if any, that the value of the member was
+    /// deleted.
+    pub(crate) fn notify_delete(
+        &self,
+        object: &Bound<'_, AtorsBase>,
+        old: Py<PyAny>,
+    ) -> PyResult<()> {
+        if self.observer.is_noop() || !notifications_enabled(object) {
+            return Ok(());
+        }
+        self.observer
+            .notify(object, ChangeEvent::delete(object, &self.name, old))
     }
 
     pub fn validator(&self) -> &Validator {
         &self.validator
     }
 
+    pub fn default_behavior(&self) -> &DefaultBehavior {
+        &self.default
+    }
+
     pub fn has_default(&self) -> bool {
         !matches!(self.default, DefaultBehavior::NoDefault {})
     }
 
+    /// Whether the value of the member is computed by its pre-get behavior.
+    pub fn is_computed(&self) -> bool {
+        self.pre_getattr.is_compute()
+    }
+
+    /// Whether the value of the member can be read from an external store.
+    pub fn is_store_backed(&self) -> bool {
+        self.pre_getattr.is_store_backed()
+    }
+
+    /// Whether the member can be reset to its default by clearing its slot.
+    pub fn is_resettable(&self) -> bool {
+        matches!(self.delattr, DelattrBehavior::Slot {})
+            && self.has_default()
+            && !self.pre_setattr.is_write_protected()
+    }
+
     pub fn with_owner(&self, py: Python<'_>, owner: &Bound<'_, PyAny>) -> Self {
         Member {
             name: self.name.clone(),
@@ -170,13 +307,17 @@ impl Member {
             post_getattr: self.post_getattr.clone(),
             pre_setattr: self.pre_setattr.clone(),
             post_setattr: self.post_setattr.clone(),
+            observer: self.observer.clone(),
             delattr: self.delattr.clone(),
             default: self.default.clone(),
             validator: self.validator.with_owner(py, owner),
-            metadata: clone_metadata(&self.metadata),
+            metadata: self.metadata.clone(),
+            doc: self.doc.clone(),
             init: self.init,
             pickle: self.pickle,
             pickle_explicit: self.pickle_explicit,
+            materialize_default: self.materialize_default,
+            on_error: clone_on_error(&self.on_error),
         }
     }
 }
@@ -255,12 +396,62 @@ fn get_or_create_value<'py>(
     }
 }
 
+thread_local! {
+    /// Defaults being computed on this thread as (object address, member)
+    /// pairs, used to detect dependency cycles between defaults. Members are
+    /// compared by identity so that no name is copied on the hot path.
+    static PENDING_DEFAULTS: RefCell<Vec<(usize, Py<Member>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Remove the innermost pending default when dropped.
+struct PendingDefaultGuard;
+
+impl Drop for PendingDefaultGuard {
+    fn drop(&mut self) {
+        PENDING_DEFAULTS.with_borrow_mut(|p| p.pop());
+    }
+}
+
+/// Record that the default of a member is being computed, erroring if it is
+/// already being computed which indicates a dependency cycle.
+fn enter_default_computation<'py>(
+    member: &PyRef<'py, Member>,
+    object: &Bound<'py, AtorsBase>,
+) -> PyResult<PendingDefaultGuard> {
+    let py = object.py();
+    let address = object.as_ptr().addr();
+    let member_ptr = member.as_ptr();
+    let cycle = PENDING_DEFAULTS.with_borrow(|pending| {
+        pending
+            .iter()
+            .position(|(o, m)| *o == address && m.as_ptr() == member_ptr)
+            .map(|start| {
+                pending[start..]
+                    .iter()
+                    .map(|(_, m)| m.bind(py).borrow().name.clone())
+                    .chain(std::iter::once(member.name.clone()))
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+            })
+    });
+    if let Some(cycle) = cycle {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+            "Cyclic dependency between member defaults of {}: {cycle}",
+            object.repr()?
+        )));
+    }
+    let member = member.into_pyobject(py)?.to_owned().unbind();
+    PENDING_DEFAULTS.with_borrow_mut(|p| p.push((address, member)));
+    Ok(PendingDefaultGuard)
+}
+
 #[cold]
 fn create_default_value<'py>(
     member: &PyRef<'py, Member>,
     object: &Bound<'py, AtorsBase>,
 ) -> PyResult<Bound<'py, PyAny>> {
     let py = object.py();
+    let _guard = enter_default_computation(member, object)?;
     let default = match member.default.default(member, object) {
         Ok(value) => value,
         Err(err) => return Err(default_get_failed(py, member, object, err)?),
@@ -272,7 +463,9 @@ fn create_default_value<'py>(
         Ok(value) => value,
         Err(err) => return Err(default_validate_failed(py, member, object, err)?),
     };
-    set_slot(object, member.slot_index, &new);
+    if member.materialize_default {
+        set_slot(object, member.slot_index, &new);
+    }
     Ok(new)
 }
 
@@ -292,10 +485,13 @@ fn try_get_descriptor<'py>(
 
 /// Cold path: runs pre_get hook (only called when pre_getattr is not noop).
 #[cold]
-fn run_pre_get<'py>(self_: &PyRef<'py, Member>, object: &Bound<'py, AtorsBase>) -> PyResult<()> {
+fn run_pre_get<'py>(
+    self_: &PyRef<'py, Member>,
+    object: &Bound<'py, AtorsBase>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
     let py = object.py();
     match self_.pre_getattr.pre_get(self_, object) {
-        Ok(()) => Ok(()),
+        Ok(v) => Ok(v),
         Err(err) => Err(err_with_cause(
             py,
             pyo3::PyErr::from_type(
@@ -349,7 +545,8 @@ fn run_pre_set<'py>(self_: &PyRef<'py, Member>, object: &Bound<'py, AtorsBase>)
             // Frozen takes precedence: report it when the object was frozen at call-time.
             if is_frozen(object) {
                 return Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                    "Cannot modify {} which is frozen.",
+                    "Cannot set member '{}' on frozen {}.",
+                    self_.name,
                     object.repr()?,
                 )));
             }
@@ -379,7 +576,8 @@ fn validate_set_failed<'py>(
     // Frozen takes precedence: report it when the object was frozen at call-time.
     if is_frozen(object) {
         return Ok(pyo3::exceptions::PyTypeError::new_err(format!(
-            "Cannot modify {} which is frozen.",
+            "Cannot set member '{}' on frozen {}.",
+            member.name,
             object.repr()?,
         )));
     }
@@ -397,6 +595,46 @@ fn validate_set_failed<'py>(
     ))
 }
 
+/// Cold path: call the on_error callback of a member after a failed
+/// validation and validate the replacement value it returns.
+///
+/// The replacement is validated only once and without calling the callback
+/// again, so that a callback returning invalid values cannot loop forever.
+#[cold]
+fn recover_from_set_error<'py>(
+    py: Python<'py>,
+    member: &PyRef<'py, Member>,
+    object: &Bound<'py, AtorsBase>,
+    value: &Bound<'py, PyAny>,
+    on_error: &Bound<'py, PyAny>,
+    err: pyo3::PyErr,
+) -> PyResult<Bound<'py, PyAny>> {
+    if is_frozen(object) {
+        return Err(validate_set_failed(py, member, object, err)?);
+    }
+    let replacement = on_error.call1((member, object, value, err.value(py)))?;
+    match member
+        .validator
+        .validate(Some(&member.name), Some(object), &replacement)
+    {
+        Ok(v) => Ok(v),
+        // The error type of the retry may not accept a message as sole argument.
+        Err(retry_err) => Err(err_with_cause(
+            py,
+            new_validation_error(
+                py,
+                format!(
+                    "Validation of the value returned by the on_error callback of member \
+                     '{}' of {} failed",
+                    member.name,
+                    object.repr()?,
+                ),
+            ),
+            retry_err,
+        )),
+    }
+}
+
 /// Cold path: runs post_set hook (only called when post_setattr is not noop).
 #[cold]
 fn run_post_set<'py>(
@@ -423,6 +661,23 @@ fn run_post_set<'py>(
     }
 }
 
+/// Cold path: forward a validated value to the setter method of the object.
+#[cold]
+fn run_setter<'py>(
+    self_: &PyRef<'py, Member>,
+    object: &Bound<'py, AtorsBase>,
+    new: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    if is_frozen(object) {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot set member '{}' on frozen {}.",
+            self_.name,
+            object.repr()?
+        )));
+    }
+    run_post_set(self_, object, &None, new)
+}
+
 impl Member {
     pub fn __get__<'py>(
         self_: PyRef<'py, Self>,
@@ -433,11 +688,17 @@ impl Member {
             Err(cast_err) => return try_get_descriptor(self_, object, cast_err),
         };
 
-        if !self_.pre_getattr.is_noop() {
-            run_pre_get(&self_, object)?;
-        }
+        // A pre-get behavior may supply the value, bypassing the slot.
+        let computed = if !self_.pre_getattr.is_noop() {
+            run_pre_get(&self_, object)?
+        } else {
+            None
+        };
 
-        let value = get_or_create_value(&self_, object)?;
+        let value = match computed {
+            Some(v) => v,
+            None => get_or_create_value(&self_, object)?,
+        };
 
         if !self_.post_getattr.is_noop() {
             run_post_get(&self_, object, &value)?;
@@ -460,15 +721,40 @@ impl Member {
             run_pre_set(&self_, object)?;
         }
 
-        // Validate the new value
-        let new = match self_
-            .validator
-            .validate(Some(&self_.name), Some(object), value)
-        {
-            Ok(v) => v,
-            Err(err) => return Err(validate_set_failed(py, &self_, object, err)?),
+        // Validate the new value unless validation was disabled by no_validation
+        let new = if is_validation_disabled(py)? {
+            value.clone()
+        } else {
+            // Instance specific validators run before the member one.
+            let validated = match get_instance_validator(object, &self_.name)? {
+                Some(instance_validator) => instance_validator
+                    .get()
+                    .validate(Some(&self_.name), Some(object), value)
+                    .and_then(|v| {
+                        self_
+                            .validator
+                            .validate(Some(&self_.name), Some(object), &v)
+                    }),
+                None => self_
+                    .validator
+                    .validate(Some(&self_.name), Some(object), value),
+            };
+            match validated {
+                Ok(v) => v,
+                Err(err) => match &self_.on_error {
+                    Some(on_error) => {
+                        recover_from_set_error(py, &self_, object, value, on_error.bind(py), err)?
+                    }
+                    None => return Err(validate_set_failed(py, &self_, object, err)?),
+                },
+            }
         };
 
+        // Members forwarding to a setter method do not store the value.
+        if self_.post_setattr.is_setter() {
+            return run_setter(&self_, object, &new);
+        }
+
         // Atomically check frozen + write slot + capture old value in one
         // critical section. Err(()) means the object was frozen.
         let old_on_write = match replace_slot(object, self_.slot_index, &new) {
@@ -476,17 +762,36 @@ impl Member {
             Ok(ReplaceSlotOutcome::Unchanged) => None,
             Err(()) => {
                 return Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                    "Cannot modify {} which is frozen.",
+                    "Cannot set member '{}' on frozen {}.",
+                    self_.name,
                     object.repr()?
                 )));
             }
         };
 
         if let Some(old_on_write) = old_on_write {
+            // Restore the previous value if the change violates an invariant.
+            if instance_has_invariants(object)
+                && let Err(err) = check_invariants(object, Some(&self_.name))
+            {
+                match &old_on_write {
+                    Some(old) => set_slot(object, self_.slot_index, old.bind(py)),
+                    None => del_slot(object, self_.slot_index),
+                }
+                return Err(err);
+            }
+
             if !self_.post_setattr.is_noop() {
                 run_post_set(&self_, object, &old_on_write.as_ref(), &new)?;
             }
 
+            if !self_.observer.is_noop() && notifications_enabled(object) {
+                self_.observer.notify(
+                    object,
+                    ChangeEvent::set(object, &self_.name, old_on_write.as_ref(), &new),
+                )?;
+            }
+
             notify_member_change(
                 object,
                 &self_.name,
@@ -503,15 +808,27 @@ impl Member {
         object: Bound<'py, PyAny>,
     ) -> pyo3::PyResult<()> {
         let object = object.cast::<AtorsBase>()?;
-        self_.delattr.del(&self_, object)
+        if self_.observer.is_noop() {
+            return self_.delattr.del(&self_, object);
+        }
+
+        let old = get_slot_owned(object, self_.slot_index);
+        self_.delattr.del(&self_, object)?;
+        match old {
+            Some(old) => self_.notify_delete(object, old),
+            None => Ok(()),
+        }
     }
 
     pub fn __traverse__(&self, visit: pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
         if let Some(m) = &self.metadata {
-            for (_k, v) in m.iter() {
+            for v in m.0.values() {
                 visit.call(v)?
             }
         }
+        if let Some(on_error) = &self.on_error {
+            visit.call(on_error)?
+        }
         Ok(())
     }
 
@@ -804,16 +1121,24 @@ pub struct MemberBuilder {
     post_getattr: Option<PostGetattrBehavior>,
     pre_setattr: Option<PreSetattrBehavior>,
     post_setattr: Option<PostSetattrBehavior>,
+    observer: Option<ObserverBehavior>,
     delattr: Option<DelattrBehavior>,
     default: Option<DefaultBehavior>,
     type_validator: Option<TypeValidator>,
     value_validators: Option<Vec<ValueValidator>>,
+    normalizers: Option<Vec<Normalizer>>,
     coerce: Option<Coercer>,
     coerce_init: Option<Coercer>,
     metadata: Option<HashMap<String, Py<PyAny>>>,
+    doc: Option<String>,
     forward_ref_environment_factory: Option<Py<PyAny>>,
     pub pickle: Option<bool>,
     pub pickle_explicit: bool,
+    /// User-specified default materialization flag. None means true.
+    materialize_default: Option<bool>,
+    /// User-specified strict coercion flag. None means false.
+    coerce_strict: Option<bool>,
+    on_error: Option<Py<PyAny>>,
     inherit: bool,
     // Only required when building a new member in the metaclass since the owner
     // should be scoped to the original class definition itself and not altered
@@ -826,12 +1151,14 @@ pub struct MemberBuilder {
 impl MemberBuilder {
     #[new]
     #[allow(private_interfaces)] // MemberDefaultArg is an internal pyo3 extraction type, not a public Rust API
-    #[pyo3(signature = (*, init = None, default = MemberDefaultArg::Missing, default_factory = None))]
+    #[pyo3(signature = (*, init = None, default = MemberDefaultArg::Missing, default_factory = None, materialize_default = None, coerce_strict = None))]
     pub fn py_new<'py>(
         _py: Python<'py>,
         init: Option<bool>,
         default: MemberDefaultArg<'py>,
         default_factory: Option<Bound<'py, PyAny>>,
+        materialize_default: Option<bool>,
+        coerce_strict: Option<bool>,
     ) -> PyResult<Self> {
         if !matches!(default, MemberDefaultArg::Missing) && default_factory.is_some() {
             return Err(pyo3::exceptions::PyTypeError::new_err(
@@ -840,6 +1167,8 @@ impl MemberBuilder {
         }
         let mut builder = MemberBuilder {
             init,
+            materialize_default,
+            coerce_strict,
             ..Default::default()
         };
         if let MemberDefaultArg::Value(v) = default {
@@ -885,6 +1214,45 @@ impl MemberBuilder {
         Ok(self_)
     }
 
+    /// Declare alternate names accepted for this member at init and usable
+    /// when serializing the object.
+    ///
+    /// The aliases are stored under the 'aliases' key of the metadata.
+    #[pyo3(signature = (*aliases))]
+    pub fn alias<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        aliases: &Bound<'py, PyTuple>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let py = self_.py();
+        if aliases.is_empty() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "alias() expects at least one alias",
+            ));
+        }
+        let mut names: Vec<String> = Vec::with_capacity(aliases.len());
+        for alias in aliases.iter() {
+            let Ok(alias) = alias.cast_into::<PyString>() else {
+                return Err(pyo3::exceptions::PyTypeError::new_err(
+                    "Member aliases must be str",
+                ));
+            };
+            let alias = alias.to_string();
+            if alias.is_empty() {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "Member aliases cannot be empty",
+                ));
+            }
+            if !names.contains(&alias) {
+                names.push(alias);
+            }
+        }
+        self_.metadata.get_or_insert_with(HashMap::new).insert(
+            "aliases".into(),
+            PyTuple::new(py, names)?.into_any().unbind(),
+        );
+        Ok(self_)
+    }
+
     /// Specify a default value or default value factory for this member.
     #[pyo3(name = "default")]
     pub fn py_default<'py>(
@@ -938,7 +1306,10 @@ impl MemberBuilder {
         } else {
             // Use the Type Inferred coercer by default
             // (people should not call coerce if they do not want to coerce).
-            mself.coerce = Some(Coercer::TypeInferred {});
+            mself.coerce = Some(Coercer::TypeInferred {
+                bytes_encoding: None,
+                none_to_str: false,
+            });
         };
         self_.into_bound_py_any(py)
     }
@@ -966,11 +1337,42 @@ impl MemberBuilder {
         } else {
             // Use the Type Inferred coercer by default
             // (people should not call coerce if they do not want to coerce).
-            mself.coerce_init = Some(Coercer::TypeInferred {});
+            mself.coerce_init = Some(Coercer::TypeInferred {
+                bytes_encoding: None,
+                none_to_str: false,
+            });
         };
         self_.into_bound_py_any(py)
     }
 
+    /// Specify a callable invoked when validating a value assigned to this
+    /// member fails.
+    ///
+    /// The callable receives the member, the object, the value and the error
+    /// and can either raise or return a replacement value, which is validated
+    /// in place of the original one.
+    pub fn on_error<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        callback: Bound<'py, PyAny>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        if !callback.is_callable() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "on_error expects a callable, got {}",
+                callback.repr()?
+            )));
+        }
+        let mself = &mut *self_;
+        if mself.on_error.is_some() {
+            mself
+                .multiple_settings
+                .entry("on_error".into())
+                .and_modify(|e| *e += 1)
+                .or_insert(2);
+        }
+        mself.on_error = Some(callback.unbind());
+        Ok(self_)
+    }
+
     pub fn append_value_validator<'py>(
         mut self_: PyRefMut<'py, Self>,
         value_validator: Bound<'py, PyAny>,
@@ -989,6 +1391,70 @@ impl MemberBuilder {
         Ok(self_)
     }
 
+    /// Append a normalizer transforming the value once its type is validated.
+    ///
+    /// Normalizers run in the order they were appended, before the value
+    /// validators.
+    pub fn append_normalizer<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        normalizer: Bound<'py, PyAny>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let normalizer: Normalizer = normalizer.cast::<Normalizer>()?.as_any().extract()?;
+        self_
+            .normalizers
+            .get_or_insert_with(Vec::new)
+            .push(normalizer);
+        Ok(self_)
+    }
+
+    /// Specify the type validator of this member, bypassing the inference from
+    /// the annotation.
+    ///
+    /// Value validators and normalizers are still applied once the type is
+    /// validated.
+    pub fn validate<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        type_validator: Bound<'py, PyAny>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let mself = &mut *self_;
+        if mself.type_validator.is_some() {
+            mself
+                .multiple_settings
+                .entry("validate".into())
+                .and_modify(|e| *e += 1)
+                .or_insert(2);
+        }
+        mself.type_validator = Some(type_validator.cast::<TypeValidator>()?.as_any().extract()?);
+        Ok(self_)
+    }
+
+    /// Use a predicate, typically a function returning a TypeGuard, as the
+    /// type validator of this member.
+    ///
+    /// This is a shortcut for validate(TypeValidator.Guard(predicate)). Since
+    /// the predicate is the type validator, the coercer (if any) is used when
+    /// it returns a falsy value.
+    pub fn type_guard<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        predicate: Bound<'py, PyAny>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let py = self_.py();
+        let mself = &mut *self_;
+        if mself.type_validator.is_some() {
+            mself
+                .multiple_settings
+                .entry("type_guard".into())
+                .and_modify(|e| *e += 1)
+                .or_insert(2);
+        }
+        let guard = py
+            .get_type::<TypeValidator>()
+            .getattr(intern!(py, "Guard"))?
+            .call1((predicate,))?;
+        mself.type_validator = Some(guard.extract()?);
+        Ok(self_)
+    }
+
     /// Attach or configure the pre-get behavior for this member.
     ///
     /// Accepts a behavior object (or compatible callable) which will be
@@ -1101,6 +1567,117 @@ impl MemberBuilder {
         self_.into_bound_py_any(py)
     }
 
+    /// Set a static observer invoked with a ChangeEvent after a new value is
+    /// stored by an explicit set or the value is deleted.
+    ///
+    /// As other observers, it is only invoked when notifications are enabled
+    /// on the object.
+    pub fn observe<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        observer: Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = self_.py();
+        let mself = &mut *self_;
+        if mself.observer.is_some() {
+            mself
+                .multiple_settings
+                .entry("observe".into())
+                .and_modify(|e| *e += 1)
+                .or_insert(2);
+        }
+        match observer.cast::<ObserverBehavior>() {
+            Ok(b) => mself.observer = Some(b.as_any().extract()?),
+            Err(err) => return Err(err.into()),
+        }
+        self_.into_bound_py_any(py)
+    }
+
+    /// Back this member by an external key-value store.
+    ///
+    /// Sets the BackedBy pre-get and post-set behaviors so that the value is
+    /// read from and written through to store[key] (key defaulting to the
+    /// member name). When cache is true the slot caches the store value.
+    #[pyo3(signature = (store, key=None, cache=true))]
+    pub fn backed_by<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        store: Bound<'py, PyAny>,
+        key: Option<Bound<'py, PyAny>>,
+        cache: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = self_.py();
+        let mself = &mut *self_;
+        for (name, is_set) in [
+            ("preget", mself.pre_getattr.is_some()),
+            ("postset", mself.post_setattr.is_some()),
+        ] {
+            if is_set {
+                mself
+                    .multiple_settings
+                    .entry(name.into())
+                    .and_modify(|e| *e += 1)
+                    .or_insert(2);
+            }
+        }
+        mself.pre_getattr = Some(PreGetattrBehavior::BackedBy {
+            store: store.clone().unbind(),
+            key: key.as_ref().map(|k| k.clone().unbind()),
+            cache,
+        });
+        mself.post_setattr = Some(PostSetattrBehavior::BackedBy {
+            store: store.unbind(),
+            key: key.map(|k| k.unbind()),
+        });
+        self_.into_bound_py_any(py)
+    }
+
+    /// Compute the value of this member using a method of the object, as a
+    /// property getter would.
+    ///
+    /// The method, given by name or as a function of the class body, takes no
+    /// argument beside self and the value it returns is validated. Unless a
+    /// setter is also specified, the member cannot be set.
+    pub fn getter<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        method: Bound<'py, PyAny>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let mself = &mut *self_;
+        if mself.pre_getattr.is_some() {
+            mself
+                .multiple_settings
+                .entry("preget".into())
+                .and_modify(|e| *e += 1)
+                .or_insert(2);
+        }
+        mself.pre_getattr = Some(PreGetattrBehavior::Getter {
+            meth_name: method_name(&method)?,
+        });
+        Ok(self_)
+    }
+
+    /// Forward the values assigned to this member to a method of the object,
+    /// as a property setter would.
+    ///
+    /// The method, given by name or as a function of the class body, receives
+    /// the validated value and is responsible for storing it, typically in a
+    /// backing member. It requires a getter to be specified.
+    pub fn setter<'py>(
+        mut self_: PyRefMut<'py, Self>,
+        method: Bound<'py, PyAny>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let mself = &mut *self_;
+        if mself.post_setattr.is_some() {
+            mself
+                .multiple_settings
+                .entry("postset".into())
+                .and_modify(|e| *e += 1)
+                .or_insert(2);
+        }
+        mself.post_setattr = Some(PostSetattrBehavior::Setter {
+            meth_name: method_name(&method)?,
+        });
+        Ok(self_)
+    }
+
     /// Attach or configure the delete behavior for this member.
     ///
     /// The provided behavior controls what happens when the attribute
@@ -1155,15 +1732,22 @@ impl MemberBuilder {
             let sig = py
                 .import(intern!(py, "inspect"))?
                 .getattr(intern!(py, "signature"))?;
-            let ob_sig_len = sig
-                .call1((&factory_or_modules,))?
-                .getattr(intern!(py, "parameters"))?
-                .len()?;
-            if ob_sig_len != 0 {
-                return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                    "forward_ref_environment expect a callable taking 0 got \
-                    {factory_or_modules} which takes {ob_sig_len}."
-                )));
+            match sig.call1((&factory_or_modules,)) {
+                Ok(signature) => {
+                    let ob_sig_len = signature.getattr(intern!(py, "parameters"))?.len()?;
+                    if ob_sig_len != 0 {
+                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                            "forward_ref_environment expect a callable taking 0 got \
+                            {factory_or_modules} which takes {ob_sig_len}."
+                        )));
+                    }
+                }
+                // Builtins and some C callables do not expose a signature, in
+                // which case we trust the callable to take no argument.
+                Err(err)
+                    if err.is_instance_of::<pyo3::exceptions::PyValueError>(py)
+                        || err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) => {}
+                Err(err) => return Err(err),
             }
             fc = factory_or_modules.unbind();
         } else if factory_or_modules.is_exact_instance_of::<PyString>() {
@@ -1214,6 +1798,11 @@ impl MemberBuilder {
         self.pre_getattr.as_ref()
     }
 
+    #[inline]
+    pub(crate) fn set_doc(&mut self, doc: String) {
+        self.doc = Some(doc);
+    }
+
     #[inline]
     pub fn post_getattr(&self) -> Option<&PostGetattrBehavior> {
         self.post_getattr.as_ref()
@@ -1229,6 +1818,11 @@ impl MemberBuilder {
         self.post_setattr.as_ref()
     }
 
+    #[inline]
+    pub fn observer(&self) -> Option<&ObserverBehavior> {
+        self.observer.as_ref()
+    }
+
     #[inline]
     pub fn delattr(&self) -> Option<&DelattrBehavior> {
         self.delattr.as_ref()
@@ -1244,6 +1838,11 @@ impl MemberBuilder {
         self.value_validators.as_ref()
     }
 
+    #[inline]
+    pub fn normalizers(&self) -> Option<&Vec<Normalizer>> {
+        self.normalizers.as_ref()
+    }
+
     #[inline]
     pub fn coercer(&self) -> Option<&Coercer> {
         self.coerce.as_ref()
@@ -1259,6 +1858,11 @@ impl MemberBuilder {
         &self.metadata
     }
 
+    #[inline]
+    pub fn type_validator(&self) -> Option<&TypeValidator> {
+        self.type_validator.as_ref()
+    }
+
     #[inline]
     pub fn forward_ref_environment_factory(&self) -> Option<&Py<PyAny>> {
         self.forward_ref_environment_factory.as_ref()
@@ -1279,6 +1883,21 @@ impl MemberBuilder {
         self.delattr = Some(v);
     }
 
+    /// Set a coercer specified in the annotation metadata.
+    ///
+    /// A coercer explicitly set on the builder takes precedence and the
+    /// conflict is reported when building the member.
+    pub fn set_annotation_coercer(&mut self, c: Coercer) {
+        if self.coerce.is_some() {
+            self.multiple_settings
+                .entry("coerce".into())
+                .and_modify(|e| *e += 1)
+                .or_insert(2);
+        } else {
+            self.coerce = Some(c);
+        }
+    }
+
     #[inline]
     pub fn set_type_validator(&mut self, tv: TypeValidator) {
         self.type_validator = Some(tv);
@@ -1314,6 +1933,9 @@ impl MemberBuilder {
         if self.post_setattr.is_none() {
             self.post_setattr = Some(member.post_setattr.clone());
         }
+        if self.observer.is_none() {
+            self.observer = Some(member.observer.clone());
+        }
         if self.delattr.is_none() {
             self.delattr = Some(member.delattr.clone());
         }
@@ -1326,6 +1948,9 @@ impl MemberBuilder {
         if self.value_validators.is_none() {
             self.value_validators = Some(member.validator.value_validators.to_vec());
         }
+        if self.normalizers.is_none() {
+            self.normalizers = Some(member.validator.normalizers.to_vec());
+        }
         if self.coerce.is_none() {
             self.coerce = member.validator.coercer.clone();
         }
@@ -1333,12 +1958,21 @@ impl MemberBuilder {
             self.coerce_init = member.validator.init_coercer.clone();
         }
         if self.metadata.is_none() {
-            self.metadata = clone_metadata(&member.metadata);
+            self.metadata = member.metadata.clone().map(|m| m.0);
         }
         if self.pickle.is_none() {
             self.pickle = Some(member.pickle);
             self.pickle_explicit = member.pickle_explicit;
         }
+        if self.materialize_default.is_none() {
+            self.materialize_default = Some(member.materialize_default);
+        }
+        if self.coerce_strict.is_none() {
+            self.coerce_strict = Some(member.validator.coerce_strict);
+        }
+        if self.on_error.is_none() {
+            self.on_error = clone_on_error(&member.on_error);
+        }
     }
 
     /// Finalize the builder and construct a `Member` descriptor.
@@ -1405,45 +2039,93 @@ impl MemberBuilder {
             )),))?;
         }
 
-        // For union type validators, if type inferred coercion is requested at
-        // the member level we set coercion on all union validators if no specific
-        // was set.
-        if let TypeValidator::Union { ref mut members } = tv {
-            if let Some(Coercer::TypeInferred {}) = &self.coerce {
+        // For union (and optional) type validators, if type inferred coercion is
+        // requested at the member level we set coercion on all union validators if
+        // no specific was set.
+        let union_members: Option<&mut [Validator]> = match tv {
+            TypeValidator::Union { ref mut members } => Some(members.as_mut_slice()),
+            TypeValidator::Optional { ref mut inner } => Some(std::slice::from_mut(&mut **inner)),
+            _ => None,
+        };
+        if let Some(members) = union_members {
+            if let Some(c @ Coercer::TypeInferred { .. }) = &self.coerce {
                 for m in members.iter_mut() {
                     if m.coercer.is_none() {
-                        m.coercer = Some(Coercer::TypeInferred {});
+                        m.coercer = Some(c.clone());
                     }
                 }
             }
-            if let Some(Coercer::TypeInferred {}) = &self.coerce_init {
+            if let Some(c @ Coercer::TypeInferred { .. }) = &self.coerce_init {
                 for m in members.iter_mut() {
                     if m.init_coercer.is_none() {
-                        m.init_coercer = Some(Coercer::TypeInferred {});
+                        m.init_coercer = Some(c.clone());
                     }
                 }
             }
         }
 
+        let validator = Validator {
+            type_validator: tv,
+            value_validators: self.value_validators.unwrap_or_default().into_boxed_slice(),
+            coercer: self.coerce,
+            init_coercer: self.coerce_init,
+            normalizers: self.normalizers.unwrap_or_default().into_boxed_slice(),
+            coerce_strict: self.coerce_strict.unwrap_or(false),
+        };
+
+        // Validate static defaults eagerly, as they would be on first access, so
+        // that invalid literals are reported at class creation. Defaults which
+        // depend on unresolved forward references or whose validation needs the
+        // object are checked lazily.
+        if let Some(DefaultBehavior::Static { value }) = &self.default
+            && !validator.type_validator.contains_forward_ref()
+            && !validator.needs_object()
+            && let Err(err) = validator.validate(Some(&name), None, value.bind(py))
+        {
+            return Err(err_with_cause(
+                py,
+                pyo3::PyErr::from_type(
+                    err.get_type(py),
+                    format!("Invalid default value for member '{name}' of {type_name}"),
+                ),
+                err,
+            ));
+        }
+
+        let pre_getattr = self.pre_getattr.unwrap_or(PreGetattrBehavior::NoOp {});
+        let post_setattr = self.post_setattr.unwrap_or(PostSetattrBehavior::NoOp {});
+        if post_setattr.is_setter() && !pre_getattr.is_getter() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Member {name} of {type_name} has a setter but no getter."
+            )));
+        }
+        // A member with a getter but no setter is read only, as a property.
+        let pre_setattr = match self.pre_setattr {
+            Some(b) => b,
+            None if pre_getattr.is_getter() && !post_setattr.is_setter() => {
+                PreSetattrBehavior::Constant {}
+            }
+            None => PreSetattrBehavior::NoOp {},
+        };
+
         Ok(Member {
             name,
             slot_index: index,
-            pre_getattr: self.pre_getattr.unwrap_or(PreGetattrBehavior::NoOp {}),
+            pre_getattr,
             post_getattr: self.post_getattr.unwrap_or(PostGetattrBehavior::NoOp {}),
-            pre_setattr: self.pre_setattr.unwrap_or(PreSetattrBehavior::NoOp {}),
-            post_setattr: self.post_setattr.unwrap_or(PostSetattrBehavior::NoOp {}),
+            pre_setattr,
+            post_setattr,
+            observer: self.observer.unwrap_or(ObserverBehavior::NoOp {}),
             delattr: self.delattr.unwrap_or(DelattrBehavior::Slot {}),
             default: self.default.unwrap_or(DefaultBehavior::NoDefault {}),
-            validator: Validator {
-                type_validator: tv,
-                value_validators: self.value_validators.unwrap_or_default().into_boxed_slice(),
-                coercer: self.coerce,
-                init_coercer: self.coerce_init,
-            },
-            metadata: self.metadata,
+            validator,
+            metadata: self.metadata.map(MemberMetadata),
+            doc: self.doc,
             init,
             pickle,
             pickle_explicit: self.pickle_explicit,
+            materialize_default: self.materialize_default.unwrap_or(true),
+            on_error: self.on_error,
         })
     }
 }
@@ -1457,13 +2139,16 @@ impl Clone for MemberBuilder {
             post_getattr: self.post_getattr.clone(),
             pre_setattr: self.pre_setattr.clone(),
             post_setattr: self.post_setattr.clone(),
+            observer: self.observer.clone(),
             delattr: self.delattr.clone(),
             default: self.default.clone(),
             type_validator: self.type_validator.clone(),
             value_validators: self.value_validators.clone(),
+            normalizers: self.normalizers.clone(),
             coerce: self.coerce.clone(),
             coerce_init: self.coerce_init.clone(),
             metadata: clone_metadata(&self.metadata),
+            doc: self.doc.clone(),
             forward_ref_environment_factory: {
                 if let Some(fr) = self.forward_ref_environment_factory.as_ref() {
                     Python::attach(|py| Some(fr.clone_ref(py)))
@@ -1477,6 +2162,9 @@ impl Clone for MemberBuilder {
             multiple_settings: self.multiple_settings.clone(),
             pickle: self.pickle,
             pickle_explicit: self.pickle_explicit,
+            materialize_default: self.materialize_default,
+            coerce_strict: self.coerce_strict,
+            on_error: clone_on_error(&self.on_error),
         }
     }
 }
@@ -0,0 +1,166 @@
+/*-----------------------------------------------------------------------------
+| Copyright (c) 2025-2026, Ators contributors, see git history for details
+|
+| Distributed under the terms of the Modified BSD License.
+|
+| The full license is in the file LICENSE, distributed with this software.
+|----------------------------------------------------------------------------*/
+/// Validated class level attributes shared by all the instances of a class.
+use std::sync::Mutex;
+
+use pyo3::{Bound, Py, PyAny, PyResult, Python, pyclass, pymethods, types::PyAnyMethods};
+
+use super::MemberDefaultArg;
+use crate::utils::err_with_cause;
+use crate::validators::{Validator, is_validation_disabled};
+
+/// Builder for class members used in the class body.
+///
+/// The validator of the class member is inferred from the annotation of the
+/// attribute (the argument of ClassVar when it is used).
+#[pyclass(module = "ators._ators", name = "class_member", frozen)]
+#[derive(Debug)]
+pub struct ClassMemberBuilder {
+    default: Option<Py<PyAny>>,
+}
+
+#[pymethods]
+impl ClassMemberBuilder {
+    #[new]
+    #[allow(private_interfaces)] // MemberDefaultArg is an internal pyo3 extraction type
+    #[pyo3(signature = (*, default = MemberDefaultArg::Missing))]
+    fn py_new(default: MemberDefaultArg<'_>) -> Self {
+        Self {
+            default: match default {
+                MemberDefaultArg::Missing => None,
+                MemberDefaultArg::Value(v) => Some(v.unbind()),
+            },
+        }
+    }
+}
+
+impl ClassMemberBuilder {
+    /// Build the class member, validating the default value if any.
+    pub(crate) fn build(
+        &self,
+        py: Python<'_>,
+        name: String,
+        validator: Validator,
+    ) -> PyResult<ClassMember> {
+        let value = match &self.default {
+            Some(d) => Some(
+                validator
+                    .validate(Some(&name), None, d.bind(py))
+                    .map_err(|err| {
+                        err_with_cause(
+                            py,
+                            pyo3::PyErr::from_type(
+                                err.get_type(py),
+                                format!("Invalid default value for class member '{name}'"),
+                            ),
+                            err,
+                        )
+                    })?
+                    .unbind(),
+            ),
+            None => None,
+        };
+        Ok(ClassMember {
+            name,
+            validator,
+            value: Mutex::new(value),
+        })
+    }
+}
+
+/// Descriptor storing a validated value on the class.
+///
+/// The value is shared by the class, its subclasses and all their instances.
+/// It can only be set on the class (the metaclass routes the assignment to
+/// `set_value`) and does not use any instance slot.
+#[pyclass(module = "ators._ators", frozen)]
+#[derive(Debug)]
+pub struct ClassMember {
+    name: String,
+    validator: Validator,
+    value: Mutex<Option<Py<PyAny>>>,
+}
+
+#[pymethods]
+impl ClassMember {
+    #[getter]
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    fn validator(&self) -> Validator {
+        self.validator.clone()
+    }
+
+    /// Validate and store the value of the class member.
+    fn set_value(&self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = value.py();
+        let new = if is_validation_disabled(py)? {
+            value.clone()
+        } else {
+            self.validator
+                .validate(Some(&self.name), None, value)
+                .map_err(|err| {
+                    err_with_cause(
+                        py,
+                        pyo3::PyErr::from_type(
+                            err.get_type(py),
+                            format!("Validation failed for class member '{}'", self.name),
+                        ),
+                        err,
+                    )
+                })?
+        };
+        // The previous value is dropped once the lock is released since its
+        // __del__ may access the class member.
+        let mut guard = self
+            .value
+            .lock()
+            .expect("Class member lock is never poisoned");
+        let old = guard.replace(new.unbind());
+        drop(guard);
+        drop(old);
+        Ok(())
+    }
+
+    fn __get__<'py>(
+        &self,
+        py: Python<'py>,
+        _object: Option<&Bound<'py, PyAny>>,
+        _owner: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match &*self
+            .value
+            .lock()
+            .expect("Class member lock is never poisoned")
+        {
+            Some(v) => Ok(v.clone_ref(py).into_bound(py)),
+            None => Err(pyo3::exceptions::PyAttributeError::new_err(format!(
+                "Class member '{}' has no value",
+                self.name,
+            ))),
+        }
+    }
+
+    fn __set__(&self, object: &Bound<'_, PyAny>, _value: &Bound<'_, PyAny>) -> PyResult<()> {
+        Err(pyo3::exceptions::PyAttributeError::new_err(format!(
+            "Class member '{}' can only be set on the class, not on {}",
+            self.name,
+            object.repr()?
+        )))
+    }
+
+    fn __delete__(&self, object: &Bound<'_, PyAny>) -> PyResult<()> {
+        Err(pyo3::exceptions::PyAttributeError::new_err(format!(
+            "Class member '{}' cannot be deleted from {}",
+            self.name,
+            object.repr()?
+        )))
+    }
+}
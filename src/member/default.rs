@@ -6,9 +6,10 @@
 | The full license is in the file LICENSE, distributed with this software.
 |----------------------------------------------------------------------------*/
 /// Default value behavior definitions and related utilities.
-use crate::utils::create_behavior_callable_checker;
+use crate::class::base::{UnsetType, unset};
+use crate::utils::{create_behavior_callable_checker, err_with_cause};
 use pyo3::{
-    Bound, Py, PyAny, PyRef, PyResult, Python, pyclass,
+    Bound, Py, PyAny, PyRef, PyResult, Python, intern, pyclass,
     types::{PyAnyMethods, PyDict, PyString, PyTuple},
 };
 
@@ -35,6 +36,21 @@ pub enum DefaultBehavior {
     CallMemberObject { callable: db_callmo::Callable },
     #[pyo3(constructor = (meth_name))]
     ObjectMethod { meth_name: Py<PyString> },
+    /// Read the default from an environment variable each time a default is
+    /// needed. The raw string is passed to the parser if any, and otherwise
+    /// left to the validation of the member (and hence to its coercer). When
+    /// the variable is not set the fallback default is used, if provided.
+    #[pyo3(constructor = (name, parser=None, default=no_fallback()))]
+    EnvVar {
+        name: Py<PyString>,
+        parser: Option<Py<PyAny>>,
+        default: Py<PyAny>,
+    },
+}
+
+/// Marker used when no fallback default is provided to EnvVar.
+fn no_fallback() -> Py<PyAny> {
+    Python::attach(|py| unset(py).clone_ref(py).into_any())
 }
 
 impl DefaultBehavior {
@@ -58,15 +74,55 @@ impl DefaultBehavior {
             Self::CallMemberObject { callable } => {
                 callable.0.bind(member.py()).call1((&member.name, object))
             }
-            // XXX improve error message since people writing the method may not
-            // realize the required signature and we cannot check it at
-            // behavior definition time
-            // Do it if the call fails only and do it for all relevant behavior
+            // The method signature is checked when the class is created.
             Self::ObjectMethod { meth_name } => object.call_method1(meth_name, (member,)),
+            Self::EnvVar {
+                name,
+                parser,
+                default,
+            } => env_var_default(member.py(), name.bind(member.py()), parser, default),
         }
     }
 }
 
+/// Read a default value from an environment variable.
+fn env_var_default<'py>(
+    py: Python<'py>,
+    name: &Bound<'py, PyString>,
+    parser: &Option<Py<PyAny>>,
+    default: &Py<PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let raw = py
+        .import(intern!(py, "os"))?
+        .getattr(intern!(py, "environ"))?
+        .call_method1(intern!(py, "get"), (name,))?;
+    if raw.is_none() {
+        let default = default.bind(py);
+        return if default.is_instance_of::<UnsetType>() {
+            Err(pyo3::exceptions::PyKeyError::new_err(format!(
+                "The environment variable '{name}' is not set and no fallback default was provided"
+            )))
+        } else {
+            Ok(default.clone())
+        };
+    }
+    match parser {
+        None => Ok(raw),
+        Some(parser) => parser.bind(py).call1((&raw,)).map_err(|err| {
+            err_with_cause(
+                py,
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "Failed to parse the value {} of the environment variable '{name}'",
+                    raw.repr()
+                        .map(|r| r.to_string())
+                        .unwrap_or_else(|_| "<unrepresentable>".into())
+                )),
+                err,
+            )
+        }),
+    }
+}
+
 /// Construct a [`DefaultBehavior::Call`] from a Python callable, validating
 /// that the callable accepts exactly zero arguments.
 pub(super) fn call_default_from_factory<'py>(
@@ -96,6 +152,15 @@ impl Clone for DefaultBehavior {
             Self::ObjectMethod { meth_name } => Self::ObjectMethod {
                 meth_name: meth_name.clone_ref(py),
             },
+            Self::EnvVar {
+                name,
+                parser,
+                default,
+            } => Self::EnvVar {
+                name: name.clone_ref(py),
+                parser: parser.as_ref().map(|p| p.clone_ref(py)),
+                default: default.clone_ref(py),
+            },
         })
     }
 }
@@ -6,16 +6,34 @@
 | The full license is in the file LICENSE, distributed with this software.
 |----------------------------------------------------------------------------*/
 /// Member pre and post getattr behavior definitions and related utilities.
-use crate::utils::create_behavior_callable_checker;
+use crate::{
+    class::base::{get_slot_owned, set_slot},
+    utils::create_behavior_callable_checker,
+};
 use pyo3::{
-    Bound, Py, PyRef, PyResult, Python, pyclass,
+    Bound, Py, PyRef, PyResult, Python,
+    exceptions::PyKeyError,
+    pyclass,
     types::PyString,
     types::{PyAny, PyAnyMethods},
 };
 
 create_behavior_callable_checker!(preg_callmo, PreGetattrBehavior, CallMemberObject, 2);
+create_behavior_callable_checker!(preg_cmpmo, PreGetattrBehavior, ComputeMemberObject, 2);
 
 /// Pre-getattr behavior definitions and related utilities.
+///
+/// The Compute variants return the value to use in place of the one stored in
+/// the slot, which is then neither read nor initialized from the default.
+///
+/// BackedBy reads the value from an external key-value store (looked up by key,
+/// or by member name when no key is given). When cache is true the slot is
+/// consulted first and the validated store value is written to it. A missing
+/// key falls back to the slot and the default.
+///
+/// Getter calls the named method of the object without argument, as a
+/// property getter would, and validates the returned value which is then used
+/// in place of the slot value.
 #[pyclass(module = "ators._ators", frozen, from_py_object)]
 #[derive(Debug)]
 pub enum PreGetattrBehavior {
@@ -25,6 +43,18 @@ pub enum PreGetattrBehavior {
     CallMemberObject { callable: preg_callmo::Callable },
     #[pyo3(constructor = (meth_name))]
     ObjectMethod { meth_name: Py<PyString> },
+    #[pyo3(constructor = (callable))]
+    ComputeMemberObject { callable: preg_cmpmo::Callable },
+    #[pyo3(constructor = (meth_name))]
+    ComputeObjectMethod { meth_name: Py<PyString> },
+    #[pyo3(constructor = (store, key=None, cache=true))]
+    BackedBy {
+        store: Py<PyAny>,
+        key: Option<Py<PyAny>>,
+        cache: bool,
+    },
+    #[pyo3(constructor = (meth_name))]
+    Getter { meth_name: Py<PyString> },
 }
 
 impl PreGetattrBehavior {
@@ -33,27 +63,97 @@ impl PreGetattrBehavior {
         matches!(self, Self::NoOp {})
     }
 
+    #[inline]
+    pub(crate) fn is_compute(&self) -> bool {
+        matches!(
+            self,
+            Self::ComputeMemberObject { .. }
+                | Self::ComputeObjectMethod { .. }
+                | Self::Getter { .. }
+        )
+    }
+
+    #[inline]
+    pub(crate) fn is_getter(&self) -> bool {
+        matches!(self, Self::Getter { .. })
+    }
+
+    #[inline]
+    pub(crate) fn is_store_backed(&self) -> bool {
+        matches!(self, Self::BackedBy { .. })
+    }
+
     /// Behavior to execute before getting a member value.
-    // new is unvalidated at this stage
+    ///
+    /// Returns the value to use in place of the slot value, if any.
     pub(crate) fn pre_get<'py>(
         &self,
         member: &PyRef<'py, super::Member>,
         object: &Bound<'py, crate::class::base::AtorsBase>,
-    ) -> PyResult<()> {
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
         match self {
-            Self::NoOp {} => Ok(()),
+            Self::NoOp {} => Ok(None),
             Self::CallMemberObject { callable } => callable
                 .0
                 .bind(member.py())
                 .call1((member, object))
-                .map(|_| ()),
+                .map(|_| None),
             Self::ObjectMethod { meth_name } => {
-                object.call_method1(meth_name, (member,)).map(|_| ())
+                object.call_method1(meth_name, (member,)).map(|_| None)
+            }
+            Self::ComputeMemberObject { callable } => callable
+                .0
+                .bind(member.py())
+                .call1((member, object))
+                .map(Some),
+            Self::ComputeObjectMethod { meth_name } => {
+                object.call_method1(meth_name, (member,)).map(Some)
+            }
+            Self::BackedBy { store, key, cache } => {
+                read_from_store(member, object, store, key.as_ref(), *cache)
+            }
+            Self::Getter { meth_name } => {
+                let value = object.call_method0(meth_name)?;
+                member
+                    .validator
+                    .validate(Some(&member.name), Some(object), &value)
+                    .map(Some)
             }
         }
     }
 }
 
+/// Read a member value from an external store, validating and optionally
+/// caching it in the slot.
+fn read_from_store<'py>(
+    member: &PyRef<'py, super::Member>,
+    object: &Bound<'py, crate::class::base::AtorsBase>,
+    store: &Py<PyAny>,
+    key: Option<&Py<PyAny>>,
+    cache: bool,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = member.py();
+    if cache && get_slot_owned(object, member.slot_index).is_some() {
+        return Ok(None);
+    }
+    let stored = match key {
+        Some(k) => store.bind(py).get_item(k),
+        None => store.bind(py).get_item(&member.name),
+    };
+    let value = match stored {
+        Ok(v) => v,
+        Err(err) if err.is_instance_of::<PyKeyError>(py) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let valid = member
+        .validator
+        .validate(Some(&member.name), Some(object), &value)?;
+    if cache {
+        set_slot(object, member.slot_index, &valid);
+    }
+    Ok(Some(valid))
+}
+
 impl Clone for PreGetattrBehavior {
     fn clone(&self) -> Self {
         Python::attach(|py| match self {
@@ -64,6 +164,20 @@ impl Clone for PreGetattrBehavior {
             Self::ObjectMethod { meth_name } => Self::ObjectMethod {
                 meth_name: meth_name.clone_ref(py),
             },
+            Self::ComputeMemberObject { callable } => Self::ComputeMemberObject {
+                callable: preg_cmpmo::Callable(callable.0.clone_ref(py)),
+            },
+            Self::ComputeObjectMethod { meth_name } => Self::ComputeObjectMethod {
+                meth_name: meth_name.clone_ref(py),
+            },
+            Self::BackedBy { store, key, cache } => Self::BackedBy {
+                store: store.clone_ref(py),
+                key: key.as_ref().map(|k| k.clone_ref(py)),
+                cache: *cache,
+            },
+            Self::Getter { meth_name } => Self::Getter {
+                meth_name: meth_name.clone_ref(py),
+            },
         })
     }
 }
@@ -0,0 +1,71 @@
+/*-----------------------------------------------------------------------------
+| Copyright (c) 2026, Ators contributors, see git history for details
+|
+| Distributed under the terms of the Modified BSD License.
+|
+| The full license is in the file LICENSE, distributed with this software.
+|----------------------------------------------------------------------------*/
+/// Static observer behavior definitions and related utilities.
+use crate::{
+    class::base::AtorsBase, observers::ChangeEvent, utils::create_behavior_callable_checker,
+};
+use pyo3::{
+    Bound, Py, PyResult, Python, pyclass,
+    types::{PyAnyMethods, PyString},
+};
+
+create_behavior_callable_checker!(obs_callc, ObserverBehavior, CallChange, 1);
+
+/// Static observer of the changes of a member value.
+///
+/// The observer is shared by all the instances of the class and is invoked
+/// with a ChangeEvent after a value was stored by an explicit set or deleted,
+/// when notifications are enabled on the object.
+#[pyclass(module = "ators._ators", frozen, from_py_object)]
+#[derive(Debug)]
+pub enum ObserverBehavior {
+    #[pyo3(constructor = ())]
+    NoOp {},
+    #[pyo3(constructor = (callable))]
+    CallChange { callable: obs_callc::Callable },
+    #[pyo3(constructor = (meth_name))]
+    ObjectMethod { meth_name: Py<PyString> },
+}
+
+impl ObserverBehavior {
+    #[inline]
+    pub(crate) fn is_noop(&self) -> bool {
+        matches!(self, Self::NoOp {})
+    }
+
+    /// Notify the observer of a change of the member value.
+    pub(crate) fn notify(
+        &self,
+        object: &Bound<'_, AtorsBase>,
+        change: ChangeEvent,
+    ) -> PyResult<()> {
+        match self {
+            Self::NoOp {} => Ok(()),
+            Self::CallChange { callable } => {
+                callable.0.bind(object.py()).call1((change,)).map(|_| ())
+            }
+            Self::ObjectMethod { meth_name } => {
+                object.call_method1(meth_name, (change,)).map(|_| ())
+            }
+        }
+    }
+}
+
+impl Clone for ObserverBehavior {
+    fn clone(&self) -> Self {
+        Python::attach(|py| match self {
+            Self::NoOp {} => Self::NoOp {},
+            Self::CallChange { callable } => Self::CallChange {
+                callable: obs_callc::Callable(callable.0.clone_ref(py)),
+            },
+            Self::ObjectMethod { meth_name } => Self::ObjectMethod {
+                meth_name: meth_name.clone_ref(py),
+            },
+        })
+    }
+}
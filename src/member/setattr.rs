@@ -36,6 +36,12 @@ impl PreSetattrBehavior {
         matches!(self, Self::NoOp {})
     }
 
+    /// Whether the behavior forbids changing a value once set.
+    #[inline]
+    pub(crate) fn is_write_protected(&self) -> bool {
+        matches!(self, Self::Constant {} | Self::ReadOnly {})
+    }
+
     /// Execute the pre-setattr behavior, returning an error if the behavior
     /// does not allow the setattr to proceed.
     pub(crate) fn pre_set<'py>(
@@ -97,6 +103,15 @@ impl Clone for PreSetattrBehavior {
 
 create_behavior_callable_checker!(posts_callmoon, PostSetattrBehavior, CallNameObjectOldNew, 4);
 
+/// Post-setattr behavior definitions and related utilities.
+///
+/// BackedBy writes the new value to an external key-value store (under key, or
+/// under the member name when no key is given).
+///
+/// Setter forwards the validated value to the named method of the object, as
+/// a property setter would, in place of storing it in the slot. Since the
+/// value of the member is then owned by the object, no notification is
+/// emitted for the member.
 #[pyclass(module = "ators._ators", frozen, from_py_object)]
 #[derive(Debug)]
 pub enum PostSetattrBehavior {
@@ -106,6 +121,13 @@ pub enum PostSetattrBehavior {
     CallMemberObjectOldNew { callable: posts_callmoon::Callable },
     #[pyo3(constructor = (meth_name))]
     ObjectMethod { meth_name: Py<PyString> },
+    #[pyo3(constructor = (store, key=None))]
+    BackedBy {
+        store: Py<PyAny>,
+        key: Option<Py<PyAny>>,
+    },
+    #[pyo3(constructor = (meth_name))]
+    Setter { meth_name: Py<PyString> },
 }
 
 impl PostSetattrBehavior {
@@ -114,6 +136,11 @@ impl PostSetattrBehavior {
         matches!(self, Self::NoOp {})
     }
 
+    #[inline]
+    pub(crate) fn is_setter(&self) -> bool {
+        matches!(self, Self::Setter { .. })
+    }
+
     /// Behavior to execute after setting a member value.
     pub(crate) fn post_set<'py>(
         &self,
@@ -134,6 +161,11 @@ impl PostSetattrBehavior {
                 // XXX should use sentinel value
                 .call_method1(meth_name, (member, old, new))
                 .map(|_| ()),
+            Self::BackedBy { store, key } => match key {
+                Some(k) => store.bind(member.py()).set_item(k, new),
+                None => store.bind(member.py()).set_item(&member.name, new),
+            },
+            Self::Setter { meth_name } => object.call_method1(meth_name, (new,)).map(|_| ()),
         }
     }
 }
@@ -148,6 +180,13 @@ impl Clone for PostSetattrBehavior {
             Self::ObjectMethod { meth_name } => Self::ObjectMethod {
                 meth_name: meth_name.clone_ref(py),
             },
+            Self::BackedBy { store, key } => Self::BackedBy {
+                store: store.clone_ref(py),
+                key: key.as_ref().map(|k| k.clone_ref(py)),
+            },
+            Self::Setter { meth_name } => Self::Setter {
+                meth_name: meth_name.clone_ref(py),
+            },
         })
     }
 }
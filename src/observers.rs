@@ -39,16 +39,81 @@ impl AtorsChange {
     }
 }
 
+/// Change of a member value passed to static observers.
+///
+/// The type is "create" when the member had no value, "update" when it had
+/// one and "delete" when the value was deleted. The old (resp. new) value is
+/// None when the member had no value (resp. was deleted).
+#[pyclass(module = "ators._ators", frozen, get_all)]
+#[derive(Debug)]
+pub struct ChangeEvent {
+    object: Py<AtorsBase>,
+    name: String,
+    #[pyo3(name = "type")]
+    type_: &'static str,
+    old: Py<PyAny>,
+    new: Py<PyAny>,
+}
+
+impl ChangeEvent {
+    /// Build the event describing the assignment of a new value.
+    pub(crate) fn set<'py>(
+        object: &Bound<'py, AtorsBase>,
+        name: &str,
+        old: Option<&Py<PyAny>>,
+        new: &Bound<'py, PyAny>,
+    ) -> Self {
+        let py = object.py();
+        Self {
+            object: object.clone().unbind(),
+            name: name.to_string(),
+            type_: if old.is_some() { "update" } else { "create" },
+            old: old.map_or_else(|| py.None(), |o| o.clone_ref(py)),
+            new: new.clone().unbind(),
+        }
+    }
+
+    /// Build the event describing the deletion of a value.
+    pub(crate) fn delete(object: &Bound<'_, AtorsBase>, name: &str, old: Py<PyAny>) -> Self {
+        Self {
+            object: object.clone().unbind(),
+            name: name.to_string(),
+            type_: "delete",
+            old,
+            new: object.py().None(),
+        }
+    }
+}
+
+#[pymethods]
+impl ChangeEvent {
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "ChangeEvent(name='{}', type='{}', old={}, new={})",
+            self.name,
+            self.type_,
+            self.old.bind(py).repr()?,
+            self.new.bind(py).repr()?
+        ))
+    }
+}
+
 enum ObserverCallback {
-    WeakMethod { weak_method: Py<PyAny> },
-    Callable { callable: Py<PyAny> },
+    /// Weak reference to the callback (a weakref.WeakMethod for bound methods
+    /// and a weakref.ref otherwise) which does not keep the callback alive.
+    WeakRef {
+        weak_ref: Py<PyAny>,
+    },
+    Callable {
+        callable: Py<PyAny>,
+    },
 }
 
 impl Clone for ObserverCallback {
     fn clone(&self) -> Self {
         Python::attach(|py| match self {
-            Self::WeakMethod { weak_method } => Self::WeakMethod {
-                weak_method: weak_method.clone_ref(py),
+            Self::WeakRef { weak_ref } => Self::WeakRef {
+                weak_ref: weak_ref.clone_ref(py),
             },
             Self::Callable { callable } => Self::Callable {
                 callable: callable.clone_ref(py),
@@ -57,6 +122,53 @@ impl Clone for ObserverCallback {
     }
 }
 
+impl ObserverCallback {
+    /// Whether the callback is still alive (strong callbacks always are).
+    fn is_alive(&self, py: Python<'_>) -> bool {
+        match self {
+            Self::Callable { .. } => true,
+            Self::WeakRef { weak_ref } => weak_ref
+                .bind(py)
+                .call0()
+                .map(|target| !target.is_none())
+                .unwrap_or(true),
+        }
+    }
+}
+
+/// Build an observer holding a weak reference to the callback if possible.
+fn make_weak_observer(callback: &Bound<'_, PyAny>) -> PyResult<ObserverCallback> {
+    let py = callback.py();
+    let types = py.import(intern!(py, "types"))?;
+    // Bound methods of builtin objects are created on attribute access and
+    // their owner rarely supports weak references, so keep them alive.
+    if callback.is_instance(&types.getattr(intern!(py, "BuiltinMethodType"))?)? {
+        return Ok(ObserverCallback::Callable {
+            callable: callback.clone().unbind(),
+        });
+    }
+    let weakref = py.import(intern!(py, "weakref"))?;
+    let weak_ref = if callback.is_instance(&types.getattr(intern!(py, "MethodType"))?)? {
+        weakref
+            .getattr(intern!(py, "WeakMethod"))?
+            .call1((callback,))
+    } else {
+        weakref.getattr(intern!(py, "ref"))?.call1((callback,))
+    };
+    Ok(match weak_ref {
+        Ok(weak_ref) => ObserverCallback::WeakRef {
+            weak_ref: weak_ref.unbind(),
+        },
+        // The callback does not support weak references.
+        Err(err) if err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) => {
+            ObserverCallback::Callable {
+                callable: callback.clone().unbind(),
+            }
+        }
+        Err(err) => return Err(err),
+    })
+}
+
 #[pyclass(module = "ators._ators", frozen)]
 pub struct ObserverPool {
     callbacks: UnsafeCell<HashMap<String, Vec<ObserverCallback>>>,
@@ -72,40 +184,32 @@ impl ObserverPool {
         }
     }
 
+    /// Register a callback for a member.
+    ///
+    /// Unless strong is true, the callback is stored as a weak reference when
+    /// it supports them so that observing does not keep the callback (and the
+    /// owner of a bound method) alive. Callbacks that cannot be weakly
+    /// referenced and bound methods of builtin objects (such as list.append)
+    /// are always stored strongly.
     pub(crate) fn add<'py>(
         pool: &Bound<'py, ObserverPool>,
         member_name: &str,
         callback: &Bound<'py, PyAny>,
+        strong: bool,
     ) -> PyResult<()> {
         let py = pool.py();
+        let observer = if strong {
+            ObserverCallback::Callable {
+                callable: callback.clone().unbind(),
+            }
+        } else {
+            make_weak_observer(callback)?
+        };
         with_critical_section(pool.as_any(), || {
             let callbacks = unsafe { &mut *pool.get().callbacks.get() };
             let observers = callbacks.entry(member_name.to_string()).or_default();
-            observers.retain(|observer| match observer {
-                ObserverCallback::Callable { .. } => true,
-                ObserverCallback::WeakMethod { weak_method } => weak_method
-                    .bind(py)
-                    .call0()
-                    .map(|target| !target.is_none())
-                    .unwrap_or(true),
-            });
-
-            let method_type = py
-                .import(intern!(py, "types"))?
-                .getattr(intern!(py, "MethodType"))?;
-            if callback.is_instance(&method_type)? {
-                let weak_method = py
-                    .import(intern!(py, "weakref"))?
-                    .getattr(intern!(py, "WeakMethod"))?
-                    .call1((callback,))?
-                    .unbind();
-                observers.push(ObserverCallback::WeakMethod { weak_method });
-            } else {
-                observers.push(ObserverCallback::Callable {
-                    callable: callback.clone().unbind(),
-                });
-            }
-
+            observers.retain(|observer| observer.is_alive(py));
+            observers.push(observer);
             Ok(())
         })
     }
@@ -131,8 +235,8 @@ impl ObserverPool {
                             }
                             kept.push(observer);
                         }
-                        ObserverCallback::WeakMethod { weak_method } => {
-                            let target = weak_method.bind(py).call0()?;
+                        ObserverCallback::WeakRef { weak_ref } => {
+                            let target = weak_ref.bind(py).call0()?;
                             if target.is_none() {
                                 continue;
                             }
@@ -175,32 +279,23 @@ impl ObserverPool {
                         errors.push(err);
                     }
                 }
-                ObserverCallback::WeakMethod { weak_method } => {
-                    match weak_method.bind(py).call0() {
-                        Ok(cb) => {
-                            if !cb.is_none()
-                                && let Err(err) = cb.call1((change,))
-                            {
-                                errors.push(err);
-                            }
+                ObserverCallback::WeakRef { weak_ref } => match weak_ref.bind(py).call0() {
+                    Ok(cb) => {
+                        if !cb.is_none()
+                            && let Err(err) = cb.call1((change,))
+                        {
+                            errors.push(err);
                         }
-                        Err(err) => errors.push(err),
                     }
-                }
+                    Err(err) => errors.push(err),
+                },
             }
         }
 
         with_critical_section(pool.as_any(), || {
             let callbacks = unsafe { &mut *pool.get().callbacks.get() };
             if let Some(observers) = callbacks.get_mut(member_name) {
-                observers.retain(|observer| match observer {
-                    ObserverCallback::Callable { .. } => true,
-                    ObserverCallback::WeakMethod { weak_method } => weak_method
-                        .bind(py)
-                        .call0()
-                        .map(|target| !target.is_none())
-                        .unwrap_or(true),
-                });
+                observers.retain(|observer| observer.is_alive(py));
                 if observers.is_empty() {
                     callbacks.remove(member_name);
                 }
@@ -218,7 +313,7 @@ impl ObserverPool {
         for observers in callbacks.values() {
             for observer in observers {
                 match observer {
-                    ObserverCallback::WeakMethod { weak_method } => visit.call(weak_method)?,
+                    ObserverCallback::WeakRef { weak_ref } => visit.call(weak_ref)?,
                     ObserverCallback::Callable { callable } => visit.call(callable)?,
                 }
             }
@@ -22,6 +22,8 @@ use std::collections::HashMap;
 // XXX  use module state to store those types
 static TYPING_ANY_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
 static TYPING_TYPEVAR_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static DECIMAL_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static DECIMAL_INVALID_OPERATION_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
 
 #[inline]
 fn get_typing_any_type<'py>(py: pyo3::Python<'py>) -> &'py Bound<'py, PyType> {
@@ -41,6 +43,28 @@ fn get_typing_typevar_type<'py>(py: pyo3::Python<'py>) -> &'py Bound<'py, PyType
         .expect("typing.TypeVar is a type and should be a PyType.")
 }
 
+/// Return the decimal.Decimal type.
+#[inline]
+pub(crate) fn get_decimal_type<'py>(py: pyo3::Python<'py>) -> &'py Bound<'py, PyType> {
+    DECIMAL_TYPE
+        .import(py, "decimal", "Decimal")
+        .expect("decimal.Decimal should always be present in the decimal module.")
+        .cast::<PyType>()
+        .expect("decimal.Decimal is a type and should be a PyType.")
+}
+
+/// Return the decimal.InvalidOperation type.
+#[inline]
+pub(crate) fn get_decimal_invalid_operation_type<'py>(
+    py: pyo3::Python<'py>,
+) -> &'py Bound<'py, PyType> {
+    DECIMAL_INVALID_OPERATION_TYPE
+        .import(py, "decimal", "InvalidOperation")
+        .expect("decimal.InvalidOperation should always be present in the decimal module.")
+        .cast::<PyType>()
+        .expect("decimal.InvalidOperation is a type and should be a PyType.")
+}
+
 /// Return `true` when `param` is `typing.Any`.
 pub(crate) fn is_any_type(param: &Bound<'_, PyAny>) -> PyResult<bool> {
     Ok(param.is(get_typing_any_type(param.py())))
@@ -203,6 +227,18 @@ macro_rules! create_behavior_callable_checker {
             #[derive(Debug)]
             pub struct Callable(pub Py<PyAny>);
 
+            impl PartialEq for Callable {
+                fn eq(&self, other: &Self) -> bool {
+                    crate::utils::py_eq(&self.0, &other.0)
+                }
+            }
+
+            impl std::hash::Hash for Callable {
+                fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                    crate::utils::py_hash(&self.0, state);
+                }
+            }
+
             impl FromPyObject<'_, '_> for Callable {
                 type Error = PyErr;
                 fn extract(ob: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
@@ -245,6 +281,23 @@ pub(crate) use create_behavior_callable_checker;
 // This approach allows to implement an equivalent of custom constructor
 // for enums
 
+/// Compare two Python objects using Python equality.
+///
+/// Used to implement structural equality of the pyclasses wrapping Python
+/// objects. Objects whose comparison fails are considered different.
+pub(crate) fn py_eq<T>(a: &Py<T>, b: &Py<T>) -> bool {
+    a.is(b) || Python::attach(|py| a.bind(py).as_any().eq(b.bind(py).as_any()).unwrap_or(false))
+}
+
+/// Feed the Python hash of an object to a Rust hasher.
+///
+/// Unhashable objects all contribute the same value which is consistent with
+/// [`py_eq`] at the cost of more collisions.
+pub(crate) fn py_hash<T, H: std::hash::Hasher>(o: &Py<T>, state: &mut H) {
+    use std::hash::Hash;
+    Python::attach(|py| o.bind(py).as_any().hash().unwrap_or(-1)).hash(state);
+}
+
 /// Wrapper allowing to hash and compare for eq Py<PyType> for use in HashMap
 /// while guaranteeing that the underlying Python type remain valid.
 #[allow(dead_code)]
@@ -8,48 +8,151 @@
 /// Validator structs managing type and value validation and performing
 /// coercion if necessary
 use pyo3::{
-    Bound, Py, PyAny, PyResult, Python, pyclass, pymethods,
-    types::{PyDict, PyTuple},
+    Bound, Py, PyAny, PyErr, PyResult, Python,
+    exceptions::{PyTypeError, PyValueError},
+    ffi, intern, pyclass, pymethods,
+    sync::PyOnceLock,
+    types::{PyAnyMethods, PyDict, PyTuple, PyTypeMethods},
 };
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::errors::{into_coercion_error, new_coercion_error};
+use crate::utils::err_with_cause;
+
 mod coercer;
 pub use coercer::Coercer;
+mod normalizers;
+pub use normalizers::Normalizer;
 pub(crate) mod types;
 pub use types::TypeValidator;
 mod values;
-pub(crate) use values::ValidValues;
 pub use values::ValueValidator;
+pub(crate) use values::{LiteralValues, RangeBound};
+
+static VALIDATION_DISABLED: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+/// Number of no_validation contexts currently entered, in any thread.
+///
+/// Looking up the ContextVar on every assignment is comparatively costly, so
+/// it is only consulted while at least one context is active.
+static ACTIVE_NO_VALIDATION: AtomicUsize = AtomicUsize::new(0);
+
+/// ContextVar flagging that member assignments should skip validation.
+fn get_validation_disabled_var<'py>(py: Python<'py>) -> &'py Bound<'py, PyAny> {
+    VALIDATION_DISABLED
+        .get_or_try_init(py, || -> PyResult<Py<PyAny>> {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(intern!(py, "default"), false)?;
+            Ok(py
+                .import(intern!(py, "contextvars"))?
+                .getattr(intern!(py, "ContextVar"))?
+                .call(("ators_validation_disabled",), Some(&kwargs))?
+                .unbind())
+        })
+        .expect("contextvars.ContextVar should always be constructible.")
+        .bind(py)
+}
+
+/// Return whether validation of member assignments is disabled in the current context.
+#[inline]
+pub(crate) fn is_validation_disabled(py: Python<'_>) -> PyResult<bool> {
+    if ACTIVE_NO_VALIDATION.load(Ordering::Acquire) == 0 {
+        return Ok(false);
+    }
+    let var = get_validation_disabled_var(py);
+    let mut value = std::ptr::null_mut();
+    // Safety: var is a ContextVar and value receives a new reference (the
+    // ContextVar has a default so it is never null on success).
+    if unsafe { ffi::PyContextVar_Get(var.as_ptr(), std::ptr::null_mut(), &mut value) } < 0 {
+        return Err(PyErr::fetch(py));
+    }
+    let value = unsafe { Bound::from_owned_ptr(py, value) };
+    Ok(value.is(unsafe { Bound::from_borrowed_ptr(py, ffi::Py_True()) }))
+}
+
+/// Context manager disabling type and value validation of member assignments.
+///
+/// While active, `Member.__set__` stores the provided value as is (no
+/// validation nor coercion) but still runs the pre/post setattr behaviors and
+/// notifies observers. This is unsafe: invalid values will be stored and
+/// containers will not be converted to their validating counterparts. It is
+/// meant for bulk loading of data validated upstream and is scoped to the
+/// current context (thread or asyncio task). Contexts copied while it is
+/// active (e.g. by a task created within the block) stop skipping validation
+/// once every active no_validation block has exited. Nested uses (including
+/// of the same context manager) are supported.
+#[pyclass(module = "ators._ators", name = "no_validation")]
+pub struct NoValidation {
+    tokens: Vec<Py<PyAny>>,
+}
+
+#[pymethods]
+impl NoValidation {
+    #[new]
+    fn new() -> Self {
+        Self { tokens: Vec::new() }
+    }
+
+    fn __enter__(&mut self, py: Python<'_>) -> PyResult<()> {
+        let var = get_validation_disabled_var(py);
+        let token = var.call_method1(intern!(py, "set"), (true,))?.unbind();
+        self.tokens.push(token);
+        ACTIVE_NO_VALIDATION.fetch_add(1, Ordering::AcqRel);
+        Ok(())
+    }
+
+    fn __exit__(
+        &mut self,
+        py: Python<'_>,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        if let Some(token) = self.tokens.pop() {
+            ACTIVE_NO_VALIDATION.fetch_sub(1, Ordering::AcqRel);
+            get_validation_disabled_var(py).call_method1(intern!(py, "reset"), (token,))?;
+        }
+        Ok(false)
+    }
+}
 
 // FIXME pub visibility is required to alter coercion behaviors (for Union),
 // may want a specific API later
 // NOTE There is no sanity check that value validators make sense in combination
 // with the type validator since arbitrary code (member method, object method)
 // prevent any truly meaningful validation
-#[pyclass(module = "ators._ators", frozen, from_py_object)]
-#[derive(Debug)]
+#[pyclass(module = "ators._ators", frozen, eq, hash, from_py_object)]
+#[derive(Debug, PartialEq, Hash)]
 pub struct Validator {
     pub type_validator: TypeValidator,
     pub value_validators: Box<[ValueValidator]>,
     pub coercer: Option<Coercer>,
     pub init_coercer: Option<Coercer>,
+    pub normalizers: Box<[Normalizer]>,
+    /// Whether coerced values are validated again, rather than trusting the
+    /// coercer.
+    pub coerce_strict: bool,
 }
 
 #[pymethods]
 impl Validator {
     #[new]
-    pub fn new(
+    #[pyo3(signature = (type_validator, value_validators=None, coercer=None, init_coercer=None, normalizers=None, coerce_strict=false))]
+    fn py_new(
         type_validator: TypeValidator,
         value_validators: Option<Vec<ValueValidator>>,
         coercer: Option<Coercer>,
         init_coercer: Option<Coercer>,
+        normalizers: Option<Vec<Normalizer>>,
+        coerce_strict: bool,
     ) -> Self {
         Self {
-            type_validator,
-            value_validators: value_validators
-                .map(|v| v.into_boxed_slice())
+            normalizers: normalizers
+                .map(|n| n.into_boxed_slice())
                 .unwrap_or_else(|| Box::new([])),
-            coercer,
-            init_coercer,
+            coerce_strict,
+            ..Self::new(type_validator, value_validators, coercer, init_coercer)
         }
     }
 
@@ -61,9 +164,18 @@ impl Validator {
                 .into_boxed_slice(),
             coercer: self.coercer.clone(),
             init_coercer: self.init_coercer.clone(),
+            normalizers: self.normalizers.clone(),
+            coerce_strict: self.coerce_strict,
         })
     }
 
+    /// Validate a value outside of any object, coercing it if the validator
+    /// allows it, and return the validated value.
+    #[pyo3(name = "validate")]
+    fn py_validate<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        self.validate(None, None, value)
+    }
+
     #[getter]
     fn get_type_validator(&self) -> TypeValidator {
         self.type_validator.clone()
@@ -74,6 +186,11 @@ impl Validator {
         self.value_validators.to_vec()
     }
 
+    #[getter]
+    fn get_normalizers(&self) -> Vec<Normalizer> {
+        self.normalizers.to_vec()
+    }
+
     #[getter]
     fn get_coercer(&self) -> Option<Coercer> {
         self.coercer.clone()
@@ -81,11 +198,43 @@ impl Validator {
 
     #[getter]
     fn get_init_coercer(&self) -> Option<Coercer> {
-        self.coercer.clone()
+        self.init_coercer.clone()
+    }
+
+    #[getter]
+    fn get_coerce_strict(&self) -> bool {
+        self.coerce_strict
     }
 }
 
 impl Validator {
+    pub fn new(
+        type_validator: TypeValidator,
+        value_validators: Option<Vec<ValueValidator>>,
+        coercer: Option<Coercer>,
+        init_coercer: Option<Coercer>,
+    ) -> Self {
+        Self {
+            type_validator,
+            value_validators: value_validators
+                .map(|v| v.into_boxed_slice())
+                .unwrap_or_else(|| Box::new([])),
+            coercer,
+            init_coercer,
+            normalizers: Box::new([]),
+            coerce_strict: false,
+        }
+    }
+
+    /// Whether validating a value (coercion included) needs the object owning
+    /// the member, at any nesting level.
+    pub(crate) fn needs_object(&self) -> bool {
+        self.value_validators.iter().any(|v| v.needs_object())
+            || self.normalizers.iter().any(|n| n.needs_object())
+            || self.coercer.as_ref().is_some_and(|c| c.needs_object())
+            || self.type_validator.any_nested(&|v| v.needs_object())
+    }
+
     /// Validate the value against the type and value validators, with coercion
     /// if validation fails and a coercer is defined
     pub fn validate<'py>(
@@ -100,6 +249,8 @@ impl Validator {
                 // Apply cold_branch once stabilized in 1.95
                 if let Some(c) = &self.coercer {
                     c.coerce_value(false, &self.type_validator, name, object, value)
+                        .and_then(|v| self.revalidate_coerced_container(c, name, object, v))
+                        .map_err(|err| into_coercion_error(value.py(), err))
                 } else {
                     Err(err)
                 }
@@ -124,20 +275,104 @@ impl Validator {
         object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
         value: &Bound<'py, PyAny>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        if is_init && let Some(c) = &self.init_coercer {
-            c.coerce_value(is_init, &self.type_validator, member_name, object, value)
-        } else if !is_init && let Some(c) = &self.coercer {
+        let coercer = if is_init {
+            self.init_coercer.as_ref()
+        } else {
+            self.coercer.as_ref()
+        };
+        if let Some(c) = coercer {
             c.coerce_value(is_init, &self.type_validator, member_name, object, value)
+                .and_then(|v| self.revalidate_coerced_container(c, member_name, object, v))
+                .map_err(|err| into_coercion_error(value.py(), err))
         } else {
-            Err(pyo3::exceptions::PyTypeError::new_err(
-                "No coercer defined for {:?}",
+            Err(new_coercion_error(
+                value.py(),
+                format!("No coercer defined for {:?}", self.type_validator),
             ))
         }
     }
 
     #[inline]
     pub fn is_set_passthrough(&self) -> bool {
-        matches!(self.type_validator, TypeValidator::Any {}) && self.value_validators.is_empty()
+        matches!(self.type_validator, TypeValidator::Any {})
+            && self.value_validators.is_empty()
+            && self.normalizers.is_empty()
+    }
+
+    /// Validate the type of the value produced by a custom coercer when the
+    /// type validator is a container validator.
+    ///
+    /// Coerced values are not validated again, so a custom coercer returning
+    /// the wrong container would otherwise go unnoticed. TypeInferred builds
+    /// the containers itself and is hence trusted.
+    ///
+    /// When coerce_strict is set, the coerced value is instead fully validated
+    /// (type, normalizers and value validators) whatever the coercer.
+    fn revalidate_coerced_container<'py>(
+        &self,
+        coercer: &Coercer,
+        member_name: Option<&str>,
+        object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
+        coerced: Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if self.coerce_strict {
+            let py = coerced.py();
+            return self
+                .strict_validate(member_name, object, &coerced)
+                .map_err(|err| {
+                    err_with_cause(
+                        py,
+                        new_coercion_error(
+                            py,
+                            format!(
+                                "The value {} produced by the {} coercer is not valid for \
+                                 the member",
+                                coerced.repr().map(|r| r.to_string()).unwrap_or_default(),
+                                coercer.kind()
+                            ),
+                        ),
+                        err,
+                    )
+                });
+        }
+        if matches!(coercer, Coercer::TypeInferred { .. })
+            || !matches!(
+                self.type_validator,
+                TypeValidator::Tuple { .. }
+                    | TypeValidator::VarTuple { .. }
+                    | TypeValidator::List { .. }
+                    | TypeValidator::Set { .. }
+                    | TypeValidator::FrozenSet { .. }
+                    | TypeValidator::Dict { .. }
+                    | TypeValidator::Sequence { .. }
+                    | TypeValidator::Mapping { .. }
+                    | TypeValidator::TypedDict { .. }
+            )
+        {
+            return Ok(coerced);
+        }
+        let py = coerced.py();
+        self.type_validator
+            .validate_type(member_name, object, &coerced)
+            .map_err(|err| {
+                let type_name = coerced
+                    .get_type()
+                    .name()
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|_| "<unknown>".to_string());
+                err_with_cause(
+                    py,
+                    new_coercion_error(
+                        py,
+                        format!(
+                            "The {} coercer returned a value of type {type_name} which is not \
+                             valid for the container type of the member",
+                            coercer.kind()
+                        ),
+                    ),
+                    err,
+                )
+            })
     }
 
     /// Validate the value against the type and value validators, without coercion
@@ -147,9 +382,16 @@ impl Validator {
         object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
         value: &Bound<'py, PyAny>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let v = self
+        let mut v = self
             .type_validator
             .validate_type(member_name, object, value)?;
+        if !self.normalizers.is_empty() {
+            for n in &self.normalizers {
+                v = n.normalize(member_name, object, &v)?;
+            }
+            // Normalizers may return a value of any type.
+            v = self.type_validator.validate_type(member_name, object, &v)?;
+        }
         for vv in &self.value_validators {
             vv.validate_value(member_name, object, &v)?;
         }
@@ -158,6 +400,64 @@ impl Validator {
 }
 
 impl Validator {
+    /// Collect the messages of the errors raised when validating a value,
+    /// without coercing it.
+    ///
+    /// Type errors are prefixed with "Type error: " and value errors with
+    /// "Value error: ". Value validators only run on values of a valid type,
+    /// and all of them run so that every problem is reported. Only TypeError
+    /// and ValueError are treated as validation failures, other errors
+    /// propagate.
+    pub(crate) fn check_value<'py>(
+        &self,
+        member_name: Option<&str>,
+        object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
+        value: &Bound<'py, PyAny>,
+    ) -> PyResult<Vec<String>> {
+        let py = value.py();
+        // Include the causes since nested failures (e.g. of a container item)
+        // are described by them.
+        let message = |kind: &str, err: PyErr| -> PyResult<String> {
+            if !(err.is_instance_of::<PyTypeError>(py) || err.is_instance_of::<PyValueError>(py)) {
+                return Err(err);
+            }
+            let mut msg = format!("{kind} error: {}", err.value(py).str()?);
+            let mut cause = err.cause(py);
+            while let Some(c) = cause {
+                msg.push(' ');
+                msg.push_str(&c.value(py).str()?.to_string());
+                cause = c.cause(py);
+            }
+            Ok(msg)
+        };
+        let mut v = match self
+            .type_validator
+            .validate_type(member_name, object, value)
+        {
+            Ok(v) => v,
+            Err(err) => return Ok(vec![message("Type", err)?]),
+        };
+        for n in &self.normalizers {
+            v = match n.normalize(member_name, object, &v) {
+                Ok(v) => v,
+                Err(err) => return Ok(vec![message("Value", err)?]),
+            };
+        }
+        if !self.normalizers.is_empty() {
+            v = match self.type_validator.validate_type(member_name, object, &v) {
+                Ok(v) => v,
+                Err(err) => return Ok(vec![message("Type", err)?]),
+            };
+        }
+        let mut errors = Vec::new();
+        for vv in &self.value_validators {
+            if let Err(err) = vv.validate_value(member_name, object, &v) {
+                errors.push(message("Value", err)?);
+            }
+        }
+        Ok(errors)
+    }
+
     /// Clone and set the owner of the type validator which is used for ForwardRef resolution
     pub(crate) fn with_owner(&self, py: Python<'_>, owner: &Bound<'_, PyAny>) -> Self {
         Self {
@@ -165,6 +465,8 @@ impl Validator {
             value_validators: self.value_validators.clone(),
             coercer: self.coercer.clone(),
             init_coercer: self.init_coercer.clone(),
+            normalizers: self.normalizers.clone(),
+            coerce_strict: self.coerce_strict,
         }
     }
 }
@@ -176,6 +478,8 @@ impl Clone for Validator {
             value_validators: self.value_validators.iter().cloned().collect(),
             coercer: self.coercer.clone(),
             init_coercer: self.init_coercer.clone(),
+            normalizers: self.normalizers.iter().cloned().collect(),
+            coerce_strict: self.coerce_strict,
         }
     }
 }
@@ -187,6 +491,8 @@ impl Default for Validator {
             value_validators: Box::new([]),
             coercer: None,
             init_coercer: None,
+            normalizers: Box::new([]),
+            coerce_strict: false,
         }
     }
 }
@@ -7,27 +7,71 @@
 |----------------------------------------------------------------------------*/
 /// Structures used to manage coercion behaviors for validators.
 use pyo3::{
-    Bound, Py, PyAny, PyResult, PyTypeInfo, Python, pyclass,
+    Borrowed, Bound, FromPyObject, IntoPyObject, Py, PyAny, PyErr, PyResult, PyTypeInfo, Python,
+    exceptions::PyValueError,
+    intern, pyclass,
     types::{
-        PyAnyMethods, PyBool, PyBytes, PyComplex, PyDict, PyDictMethods, PyFloat, PyFrozenSet,
-        PyInt, PyList, PyListMethods, PyMapping, PyMappingMethods, PySequence, PySequenceMethods,
-        PySet, PyString, PyTuple,
+        PyAnyMethods, PyBool, PyByteArray, PyBytes, PyComplex, PyDict, PyDictMethods, PyFloat,
+        PyFrozenSet, PyInt, PyList, PyListMethods, PyMapping, PyMappingMethods, PyMemoryView,
+        PySequence, PySequenceMethods, PySet, PyString, PyStringMethods, PyTuple, PyTupleMethods,
+        PyType, PyTypeMethods,
     },
 };
 
-use super::TypeValidator;
-use crate::utils::{TupleBuilder, create_behavior_callable_checker, err_with_cause};
+use super::{TypeValidator, types::BoxedValidator};
+use crate::errors::new_coercion_error;
+use crate::utils::{
+    TupleBuilder, create_behavior_callable_checker, err_with_cause,
+    get_decimal_invalid_operation_type, get_decimal_type, py_eq, py_hash,
+};
+use std::convert::Infallible;
+use std::hash::{Hash, Hasher};
 
 create_behavior_callable_checker!(co_callv, Coercer, CallValue, 1);
 create_behavior_callable_checker!(co_callmovi, Coercer, CallNameObjectValueInit, 4);
 
+/// Base used to parse ints, validated on construction to be 0 or in 2..=36
+/// (as accepted by int(value, base)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntBase(u32);
+
+impl FromPyObject<'_, '_> for IntBase {
+    type Error = PyErr;
+    fn extract(ob: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        let base: u32 = ob.extract()?;
+        if base == 0 || (2..=36).contains(&base) {
+            Ok(IntBase(base))
+        } else {
+            Err(PyValueError::new_err(format!(
+                "Coercer.ParseInt expects a base of 0 or between 2 and 36, got {base}."
+            )))
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &IntBase {
+    type Target = PyInt;
+    type Output = Bound<'py, PyInt>;
+    type Error = Infallible;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.0.into_pyobject(py)
+    }
+}
+
 /// Enum managing coercion behaviors for validators, used to coerce values to
 /// the right type.
-#[pyclass(module = "ators._ators", frozen, from_py_object)]
+#[pyclass(module = "ators._ators", frozen, eq, hash, from_py_object)]
 #[derive(Debug)]
 pub enum Coercer {
-    #[pyo3(constructor = ())]
-    TypeInferred {},
+    /// Coerce using the type inferred from the type validator. Coercing bytes
+    /// to str (and str to bytes) requires an explicit encoding since
+    /// str(b"a") yields "b'a'". Similarly, coercing None to str is rejected
+    /// unless none_to_str is true since str(None) yields "None".
+    #[pyo3(constructor = (bytes_encoding = None, none_to_str = false))]
+    TypeInferred {
+        bytes_encoding: Option<String>,
+        none_to_str: bool,
+    },
     // FIXME handle nested coercing for container by providing custom modes
     #[pyo3(constructor = (callable))]
     CallValue { callable: co_callv::Callable },
@@ -35,9 +79,59 @@ pub enum Coercer {
     CallNameObjectValueInit { callable: co_callmovi::Callable },
     #[pyo3(constructor = (meth_name))]
     ObjectMethod { meth_name: Py<PyString> },
+    /// Parse a str as an int in the given base (as int(value, base)). Use a
+    /// base of 0 to infer the base from the 0x, 0o or 0b prefix.
+    #[pyo3(constructor = (base = IntBase(10)))]
+    ParseInt { base: IntBase },
+    /// Parse a str as a float, optionally removing a thousands separator and
+    /// rejecting scientific notation.
+    #[pyo3(constructor = (thousands_separator = None, scientific = true))]
+    ParseFloat {
+        thousands_separator: Option<String>,
+        scientific: bool,
+    },
+    /// Coerce null-like values (by default only the empty str) to None. Other
+    /// values are handed to the optional coercer, which for Optional members
+    /// coerces to the non None type.
+    #[pyo3(constructor = (values = None, coercer = None))]
+    NullLike {
+        values: Option<Py<PyTuple>>,
+        coercer: Option<Py<Coercer>>,
+    },
+    /// Apply several coercers in turn, each one receiving the value produced
+    /// by the previous one. A failure reports the stage that failed and
+    /// chains the underlying error as cause.
+    #[pyo3(constructor = (*coercers))]
+    Chain { coercers: Vec<Py<Coercer>> },
 }
 
 impl Coercer {
+    /// Whether the coercer needs the object owning the member.
+    pub(crate) fn needs_object(&self) -> bool {
+        match self {
+            Self::CallNameObjectValueInit { .. } | Self::ObjectMethod { .. } => true,
+            Self::NullLike { coercer, .. } => {
+                coercer.as_ref().is_some_and(|c| c.get().needs_object())
+            }
+            Self::Chain { coercers } => coercers.iter().any(|c| c.get().needs_object()),
+            _ => false,
+        }
+    }
+
+    /// Name of the coercer variant used when reporting errors.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::TypeInferred { .. } => "TypeInferred",
+            Self::CallValue { .. } => "CallValue",
+            Self::CallNameObjectValueInit { .. } => "CallNameObjectValueInit",
+            Self::ObjectMethod { .. } => "ObjectMethod",
+            Self::ParseInt { .. } => "ParseInt",
+            Self::ParseFloat { .. } => "ParseFloat",
+            Self::NullLike { .. } => "NullLike",
+            Self::Chain { .. } => "Chain",
+        }
+    }
+
     /// Coerce the value using the coercer and return the coerced value
     pub(crate) fn coerce_value<'py>(
         &self,
@@ -49,24 +143,111 @@ impl Coercer {
     ) -> PyResult<Bound<'py, PyAny>> {
         let py = value.py();
         match self {
-            Self::TypeInferred {} => match type_validator {
+            Self::TypeInferred {
+                bytes_encoding,
+                none_to_str,
+            } => match type_validator {
                 TypeValidator::Any {} => Ok(value.clone()),  // Dead code but for completeness
                 TypeValidator::None {} => Err(
-                    pyo3::exceptions::PyTypeError::new_err(
+                    new_coercion_error(py,
                         "Cannot coerce a value to NoneType",
                     ),
                 ),
+                TypeValidator::Never {} => Err(
+                    new_coercion_error(py,
+                        "Cannot coerce a value to Never",
+                    ),
+                ),
                 TypeValidator::Bool {} => PyBool::type_object(py).call1((value,)),
                 TypeValidator::Int {} => PyInt::type_object(py).call1((value,)),
                 TypeValidator::Float {} => PyFloat::type_object(py).call1((value,)),
                 TypeValidator::Complex {} => PyComplex::type_object(py).call1((value,)),
-                TypeValidator::Str {} => PyString::type_object(py).call1((value,)),
-                TypeValidator::Bytes {} => PyBytes::type_object(py).call1((value,)),
-                TypeValidator::Tuple { items } => {
+                // Floats are rejected since their exact decimal expansion is
+                // rarely the expected value (Decimal(0.1) != Decimal("0.1")).
+                TypeValidator::Decimal {} => {
+                    if value.is_instance_of::<PyFloat>() {
+                        Err(new_coercion_error(py, format!(
+                            "Cannot coerce the float {} to Decimal since floats cannot \
+                             represent most decimal values exactly, pass a str or an int \
+                             instead (e.g. Decimal(str(value)))",
+                            value.repr()?
+                        )))
+                    } else {
+                        get_decimal_type(py).call1((value,)).map_err(|err| {
+                            if !err.is_instance(py, get_decimal_invalid_operation_type(py)) {
+                                return err;
+                            }
+                            match value.repr() {
+                                Ok(r) => err_with_cause(
+                                    py,
+                                    new_coercion_error(
+                                        py,
+                                        format!("Cannot coerce {r} to Decimal"),
+                                    ),
+                                    err,
+                                ),
+                                Err(e) => e,
+                            }
+                        })
+                    }
+                },
+                TypeValidator::Str {} => {
+                    if value.is_instance_of::<PyBytes>() {
+                        match bytes_encoding {
+                            Some(encoding) => apply_codec(value, "decode", encoding),
+                            None => Err(new_coercion_error(py,
+                                "Cannot coerce bytes to str without an explicit encoding, \
+                                 use Coercer.TypeInferred(bytes_encoding=...)",
+                            )),
+                        }
+                    } else if value.is_none() && !none_to_str {
+                        Err(new_coercion_error(py,
+                            "Cannot coerce None to str, use Optional[str] to accept None \
+                             or Coercer.TypeInferred(none_to_str=True) to get 'None'",
+                        ))
+                    } else {
+                        PyString::type_object(py).call1((value,))
+                    }
+                },
+                TypeValidator::Bytes {} => {
+                    if value.is_instance_of::<PyString>() {
+                        match bytes_encoding {
+                            Some(encoding) => apply_codec(value, "encode", encoding),
+                            None => Err(new_coercion_error(py,
+                                "Cannot coerce str to bytes without an explicit encoding, \
+                                 use Coercer.TypeInferred(bytes_encoding=...)",
+                            )),
+                        }
+                    } else {
+                        PyBytes::type_object(py).call1((value,))
+                    }
+                },
+                // bytearray(int) builds a zero-filled bytearray of that size
+                // which is rarely the expected result.
+                TypeValidator::ByteArray {} => {
+                    if value.is_instance_of::<PyString>() {
+                        Err(new_coercion_error(py,
+                            "Cannot coerce str to bytearray, encode it to bytes first",
+                        ))
+                    } else if value.is_instance_of::<PyInt>() {
+                        Err(new_coercion_error(py, format!(
+                            "Cannot coerce the int {} to bytearray, pass bytes or an \
+                             iterable of ints instead",
+                            value.repr()?
+                        )))
+                    } else {
+                        PyByteArray::type_object(py).call1((value,))
+                    }
+                },
+                TypeValidator::MemoryView {} => PyMemoryView::type_object(py).call1((value,)),
+                // The coerced bytes are exposed as a memoryview by the
+                // validation that follows.
+                TypeValidator::Buffer { .. } => PyBytes::type_object(py).call1((value,)),
+                TypeValidator::Tuple { items, .. } => {
                     let temp = value.cast::<PySequence>()?;
                     if temp.len()? != items.len() {
                         return Err(
-                            pyo3::exceptions::PyTypeError::new_err(
+                            new_coercion_error(py,
                                 format!(
                                     "Cannot coerce a {}-tuple into a {}-tuple",
                                     temp.len()?,
@@ -94,7 +275,7 @@ impl Coercer {
                         .build()
                         .map(|ob| ob.into_any())
                 },
-                TypeValidator::VarTuple { item } => {
+                TypeValidator::VarTuple { item, .. } => {
                     let temp = value.cast::<PySequence>()?;
                     let size = temp.len()?;
                     if size == 0 {
@@ -159,7 +340,17 @@ impl Coercer {
                         .collect::<PyResult<Vec<_>>>()?
                     ).map(|ob| ob.as_any().clone())
                 },
-                TypeValidator::List { item } => {
+                // str and bytes are sequences but are not valid for Sequence.
+                TypeValidator::Sequence { .. }
+                    if value.is_instance_of::<PyString>() || value.is_instance_of::<PyBytes>() =>
+                {
+                    Err(new_coercion_error(py, format!(
+                        "Cannot coerce {} to a sequence",
+                        value.get_type().name()?
+                    )))
+                },
+                // Sequences are coerced to lists, whose items are coerced.
+                TypeValidator::List { item } | TypeValidator::Sequence { item } => {
                     let temp = value.cast::<PySequence>()?;
                     // FIXME create the right container upfront so that we can use
                     // a fast validation path
@@ -179,50 +370,64 @@ impl Coercer {
                         .collect::<PyResult<Vec<_>>>()?
                     ).map(|ob| ob.as_any().clone())
                 },
-                TypeValidator::Dict { items } => {
-                    let coerced = PyDict::new(py);
-                    if let Ok(t) = value.cast::<PyDict>() {
-                        for (k, v) in t.iter(){
-                            if let Some((key_validator, val_validator)) = items {
-                                let ck = self.coerce_value(is_init_coercion, &key_validator.type_validator, name, object, &k);
-                                let cv = self.coerce_value(is_init_coercion, &val_validator.type_validator, name, object, &v);
-                                coerced.set_item(ck?, cv?)?;
-                            } else {
-                                coerced.set_item(k, v)?;
-                            }
-                        }
-                    } else if let Ok(tm) = value.cast::<PyMapping>() {
-                        for i in tm.items()?.iter(){
-                            let (k, v) = i.extract()?;
-                            if let Some((key_validator, val_validator)) = items {
-                                let ck = self.coerce_value(is_init_coercion, &key_validator.type_validator, name, object, &k);
-                                let cv = self.coerce_value(is_init_coercion, &val_validator.type_validator, name, object, &v);
-                                coerced.set_item(ck?, cv?)?;
-                            } else {
-                                coerced.set_item(k, v)?;
-                            }
-                        }
-                    } else {
-                        for p in value.try_iter()? {
-                            let (k, v) = p?.extract()?;
-                            if let Some((key_validator, val_validator)) = items {
-
-                                let ck = self.coerce_value(is_init_coercion, &key_validator.type_validator, name, object, &k);
-                                let cv = self.coerce_value(is_init_coercion, &val_validator.type_validator, name, object, &v);
-                                coerced.set_item(ck?, cv?)?;
-                            } else {
-                                coerced.set_item(k, v)?;
-                            }
-                        }
-                    };
-
+                TypeValidator::Dict { items } | TypeValidator::Mapping { items } => {
                     // FIXME create the right container upfront so that we can use
                     // a fast validation path
-                    Ok(coerced.as_any().clone())
+                    self.coerce_dict(is_init_coercion, items.as_ref(), name, object, value)
+                        .map(|d| d.into_any())
+                },
+                TypeValidator::TypedDict { fields, .. } => {
+                    let coerced = PyDict::new(py);
+                    for i in value.cast::<PyMapping>()?.items()?.iter() {
+                        let (k, v): (Bound<'py, PyAny>, Bound<'py, PyAny>) = i.extract()?;
+                        let field = k
+                            .extract::<&str>()
+                            .ok()
+                            .and_then(|k| fields.iter().find(|(key, ..)| key == k));
+                        if let Some((_, field_validator, _)) = field {
+                            let cv = self.coerce_value(is_init_coercion, &field_validator.type_validator, name, object, &v)?;
+                            coerced.set_item(k, cv)?;
+                        } else {
+                            coerced.set_item(k, v)?;
+                        }
+                    }
+                    // Check the presence of required keys and the absence of
+                    // unknown ones.
+                    type_validator.validate_type(name, object, coerced.as_any())
                 },
                 TypeValidator::Typed { type_ } => type_.bind(py).call1((value,)),
+                // Flags using the EJECT boundary return a plain int for
+                // undefined bits which is not a valid value.
+                TypeValidator::FlagEnum { enum_type } => {
+                    let enum_type = enum_type.bind(py);
+                    let flag = enum_type.call1((value,))?;
+                    if flag.is_instance(enum_type)? {
+                        Ok(flag)
+                    } else {
+                        Err(new_coercion_error(py, format!(
+                            "Cannot coerce {} to {}",
+                            value.repr()?,
+                            enum_type.name()?
+                        )))
+                    }
+                },
+                TypeValidator::Enum { enum_type } => coerce_enum(enum_type.bind(py), value),
+                TypeValidator::Callable { .. } => Err(
+                    new_coercion_error(py, "Cannot coerce a value to a callable")
+                ),
+                TypeValidator::Guard { .. } => Err(
+                    new_coercion_error(py, "Cannot infer a coercion for a type guard")
+                ),
+                TypeValidator::Iterable { .. } => Err(
+                    new_coercion_error(py, "Cannot coerce a value to an iterable")
+                ),
+                // Iterables are coerced to iterators, which are then wrapped to
+                // validate their items.
+                TypeValidator::Iterator { .. } => {
+                    type_validator.validate_type(name, object, value.try_iter()?.as_any())
+                },
                 TypeValidator::Subclass { type_: _ } => Err(
-                    pyo3::exceptions::PyTypeError::new_err(
+                    new_coercion_error(py,
                         "Cannot coerce a value to a subclass validator - expected a type object"
                     )
                 ),
@@ -245,7 +450,7 @@ impl Coercer {
                     Err(
                         err_with_cause(
                             value.py(),
-                            pyo3::exceptions::PyTypeError::new_err(format!(
+                            new_coercion_error(py, format!(
                                 "Could not coerce value {} to any member in union {:?}",
                                 value.repr()?,
                                 members
@@ -254,6 +459,9 @@ impl Coercer {
                         )
                     )
                 },
+                TypeValidator::Optional { inner } => {
+                    inner.coerce_value(is_init_coercion, name, object, value)
+                },
                 TypeValidator::GenericAttributes { type_, .. } => {
                     type_.bind(py).call1((value,))
                 }
@@ -291,6 +499,233 @@ impl Coercer {
                         is_init_coercion
                     ),
                 ),
+            Self::ParseInt { base } => parse_int(value, base.0),
+            Self::ParseFloat { thousands_separator, scientific } => {
+                parse_float(value, thousands_separator.as_deref(), *scientific)
+            }
+            Self::NullLike { values, coercer } => {
+                let is_null_like = match values {
+                    Some(values) => values.bind(py).contains(value)?,
+                    None => value
+                        .cast::<PyString>()
+                        .is_ok_and(|s| s.to_str().is_ok_and(str::is_empty)),
+                };
+                if is_null_like {
+                    // Ensure the validator accepts None since coerced values
+                    // are not validated again.
+                    return type_validator.validate_type(name, object, &py.None().into_bound(py));
+                }
+                match coercer {
+                    Some(c) => c.get().coerce_value(
+                        is_init_coercion,
+                        non_none_validator(py, type_validator),
+                        name,
+                        object,
+                        value,
+                    ),
+                    None => Err(new_coercion_error(
+                        py,
+                        format!("{} is not a null-like value", value.repr()?),
+                    )),
+                }
+            }
+            Self::Chain { coercers } => {
+                let mut current = value.clone();
+                for (index, coercer) in coercers.iter().enumerate() {
+                    current = match coercer.get().coerce_value(
+                        is_init_coercion,
+                        type_validator,
+                        name,
+                        object,
+                        &current,
+                    ) {
+                        Ok(coerced) => coerced,
+                        Err(err) => {
+                            return Err(err_with_cause(
+                                py,
+                                new_coercion_error(
+                                    py,
+                                    format!(
+                                        "Stage {index} ({}) of the coercion chain failed for {}",
+                                        coercer.get().kind(),
+                                        current.repr()?
+                                    ),
+                                ),
+                                err,
+                            ));
+                        }
+                    };
+                }
+                Ok(current)
+            }
+        }
+    }
+
+    /// Coerce a dict, a mapping or an iterable of key/value pairs to a dict,
+    /// coercing keys and values when item validators are specified.
+    fn coerce_dict<'py>(
+        &self,
+        is_init_coercion: bool,
+        items: Option<&(BoxedValidator, BoxedValidator)>,
+        name: Option<&str>,
+        object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
+        value: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let py = value.py();
+        let coerced = PyDict::new(py);
+        let insert = |k: Bound<'py, PyAny>, v: Bound<'py, PyAny>| -> PyResult<()> {
+            match items {
+                Some((key_validator, val_validator)) => coerced.set_item(
+                    self.coerce_value(
+                        is_init_coercion,
+                        &key_validator.type_validator,
+                        name,
+                        object,
+                        &k,
+                    )?,
+                    self.coerce_value(
+                        is_init_coercion,
+                        &val_validator.type_validator,
+                        name,
+                        object,
+                        &v,
+                    )?,
+                ),
+                None => coerced.set_item(k, v),
+            }
+        };
+
+        if let Ok(d) = value.cast::<PyDict>() {
+            for (k, v) in d.iter() {
+                insert(k, v)?;
+            }
+            return Ok(coerced);
+        }
+
+        let pairs = if let Ok(m) = value.cast::<PyMapping>() {
+            m.items()?.into_any()
+        } else {
+            value.clone()
+        };
+        for (index, pair) in pairs.try_iter()?.enumerate() {
+            let pair = pair?;
+            match pair.extract::<(Bound<'py, PyAny>, Bound<'py, PyAny>)>() {
+                Ok((k, v)) => insert(k, v)?,
+                Err(err) => {
+                    return Err(err_with_cause(
+                        py,
+                        new_coercion_error(
+                            py,
+                            format!(
+                                "Cannot coerce {} to a dict: element {index} ({}) is not a key/value pair",
+                                value.repr()?,
+                                pair.repr()?
+                            ),
+                        ),
+                        err,
+                    ));
+                }
+            }
+        }
+        Ok(coerced)
+    }
+}
+
+impl PartialEq for Coercer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::TypeInferred {
+                    bytes_encoding: ba,
+                    none_to_str: na,
+                },
+                Self::TypeInferred {
+                    bytes_encoding: bb,
+                    none_to_str: nb,
+                },
+            ) => ba == bb && na == nb,
+            (Self::CallValue { callable: a }, Self::CallValue { callable: b }) => a == b,
+            (
+                Self::CallNameObjectValueInit { callable: a },
+                Self::CallNameObjectValueInit { callable: b },
+            ) => a == b,
+            (Self::ObjectMethod { meth_name: a }, Self::ObjectMethod { meth_name: b }) => {
+                py_eq(a, b)
+            }
+            (Self::ParseInt { base: a }, Self::ParseInt { base: b }) => a == b,
+            (
+                Self::ParseFloat {
+                    thousands_separator: ta,
+                    scientific: sa,
+                },
+                Self::ParseFloat {
+                    thousands_separator: tb,
+                    scientific: sb,
+                },
+            ) => ta == tb && sa == sb,
+            (
+                Self::NullLike {
+                    values: va,
+                    coercer: ca,
+                },
+                Self::NullLike {
+                    values: vb,
+                    coercer: cb,
+                },
+            ) => {
+                (match (va, vb) {
+                    (Some(a), Some(b)) => py_eq(a, b),
+                    (None, None) => true,
+                    _ => false,
+                }) && (match (ca, cb) {
+                    (Some(a), Some(b)) => a.get() == b.get(),
+                    (None, None) => true,
+                    _ => false,
+                })
+            }
+            (Self::Chain { coercers: a }, Self::Chain { coercers: b }) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.get() == b.get())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Hash for Coercer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TypeInferred {
+                bytes_encoding,
+                none_to_str,
+            } => {
+                bytes_encoding.hash(state);
+                none_to_str.hash(state);
+            }
+            Self::CallValue { callable } => callable.hash(state),
+            Self::CallNameObjectValueInit { callable } => callable.hash(state),
+            Self::ObjectMethod { meth_name } => py_hash(meth_name, state),
+            Self::ParseInt { base } => base.hash(state),
+            Self::ParseFloat {
+                thousands_separator,
+                scientific,
+            } => {
+                thousands_separator.hash(state);
+                scientific.hash(state);
+            }
+            Self::NullLike { values, coercer } => {
+                if let Some(values) = values {
+                    py_hash(values, state);
+                }
+                if let Some(coercer) = coercer {
+                    coercer.get().hash(state);
+                }
+            }
+            Self::Chain { coercers } => {
+                for coercer in coercers {
+                    coercer.get().hash(state);
+                }
+            }
         }
     }
 }
@@ -298,7 +733,13 @@ impl Coercer {
 impl Clone for Coercer {
     fn clone(&self) -> Self {
         Python::attach(|py| match self {
-            Self::TypeInferred {} => Self::TypeInferred {},
+            Self::TypeInferred {
+                bytes_encoding,
+                none_to_str,
+            } => Self::TypeInferred {
+                bytes_encoding: bytes_encoding.clone(),
+                none_to_str: *none_to_str,
+            },
             Self::CallValue { callable } => Self::CallValue {
                 callable: co_callv::Callable(callable.0.clone_ref(py)),
             },
@@ -308,6 +749,161 @@ impl Clone for Coercer {
             Self::ObjectMethod { meth_name } => Self::ObjectMethod {
                 meth_name: meth_name.clone_ref(py),
             },
+            Self::ParseInt { base } => Self::ParseInt { base: *base },
+            Self::ParseFloat {
+                thousands_separator,
+                scientific,
+            } => Self::ParseFloat {
+                thousands_separator: thousands_separator.clone(),
+                scientific: *scientific,
+            },
+            Self::NullLike { values, coercer } => Self::NullLike {
+                values: values.as_ref().map(|v| v.clone_ref(py)),
+                coercer: coercer.as_ref().map(|c| c.clone_ref(py)),
+            },
+            Self::Chain { coercers } => Self::Chain {
+                coercers: coercers.iter().map(|c| c.clone_ref(py)).collect(),
+            },
         })
     }
 }
+
+/// Encode a str or decode a bytes object, reporting the codec on failure.
+fn apply_codec<'py>(
+    value: &Bound<'py, PyAny>,
+    method: &str,
+    encoding: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    value.call_method1(method, (encoding,)).map_err(|err| {
+        let repr = value
+            .repr()
+            .map(|r| r.to_string())
+            .unwrap_or_else(|_| "<unrepresentable>".to_string());
+        err_with_cause(
+            py,
+            new_coercion_error(
+                py,
+                format!("Cannot {method} {repr} using the '{encoding}' codec"),
+            ),
+            err,
+        )
+    })
+}
+
+/// Look up the member of an enum having the given value, listing the valid
+/// members on failure.
+fn coerce_enum<'py>(
+    enum_type: &Bound<'py, PyType>,
+    value: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    enum_type.call1((value,)).map_err(|err| {
+        let describe = || -> PyResult<String> {
+            let members = enum_type
+                .try_iter()?
+                .map(|m| {
+                    let m = m?;
+                    Ok(format!(
+                        "{} ({})",
+                        m.getattr(intern!(py, "name"))?,
+                        m.getattr(intern!(py, "value"))?.repr()?
+                    ))
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(format!(
+                "Cannot coerce {} to {}, valid members are: {}",
+                value.repr()?,
+                enum_type.name()?,
+                members.join(", ")
+            ))
+        };
+        match describe() {
+            Ok(msg) => err_with_cause(py, new_coercion_error(py, msg), err),
+            Err(e) => e,
+        }
+    })
+}
+
+/// Strip None from an Optional type validator (a union of None and a single
+/// other type).
+fn non_none_validator<'a>(py: Python<'_>, type_validator: &'a TypeValidator) -> &'a TypeValidator {
+    if let TypeValidator::Optional { inner } = type_validator {
+        return &inner.type_validator;
+    }
+    if let TypeValidator::Union { members } = type_validator {
+        let none_type = py.None().into_bound(py).get_type();
+        let mut others = members.iter().filter(|m| match &m.type_validator {
+            TypeValidator::None {} => false,
+            TypeValidator::Typed { type_ } => !type_.bind(py).is(&none_type),
+            _ => true,
+        });
+        if let (Some(other), None) = (others.next(), others.next()) {
+            return &other.type_validator;
+        }
+    }
+    type_validator
+}
+
+/// Parse a str as an int in the specified base.
+fn parse_int<'py>(value: &Bound<'py, PyAny>, base: u32) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    let Ok(s) = value.cast::<PyString>() else {
+        return Err(new_coercion_error(
+            py,
+            format!("ParseInt expects a str, got {}", value.repr()?),
+        ));
+    };
+    let repr = value.repr()?;
+    PyInt::type_object(py).call1((s, base)).map_err(|err| {
+        err_with_cause(
+            py,
+            new_coercion_error(py, format!("Cannot parse {repr} as an int in base {base}")),
+            err,
+        )
+    })
+}
+
+/// Parse a str as a float, removing the thousands separator if any.
+fn parse_float<'py>(
+    value: &Bound<'py, PyAny>,
+    thousands_separator: Option<&str>,
+    scientific: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    let Ok(s) = value.cast::<PyString>() else {
+        return Err(new_coercion_error(
+            py,
+            format!("ParseFloat expects a str, got {}", value.repr()?),
+        ));
+    };
+    let mut text = s.to_str()?.trim().to_string();
+    if let Some(sep) = thousands_separator
+        && !sep.is_empty()
+    {
+        text = text.replace(sep, "");
+    }
+    if !scientific && text.contains(['e', 'E']) && !is_special_float(&text) {
+        return Err(new_coercion_error(
+            py,
+            format!(
+                "Cannot parse {} as a float: scientific notation is not allowed",
+                value.repr()?
+            ),
+        ));
+    }
+    let repr = value.repr()?;
+    PyFloat::type_object(py).call1((text,)).map_err(|err| {
+        err_with_cause(
+            py,
+            new_coercion_error(py, format!("Cannot parse {repr} as a float")),
+            err,
+        )
+    })
+}
+
+/// Whether a str represents an infinite float (whose name contains an e).
+fn is_special_float(text: &str) -> bool {
+    let text = text.trim_start_matches(['+', '-']).to_ascii_lowercase();
+    text == "inf" || text == "infinity"
+}
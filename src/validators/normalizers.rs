@@ -0,0 +1,126 @@
+/*-----------------------------------------------------------------------------
+| Copyright (c) 2025-2026, Ators contributors, see git history for details
+|
+| Distributed under the terms of the Modified BSD License.
+|
+| The full license is in the file LICENSE, distributed with this software.
+|----------------------------------------------------------------------------*/
+/// Normalization of values after type validation.
+use pyo3::{
+    Bound, Py, PyAny, PyResult, Python, pyclass,
+    types::{PyAnyMethods, PyString},
+};
+
+use crate::utils::{create_behavior_callable_checker, py_eq, py_hash};
+use std::hash::{Hash, Hasher};
+
+create_behavior_callable_checker!(n_callv, Normalizer, CallValue, 1);
+create_behavior_callable_checker!(n_callmov, Normalizer, CallNameObjectValue, 3);
+
+/// Transformation applied to a value once its type has been validated.
+///
+/// Contrary to value validators which only check a value, normalizers return
+/// the value to use in place of the validated one (e.g. to clamp, round or
+/// canonicalize it). The returned value is validated against the type
+/// validator again.
+#[pyclass(module = "ators._ators", frozen, eq, hash, from_py_object)]
+#[derive(Debug)]
+pub enum Normalizer {
+    #[pyo3(constructor = (callable))]
+    CallValue { callable: n_callv::Callable },
+    #[pyo3(constructor = (callable))]
+    CallNameObjectValue { callable: n_callmov::Callable },
+    #[pyo3(constructor = (meth_name))]
+    ObjectMethod { meth_name: Py<PyString> },
+}
+
+impl Normalizer {
+    /// Whether the normalizer needs the object owning the member.
+    pub(crate) fn needs_object(&self) -> bool {
+        matches!(
+            self,
+            Self::CallNameObjectValue { .. } | Self::ObjectMethod { .. }
+        )
+    }
+
+    /// Return the normalized value.
+    ///
+    /// The normalization runs after type validation and before value
+    /// validation and may use member/object context depending on the selected
+    /// variant.
+    pub fn normalize<'py>(
+        &self,
+        name: Option<&str>,
+        object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
+        value: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Self::CallValue { callable } => callable.0.bind(value.py()).call1((value,)),
+            Self::CallNameObjectValue { callable } => callable.0.bind(value.py()).call1((
+                name.ok_or(pyo3::exceptions::PyRuntimeError::new_err(
+                    "Cannot use CallNameObjectValue normalization when validator is not linked to a member.",
+                ))?,
+                object.ok_or(pyo3::exceptions::PyTypeError::new_err(
+                    "Cannot use CallNameObjectValue normalization when validator is not linked to a member.",
+                ))?,
+                value,
+            )),
+            Self::ObjectMethod { meth_name } => object
+                .ok_or(pyo3::exceptions::PyTypeError::new_err(
+                    "Cannot use ObjectMethod normalization when validator is not linked to a member.",
+                ))?
+                .call_method1(
+                    meth_name,
+                    (
+                        name.ok_or(pyo3::exceptions::PyRuntimeError::new_err(
+                            "Cannot use ObjectMethod normalization when validator is not linked to a member.",
+                        ))?,
+                        value,
+                    ),
+                ),
+        }
+    }
+}
+
+impl PartialEq for Normalizer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::CallValue { callable: a }, Self::CallValue { callable: b }) => a == b,
+            (
+                Self::CallNameObjectValue { callable: a },
+                Self::CallNameObjectValue { callable: b },
+            ) => a == b,
+            (Self::ObjectMethod { meth_name: a }, Self::ObjectMethod { meth_name: b }) => {
+                py_eq(a, b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Hash for Normalizer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::CallValue { callable } => callable.hash(state),
+            Self::CallNameObjectValue { callable } => callable.hash(state),
+            Self::ObjectMethod { meth_name } => py_hash(meth_name, state),
+        }
+    }
+}
+
+impl Clone for Normalizer {
+    fn clone(&self) -> Self {
+        Python::attach(|py| match self {
+            Self::CallValue { callable } => Self::CallValue {
+                callable: n_callv::Callable(callable.0.clone_ref(py)),
+            },
+            Self::CallNameObjectValue { callable } => Self::CallNameObjectValue {
+                callable: n_callmov::Callable(callable.0.clone_ref(py)),
+            },
+            Self::ObjectMethod { meth_name } => Self::ObjectMethod {
+                meth_name: meth_name.clone_ref(py),
+            },
+        })
+    }
+}
@@ -8,36 +8,47 @@
 /// Structures used to manage type validation.
 use super::Validator;
 use crate::annotations::{build_validator_from_annotation, get_type_tools};
+use crate::errors::new_validation_error;
 use crate::get_type_mutability_map;
-use crate::utils::{Mutability, TupleBuilder, err_with_cause};
+use crate::utils::{
+    Mutability, TupleBuilder, create_behavior_callable_checker, err_with_cause, get_decimal_type,
+    py_eq, py_hash,
+};
 use pyo3::Borrowed;
 use pyo3::sync::critical_section::with_critical_section;
 use pyo3::types::PyStringMethods;
 use pyo3::{
     Bound, FromPyObject, IntoPyObject, Py, PyAny, PyErr, PyResult, Python,
+    buffer::PyUntypedBuffer,
     ffi::{
-        PyBool_Check, PyBytes_Check, PyComplex_Check, PyFloat_Check, PyLong_Check, PyUnicode_Check,
+        PyBool_Check, PyByteArray_Check, PyBytes_Check, PyComplex_Check, PyFloat_Check,
+        PyLong_Check, PyMemoryView_Check, PyUnicode_Check,
     },
-    pyclass, pymethods,
+    intern, pyclass, pymethods,
     sync::OnceLockExt,
     types::{
-        PyAnyMethods, PyDict, PyDictMethods, PyFrozenSetMethods, PyList, PyListMethods, PySet,
-        PySetMethods, PyString, PyTuple, PyTupleMethods, PyType, PyTypeMethods,
+        PyAnyMethods, PyDict, PyDictMethods, PyFrozenSetMethods, PyIterator, PyList, PyListMethods,
+        PyMapping, PyMappingMethods, PyMemoryView, PySequence, PySet, PySetMethods, PyString,
+        PyTuple, PyTupleMethods, PyType, PyTypeMethods,
     },
 };
 use std::{
     convert::Infallible,
+    ffi::CString,
+    hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
     sync::OnceLock,
 };
 
+create_behavior_callable_checker!(tv_guard, TypeValidator, Guard, 1);
+
 /// A newtype wrapper around `Box<Validator>` that implements PyO3 conversion traits.
 /// This allows using heap-allocated validators in TypeValidator variants without
 /// requiring GIL-bound storage (Py<Validator>).
 /// The risk of creating reference cycles exist but is low and since validators
 ///  exists only on types that are expected to be long-lived, it is unlikely to
 /// create any real world issues.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Hash)]
 pub struct BoxedValidator(pub Box<Validator>);
 
 impl Deref for BoxedValidator {
@@ -92,6 +103,18 @@ impl<'py> IntoPyObject<'py> for &BoxedValidator {
 /// Struct storing a tuple of types for the TypeValidator::Instance variant
 pub(crate) struct TypesTuple(Py<PyTuple>);
 
+impl PartialEq for TypesTuple {
+    fn eq(&self, other: &Self) -> bool {
+        py_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for TypesTuple {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        py_hash(&self.0, state);
+    }
+}
+
 impl TypesTuple {
     /// Coerce the value to the first type in the tuple
     pub fn coerce<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
@@ -109,24 +132,63 @@ impl TypesTuple {
     }
 }
 
+/// Resolve an entry of a TypeValidator.Instance to a type.
+///
+/// Parametrized generics (e.g. `list[int]`) are accepted and replaced by their
+/// origin, emitting a warning since the parameters cannot be checked by an
+/// instance check.
+fn resolve_instance_type<'py>(ob: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyType>>> {
+    if let Ok(ty) = ob.cast::<PyType>() {
+        return Ok(Some(ty.clone()));
+    }
+    let py = ob.py();
+    let origin = py
+        .import(intern!(py, "typing"))?
+        .getattr(intern!(py, "get_origin"))?
+        .call1((ob,))?;
+    let Ok(origin) = origin.cast_into::<PyType>() else {
+        return Ok(None);
+    };
+    PyErr::warn(
+        py,
+        &py.get_type::<pyo3::exceptions::PyUserWarning>(),
+        CString::new(format!(
+            "The parameters of {} are ignored by TypeValidator.Instance, \
+             only its origin {} is used.",
+            ob.repr()?,
+            origin.name()?
+        ))?
+        .as_c_str(),
+        1,
+    )?;
+    Ok(Some(origin))
+}
+
 impl FromPyObject<'_, '_> for TypesTuple {
     type Error = PyErr;
 
     fn extract(ob: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
         let py = ob.py();
-        if let Ok(ty) = ob.cast::<PyType>() {
-            Ok(TypesTuple(PyTuple::new(py, [ty])?.into()))
-        } else if let Ok(s) = ob.cast::<PyTuple>()
-            && s.len() > 0
-            && s.iter().all(|item| item.is_instance_of::<PyType>())
-        {
-            Ok(TypesTuple(s.to_owned().unbind()))
-        } else {
-            Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                "Expected a 'type' or 'tuple[type, ...]' for a TypeValidator.Instance, got {}",
-                ob.get_type().name()?
-            )))
+        if let Ok(s) = ob.cast::<PyTuple>() {
+            if s.len() > 0 {
+                let mut types = Vec::with_capacity(s.len());
+                for item in s.iter() {
+                    match resolve_instance_type(&item)? {
+                        Some(ty) => types.push(ty),
+                        None => break,
+                    }
+                }
+                if types.len() == s.len() {
+                    return Ok(TypesTuple(PyTuple::new(py, types)?.unbind()));
+                }
+            }
+        } else if let Some(ty) = resolve_instance_type(&ob)? {
+            return Ok(TypesTuple(PyTuple::new(py, [ty])?.into()));
         }
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Expected a 'type' or 'tuple[type, ...]' for a TypeValidator.Instance, got {}",
+            ob.get_type().name()?
+        )))
     }
 }
 
@@ -271,6 +333,33 @@ impl LateResolvedValidator {
     }
 }
 
+/// Compare optional Python objects using Python equality.
+fn opt_py_eq<T>(a: &Option<Py<T>>, b: &Option<Py<T>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => py_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+// Two late validators are equal if they resolve the same forward reference
+// in the same context, whether or not they have already been resolved.
+impl PartialEq for LateResolvedValidator {
+    fn eq(&self, other: &Self) -> bool {
+        py_eq(&self.forward_ref, &other.forward_ref)
+            && self.type_containers == other.type_containers
+            && opt_py_eq(&self.typevar_bindings, &other.typevar_bindings)
+            && opt_py_eq(&self.owner, &other.owner)
+    }
+}
+
+impl Hash for LateResolvedValidator {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        py_hash(&self.forward_ref, state);
+        self.type_containers.hash(state);
+    }
+}
+
 impl Clone for LateResolvedValidator {
     fn clone(&self) -> Self {
         Python::attach(|py| Self {
@@ -286,13 +375,16 @@ impl Clone for LateResolvedValidator {
 }
 
 /// Type validation struct managing type validation
-#[pyclass(module = "ators._ators", frozen, from_py_object)]
+#[pyclass(module = "ators._ators", frozen, eq, hash, from_py_object)]
 #[derive(Debug)]
 pub enum TypeValidator {
     #[pyo3(constructor = ())]
     Any {},
     #[pyo3(constructor = ())]
     None {},
+    /// Reject every value, used for Never and NoReturn annotations.
+    #[pyo3(constructor = ())]
+    Never {},
     #[pyo3(constructor = ())]
     Bool {},
     #[pyo3(constructor = ())]
@@ -301,26 +393,92 @@ pub enum TypeValidator {
     Float {},
     #[pyo3(constructor = ())]
     Complex {},
+    /// Instances of decimal.Decimal.
+    #[pyo3(constructor = ())]
+    Decimal {},
     #[pyo3(constructor = ())]
     Str {},
     #[pyo3(constructor = ())]
     Bytes {},
-    #[pyo3(constructor = (items))]
-    Tuple { items: Vec<Validator> },
-    #[pyo3(constructor = (item))]
-    VarTuple { item: Option<BoxedValidator> },
+    /// Instances of bytearray, the mutable counterpart of bytes.
+    #[pyo3(constructor = ())]
+    ByteArray {},
+    /// Instances of memoryview. Contrary to Buffer, other objects supporting
+    /// the buffer protocol are not accepted.
+    #[pyo3(constructor = ())]
+    MemoryView {},
+    /// Any object supporting the buffer protocol, exposed as a memoryview
+    /// which shares the memory of the object rather than copying it. The
+    /// format (in struct module syntax) and the size in bytes of the buffer
+    /// can optionally be checked. The stored memoryview holds an export of the
+    /// buffer, preventing the object from being resized while it is stored.
+    #[pyo3(constructor = (format=None, min_size=None, max_size=None))]
+    Buffer {
+        format: Option<String>,
+        min_size: Option<usize>,
+        max_size: Option<usize>,
+    },
+    /// Any callable. When check_returns is true and the expected return type
+    /// is known, the return annotation declared by the callable is compared
+    /// to it and a warning is emitted on mismatch. The check is advisory since
+    /// the value returned by the callable cannot be validated on assignment.
+    /// When arity is set, the callable must accept being called with that many
+    /// positional arguments.
+    #[pyo3(constructor = (returns=None, check_returns=false, arity=None))]
+    Callable {
+        returns: Option<Py<PyAny>>,
+        check_returns: bool,
+        arity: Option<usize>,
+    },
+    /// Values for which the predicate, typically a function returning a
+    /// TypeGuard, returns a truthy value. Since the predicate is the type
+    /// validator, the coercer (if any) runs when it returns a falsy value.
+    #[pyo3(constructor = (predicate))]
+    Guard { predicate: tv_guard::Callable },
+    /// Fixed length tuple. Only exact tuples are accepted unless
+    /// allow_subclasses is true, in which case tuple subclasses (such as
+    /// NamedTuple instances) are accepted as well.
+    #[pyo3(constructor = (items, allow_subclasses=false))]
+    Tuple {
+        items: Vec<Validator>,
+        allow_subclasses: bool,
+    },
+    /// Variable length tuple, tuple subclasses being accepted when
+    /// allow_subclasses is true.
+    #[pyo3(constructor = (item, allow_subclasses=false))]
+    VarTuple {
+        item: Option<BoxedValidator>,
+        allow_subclasses: bool,
+    },
     #[pyo3(constructor = (type_))]
     Typed { type_: Py<PyType> },
     #[pyo3(constructor = (type_))]
     Subclass { type_: Py<PyType> },
+    /// Instances of an enum.Flag subclass. Flags using the STRICT boundary
+    /// cannot be instantiated with undefined bits, so no further check is
+    /// needed.
+    #[pyo3(constructor = (enum_type))]
+    FlagEnum { enum_type: Py<PyType> },
+    /// Members of an enum.Enum subclass. When coercing, raw values are
+    /// converted to the member having that value.
+    #[pyo3(constructor = (enum_type))]
+    Enum { enum_type: Py<PyType> },
     #[pyo3(constructor = (types))]
     // TypesTuple is build from a Python object and we do not need to expose
     // it directly since it is not needed to build an Instance variant from the
     // Python side.
     #[allow(private_interfaces)]
     Instance { types: TypesTuple },
+    /// Members are tried in order, which is the order of the annotation
+    /// arguments, and the first successful validation (coercion included)
+    /// determines the result.
     #[pyo3(constructor = (members))]
     Union { members: Vec<Validator> },
+    /// None or a value valid for the inner validator. Built for unions of None
+    /// and a single other type, None being accepted without running the inner
+    /// validator.
+    #[pyo3(constructor = (inner))]
+    Optional { inner: BoxedValidator },
     #[pyo3(constructor = (type_, attributes))]
     GenericAttributes {
         type_: Py<PyType>,
@@ -335,13 +493,41 @@ pub enum TypeValidator {
     Set { item: Option<BoxedValidator> },
     #[pyo3(constructor = (item))]
     List { item: Option<BoxedValidator> },
+    /// Any iterable. When materialize is true the iterable is consumed and
+    /// its validated items are stored in a tuple. Otherwise the iterable is
+    /// kept and, if an item validator is set, wrapped so that its items are
+    /// validated lazily when iterating.
+    #[pyo3(constructor = (item, materialize=false))]
+    Iterable {
+        item: Option<BoxedValidator>,
+        materialize: bool,
+    },
+    /// Iterators only. If an item validator is set, the iterator is wrapped
+    /// so that its items are validated as they are consumed.
+    #[pyo3(constructor = (item))]
+    Iterator { item: Option<BoxedValidator> },
     #[pyo3(constructor = (items))]
     Dict {
         items: Option<(BoxedValidator, BoxedValidator)>,
     },
-    // Sequence,
-    // List,
-    // Mapping,
+    /// Fields are stored as (key, validator, required).
+    #[pyo3(constructor = (type_, fields))]
+    TypedDict {
+        type_: Py<PyType>,
+        fields: Vec<(String, Validator, bool)>,
+    },
+    /// Instances of collections.abc.Sequence other than str and bytes. The
+    /// value is not copied: items are validated in place, without coercion,
+    /// and the original object is kept.
+    #[pyo3(constructor = (item))]
+    Sequence { item: Option<BoxedValidator> },
+    /// Instances of collections.abc.Mapping. As for Sequence, the keys and
+    /// values are validated in place, without coercion, and the original
+    /// object is kept.
+    #[pyo3(constructor = (items))]
+    Mapping {
+        items: Option<(BoxedValidator, BoxedValidator)>,
+    },
     // DefaultDict,
     // OrderedDict,
     // Callable,
@@ -352,39 +538,378 @@ macro_rules! validation_error {
         if let Some(m) = $member
             && let Some(o) = $object
         {
-            Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                "The member {} from {} expects a {}, got {} ({})",
-                m,
-                o.repr()?,
-                $type,
-                $value.repr()?,
-                $value.get_type().name()?
-            )))
+            Err(new_validation_error(
+                $value.py(),
+                format!(
+                    "The member {} from {} expects a {}, got {} ({})",
+                    m,
+                    o.repr()?,
+                    $type,
+                    $value.repr()?,
+                    $value.get_type().name()?
+                ),
+            ))
+        } else {
+            Err(new_validation_error(
+                $value.py(),
+                format!(
+                    "Expected a {}, got {} ({})",
+                    $type,
+                    $value.repr()?,
+                    $value.get_type().name()?
+                ),
+            ))
+        }
+    };
+}
+
+/// Retrieve the signature of a callable, or None if it cannot be inspected.
+fn callable_signature<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = value.py();
+    Ok(py
+        .import(intern!(py, "inspect"))?
+        .getattr(intern!(py, "signature"))?
+        .call1((value,))
+        .ok())
+}
+
+/// Build the message reporting that a callable does not match the expected
+/// one, naming the member and the object if known.
+fn callable_problem_message(
+    name: Option<&str>,
+    object: Option<&Bound<'_, crate::class::base::AtorsBase>>,
+    problem: &str,
+) -> PyResult<String> {
+    Ok(
+        if let Some(m) = name
+            && let Some(o) = object
+        {
+            format!("The member {} from {} expects {}", m, o.repr()?, problem)
+        } else {
+            format!("Expected {problem}")
+        },
+    )
+}
+
+/// Warn if the return annotation declared in the signature of a callable does
+/// not match the expected return type.
+///
+/// This is a best-effort check: callables which do not declare a return
+/// annotation or declare it as a str are accepted silently. A declared type
+/// that is a subclass of the expected one is considered a match.
+fn check_callable_returns<'py>(
+    name: Option<&str>,
+    object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
+    value: &Bound<'py, PyAny>,
+    signature: &Bound<'py, PyAny>,
+    expected: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    let py = value.py();
+    let declared = signature.getattr(intern!(py, "return_annotation"))?;
+    if declared.is(py
+        .import(intern!(py, "inspect"))?
+        .getattr(intern!(py, "Signature"))?
+        .getattr(intern!(py, "empty"))?)
+        || declared.is_instance_of::<PyString>()
+    {
+        return Ok(());
+    }
+    // A None annotation stands for NoneType.
+    let none_type = py.None().into_bound(py).get_type().into_any();
+    let normalize = |ann: &Bound<'py, PyAny>| {
+        if ann.is_none() {
+            none_type.clone()
         } else {
-            Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                "Expected a {}, got {} ({})",
-                $type,
-                $value.repr()?,
-                $value.get_type().name()?
-            )))
+            ann.clone()
+        }
+    };
+    let (declared, expected) = (normalize(&declared), normalize(expected));
+    let is_subclass = match (declared.cast::<PyType>(), expected.cast::<PyType>()) {
+        (Ok(d), Ok(e)) => d.is_subclass(e)?,
+        _ => false,
+    };
+    if is_subclass
+        || declared.eq(&expected)?
+        || expected.is(py
+            .import(intern!(py, "typing"))?
+            .getattr(intern!(py, "Any"))?)
+    {
+        return Ok(());
+    }
+    let problem = format!(
+        "a callable returning {}, got {} whose declared return type is {}",
+        expected.repr()?,
+        value.repr()?,
+        declared.repr()?
+    );
+    PyErr::warn(
+        py,
+        &py.get_type::<pyo3::exceptions::PyUserWarning>(),
+        CString::new(callable_problem_message(name, object, &problem)?)?.as_c_str(),
+        0,
+    )
+}
+
+/// Check that the signature of a callable accepts the given number of
+/// positional arguments.
+fn check_callable_arity<'py>(
+    name: Option<&str>,
+    object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
+    value: &Bound<'py, PyAny>,
+    signature: &Bound<'py, PyAny>,
+    arity: usize,
+) -> PyResult<()> {
+    let py = value.py();
+    let parameter = py
+        .import(intern!(py, "inspect"))?
+        .getattr(intern!(py, "Parameter"))?;
+    let empty = parameter.getattr(intern!(py, "empty"))?;
+    let pos_only = parameter.getattr(intern!(py, "POSITIONAL_ONLY"))?;
+    let pos_or_kw = parameter.getattr(intern!(py, "POSITIONAL_OR_KEYWORD"))?;
+    let var_pos = parameter.getattr(intern!(py, "VAR_POSITIONAL"))?;
+    let kw_only = parameter.getattr(intern!(py, "KEYWORD_ONLY"))?;
+    let (mut required, mut positional, mut var_positional) = (0, 0, false);
+    let mut required_keyword = false;
+    for param in signature
+        .getattr(intern!(py, "parameters"))?
+        .call_method0(intern!(py, "values"))?
+        .try_iter()?
+    {
+        let param = param?;
+        let has_default = !param.getattr(intern!(py, "default"))?.is(&empty);
+        let kind = param.getattr(intern!(py, "kind"))?;
+        if kind.is(&pos_only) || kind.is(&pos_or_kw) {
+            positional += 1;
+            if !has_default {
+                required += 1;
+            }
+        } else if kind.is(&var_pos) {
+            var_positional = true;
+        } else if kind.is(&kw_only) {
+            required_keyword |= !has_default;
         }
+    }
+    if required <= arity && (arity <= positional || var_positional) && !required_keyword {
+        return Ok(());
+    }
+    let problem = format!(
+        "a callable accepting {} positional argument(s), got {} whose signature is {}",
+        arity,
+        value.repr()?,
+        signature.str()?
+    );
+    Err(new_validation_error(
+        py,
+        callable_problem_message(name, object, &problem)?,
+    ))
+}
+
+/// Cast a value to a tuple, accepting tuple subclasses only if requested.
+fn cast_tuple<'a, 'py>(
+    value: &'a Bound<'py, PyAny>,
+    allow_subclasses: bool,
+) -> Option<&'a Bound<'py, PyTuple>> {
+    if allow_subclasses {
+        value.cast::<PyTuple>().ok()
+    } else {
+        value.cast_exact::<PyTuple>().ok()
+    }
+}
+
+/// Normalize the byte order prefix of a struct format.
+///
+/// A single item format whose meaning does not depend on the prefix, i.e. a
+/// one byte item or an item of native byte order and a standard size equal to
+/// its native size, is returned without prefix, so that "B", "=B" and "<B" (or
+/// "d" and "<d" on a little endian platform) are considered equal.
+fn normalize_buffer_format(format: &str) -> &str {
+    let Some(code) = format.strip_prefix(['@', '=', '<', '>', '!']) else {
+        return format;
+    };
+    let native_order =
+        format.starts_with(['@', '=']) || format.starts_with('<') == cfg!(target_endian = "little");
+    let prefix_free = match code {
+        "b" | "B" | "c" | "?" | "x" => true,
+        "h" | "H" | "i" | "I" | "q" | "Q" | "e" | "f" | "d" => native_order,
+        _ => format.starts_with('@'),
+    };
+    if prefix_free { code } else { format }
+}
+
+/// Expose a buffer protocol supporting object as a memoryview, checking its
+/// format and size in bytes.
+///
+/// Exporting the buffer locks the exporter against resizing (e.g. appending
+/// to a bytearray raises BufferError) for as long as the export lives: the
+/// export used to inspect the buffer is released before returning, but the
+/// returned memoryview keeps its own export alive until it is released or
+/// collected, i.e. as long as the member stores it.
+fn validate_buffer<'py>(
+    name: Option<&str>,
+    object: Option<&Bound<'py, crate::class::base::AtorsBase>>,
+    value: &Bound<'py, PyAny>,
+    format: Option<&str>,
+    min_size: Option<usize>,
+    max_size: Option<usize>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let view = match value.cast::<PyMemoryView>() {
+        Ok(view) => view.clone(),
+        Err(_) => match PyMemoryView::from(value) {
+            Ok(view) => view,
+            Err(_) => return validation_error!("buffer", name, object, value),
+        },
     };
+    let (actual_format, size) = {
+        let buffer = PyUntypedBuffer::get(view.as_any())?;
+        (
+            buffer.format().to_string_lossy().into_owned(),
+            buffer.len_bytes(),
+        )
+    };
+    let problem = if format
+        .is_some_and(|f| normalize_buffer_format(f) != normalize_buffer_format(&actual_format))
+    {
+        Some(format!(
+            "a buffer of format '{}', got a buffer of format '{}'",
+            format.unwrap_or_default(),
+            actual_format
+        ))
+    } else if min_size.is_some_and(|m| size < m) || max_size.is_some_and(|m| size > m) {
+        Some(format!(
+            "a buffer of size between {} and {} bytes, got a buffer of {} bytes",
+            min_size.unwrap_or(0),
+            max_size.map_or("unlimited".to_owned(), |m| m.to_string()),
+            size
+        ))
+    } else {
+        None
+    };
+    match problem {
+        None => Ok(view.into_any()),
+        Some(problem) => Err(new_validation_error(
+            value.py(),
+            if let Some(m) = name
+                && let Some(o) = object
+            {
+                format!("The member {} from {} expects {}", m, o.repr()?, problem)
+            } else {
+                format!("Expected {problem}")
+            },
+        )),
+    }
+}
+
+/// Qualified name of a type, used to describe validators in error messages.
+fn type_qualname(type_: &Bound<'_, PyType>) -> String {
+    type_
+        .qualname()
+        .map(|n| n.to_string())
+        .unwrap_or_else(|_| "<unknown>".to_string())
 }
 
 impl TypeValidator {
+    /// Describe the accepted values in an annotation like syntax, for use in
+    /// error messages.
+    pub(crate) fn describe(&self, py: Python<'_>) -> String {
+        let item = |item: &Option<BoxedValidator>, container: &str| match item {
+            Some(v) => format!("{container}[{}]", v.type_validator.describe(py)),
+            None => container.to_string(),
+        };
+        let items = |items: &Option<(BoxedValidator, BoxedValidator)>, container: &str| match items
+        {
+            Some((k, v)) => format!(
+                "{container}[{}, {}]",
+                k.type_validator.describe(py),
+                v.type_validator.describe(py)
+            ),
+            None => container.to_string(),
+        };
+        match self {
+            Self::Any {} => "Any".into(),
+            Self::None {} => "None".into(),
+            Self::Never {} => "Never".into(),
+            Self::Bool {} => "bool".into(),
+            Self::Int {} => "int".into(),
+            Self::Float {} => "float".into(),
+            Self::Complex {} => "complex".into(),
+            Self::Decimal {} => "Decimal".into(),
+            Self::Str {} => "str".into(),
+            Self::Bytes {} => "bytes".into(),
+            Self::ByteArray {} => "bytearray".into(),
+            Self::MemoryView {} => "memoryview".into(),
+            Self::Buffer { .. } => "Buffer".into(),
+            Self::Callable { .. } => "Callable".into(),
+            Self::Guard { .. } => "type guard".into(),
+            Self::Tuple { items, .. } => format!(
+                "tuple[{}]",
+                items
+                    .iter()
+                    .map(|v| v.type_validator.describe(py))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::VarTuple { item: Some(v), .. } => {
+                format!("tuple[{}, ...]", v.type_validator.describe(py))
+            }
+            Self::VarTuple { item: None, .. } => "tuple".into(),
+            Self::Typed { type_ }
+            | Self::FlagEnum { enum_type: type_ }
+            | Self::Enum { enum_type: type_ }
+            | Self::GenericAttributes { type_, .. }
+            | Self::TypedDict { type_, .. } => type_qualname(type_.bind(py)),
+            Self::Subclass { type_ } => format!("type[{}]", type_qualname(type_.bind(py))),
+            Self::Instance { types } => types
+                .0
+                .bind(py)
+                .iter()
+                .map(|t| match t.cast::<PyType>() {
+                    Ok(t) => type_qualname(t),
+                    Err(_) => t.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" | "),
+            Self::Union { members } => members
+                .iter()
+                .map(|v| v.type_validator.describe(py))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            Self::Optional { inner } => format!("{} | None", inner.type_validator.describe(py)),
+            Self::ForwardValidator { .. } => "forward reference".into(),
+            Self::FrozenSet { item: i } => item(i, "frozenset"),
+            Self::Set { item: i } => item(i, "set"),
+            Self::List { item: i } => item(i, "list"),
+            Self::Iterable { item: i, .. } => item(i, "Iterable"),
+            Self::Iterator { item: i } => item(i, "Iterator"),
+            Self::Sequence { item: i } => item(i, "Sequence"),
+            Self::Dict { items: i } => items(i, "dict"),
+            Self::Mapping { items: i } => items(i, "Mapping"),
+        }
+    }
+
     pub(crate) fn with_owner(&self, py: Python<'_>, owner: &Bound<'_, PyAny>) -> Self {
         match self {
-            Self::Tuple { items } => Self::Tuple {
+            Self::Tuple {
+                items,
+                allow_subclasses,
+            } => Self::Tuple {
                 items: items.iter().map(|v| v.with_owner(py, owner)).collect(),
+                allow_subclasses: *allow_subclasses,
             },
-            Self::VarTuple { item } => Self::VarTuple {
+            Self::VarTuple {
+                item,
+                allow_subclasses,
+            } => Self::VarTuple {
                 item: item
                     .as_ref()
                     .map(|v| BoxedValidator::from(v.with_owner(py, owner))),
+                allow_subclasses: *allow_subclasses,
             },
             Self::Union { members } => Self::Union {
                 members: members.iter().map(|v| v.with_owner(py, owner)).collect(),
             },
+            Self::Optional { inner } => Self::Optional {
+                inner: BoxedValidator::from(inner.with_owner(py, owner)),
+            },
             Self::GenericAttributes { type_, attributes } => Self::GenericAttributes {
                 type_: type_.clone_ref(py),
                 attributes: attributes
@@ -410,6 +935,22 @@ impl TypeValidator {
                     .as_ref()
                     .map(|v| BoxedValidator::from(v.with_owner(py, owner))),
             },
+            Self::Iterable { item, materialize } => Self::Iterable {
+                item: item
+                    .as_ref()
+                    .map(|v| BoxedValidator::from(v.with_owner(py, owner))),
+                materialize: *materialize,
+            },
+            Self::Iterator { item } => Self::Iterator {
+                item: item
+                    .as_ref()
+                    .map(|v| BoxedValidator::from(v.with_owner(py, owner))),
+            },
+            Self::Sequence { item } => Self::Sequence {
+                item: item
+                    .as_ref()
+                    .map(|v| BoxedValidator::from(v.with_owner(py, owner))),
+            },
             Self::Dict { items } => Self::Dict {
                 items: items.as_ref().map(|(k, v)| {
                     (
@@ -418,10 +959,58 @@ impl TypeValidator {
                     )
                 }),
             },
+            Self::Mapping { items } => Self::Mapping {
+                items: items.as_ref().map(|(k, v)| {
+                    (
+                        BoxedValidator::from(k.with_owner(py, owner)),
+                        BoxedValidator::from(v.with_owner(py, owner)),
+                    )
+                }),
+            },
+            Self::TypedDict { type_, fields } => Self::TypedDict {
+                type_: type_.clone_ref(py),
+                fields: fields
+                    .iter()
+                    .map(|(k, v, r)| (k.clone(), v.with_owner(py, owner), *r))
+                    .collect(),
+            },
             _ => self.clone(),
         }
     }
 
+    /// Check whether the validator relies on a forward reference, which may not
+    /// be resolvable before the owner class is fully created.
+    pub(crate) fn contains_forward_ref(&self) -> bool {
+        matches!(self, Self::ForwardValidator { .. })
+            || self.any_nested(&|v| v.type_validator.contains_forward_ref())
+    }
+
+    /// Check whether the predicate holds for any of the validators nested in
+    /// this one (e.g. the item validator of a list).
+    pub(crate) fn any_nested(&self, predicate: &dyn Fn(&Validator) -> bool) -> bool {
+        match self {
+            Self::Tuple { items, .. } | Self::Union { members: items } => {
+                items.iter().any(predicate)
+            }
+            Self::VarTuple { item, .. }
+            | Self::FrozenSet { item }
+            | Self::Set { item }
+            | Self::List { item }
+            | Self::Iterable { item, .. }
+            | Self::Iterator { item }
+            | Self::Sequence { item } => item.as_ref().is_some_and(|v| predicate(v)),
+            Self::Optional { inner } => predicate(inner),
+            Self::Dict { items } | Self::Mapping { items } => items
+                .as_ref()
+                .is_some_and(|(k, v)| predicate(k) || predicate(v)),
+            Self::GenericAttributes { attributes, .. } => {
+                attributes.iter().any(|(_, v)| predicate(v))
+            }
+            Self::TypedDict { fields, .. } => fields.iter().any(|(_, v, _)| predicate(v)),
+            _ => false,
+        }
+    }
+
     /// Validate the type of the value, for container a new container may be
     /// returned (e.g. a new tuple with validated items), but the value itself
     /// is not coerced (e.g. a str is not converted to int even if the type
@@ -448,6 +1037,20 @@ impl TypeValidator {
                     validation_error!("bool", name, object, value)
                 }
             }
+            Self::Never {} => Err(new_validation_error(
+                value.py(),
+                if let Some(m) = name
+                    && let Some(o) = object
+                {
+                    format!(
+                        "The member '{}' of {} has type Never and cannot hold any value",
+                        m,
+                        o.repr()?
+                    )
+                } else {
+                    "Type Never cannot hold any value".to_string()
+                },
+            )),
             Self::Int {} => {
                 if unsafe { PyLong_Check(value.as_ptr()) } != 0 {
                     Ok(value.clone())
@@ -469,6 +1072,13 @@ impl TypeValidator {
                     validation_error!("complex", name, object, value)
                 }
             }
+            Self::Decimal {} => {
+                if value.is_instance(get_decimal_type(value.py()))? {
+                    Ok(value.clone())
+                } else {
+                    validation_error!("Decimal", name, object, value)
+                }
+            }
             Self::Str {} => {
                 if unsafe { PyUnicode_Check(value.as_ptr()) } != 0 {
                     Ok(value.clone())
@@ -476,34 +1086,125 @@ impl TypeValidator {
                     validation_error!("str", name, object, value)
                 }
             }
+            // bytes and bytearray are easily confused, so the error explains
+            // why the other one is rejected.
             Self::Bytes {} => {
                 if unsafe { PyBytes_Check(value.as_ptr()) } != 0 {
                     Ok(value.clone())
+                } else if unsafe { PyByteArray_Check(value.as_ptr()) } != 0 {
+                    validation_error!(
+                        "bytes (bytearray is mutable and hence not accepted)",
+                        name,
+                        object,
+                        value
+                    )
                 } else {
                     validation_error!("bytes", name, object, value)
                 }
             }
-            Self::Tuple { items } => {
-                if let Ok(tuple) = value.cast_exact::<pyo3::types::PyTuple>() {
+            Self::ByteArray {} => {
+                if unsafe { PyByteArray_Check(value.as_ptr()) } != 0 {
+                    Ok(value.clone())
+                } else if unsafe { PyBytes_Check(value.as_ptr()) } != 0 {
+                    validation_error!(
+                        "bytearray (bytes is immutable and hence not accepted)",
+                        name,
+                        object,
+                        value
+                    )
+                } else {
+                    validation_error!("bytearray", name, object, value)
+                }
+            }
+            Self::MemoryView {} => {
+                if unsafe { PyMemoryView_Check(value.as_ptr()) } != 0 {
+                    Ok(value.clone())
+                } else {
+                    validation_error!("memoryview", name, object, value)
+                }
+            }
+            Self::FlagEnum { enum_type: type_ } | Self::Enum { enum_type: type_ } => {
+                let t = type_.bind(value.py());
+                if value.is_instance(t)? {
+                    Ok(value.clone())
+                } else {
+                    validation_error!(t.name()?, name, object, value)
+                }
+            }
+            Self::Buffer {
+                format,
+                min_size,
+                max_size,
+            } => validate_buffer(name, object, value, format.as_deref(), *min_size, *max_size),
+            Self::Callable {
+                returns,
+                check_returns,
+                arity,
+            } => {
+                if !value.is_callable() {
+                    return validation_error!("callable", name, object, value);
+                }
+                let returns = returns.as_ref().filter(|_| *check_returns);
+                // Callables whose signature cannot be inspected are accepted.
+                if (arity.is_some() || returns.is_some())
+                    && let Some(signature) = callable_signature(value)?
+                {
+                    if let Some(arity) = arity {
+                        check_callable_arity(name, object, value, &signature, *arity)?;
+                    }
+                    if let Some(returns) = returns {
+                        let returns = returns.bind(value.py());
+                        check_callable_returns(name, object, value, &signature, returns)?;
+                    }
+                }
+                Ok(value.clone())
+            }
+            Self::Guard { predicate } => {
+                let predicate = predicate.0.bind(value.py());
+                if predicate.call1((value,))?.is_truthy()? {
+                    return Ok(value.clone());
+                }
+                let guard = match predicate.getattr(intern!(value.py(), "__qualname__")) {
+                    Ok(qualname) => qualname.str()?.to_string(),
+                    Err(_) => predicate.repr()?.to_string(),
+                };
+                validation_error!(
+                    format!("value satisfying the type guard {guard}"),
+                    name,
+                    object,
+                    value
+                )
+            }
+            Self::Tuple {
+                items,
+                allow_subclasses,
+            } => {
+                if let Some(tuple) = cast_tuple(value, *allow_subclasses) {
                     let t_length = tuple.len();
                     if t_length != items.len() {
                         return {
                             if let Some(m) = name
                                 && let Some(o) = object
                             {
-                                Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                                    "The member {} from {} expects a tuple of length {}, got a tuple of length {}",
-                                    m,
-                                    o.repr()?,
-                                    items.len(),
-                                    t_length,
-                                )))
+                                Err(new_validation_error(
+                                    value.py(),
+                                    format!(
+                                        "The member {} from {} expects a tuple of length {}, got a tuple of length {}",
+                                        m,
+                                        o.repr()?,
+                                        items.len(),
+                                        t_length,
+                                    ),
+                                ))
                             } else {
-                                Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                                    "Expected a tuple of length {}, got a tuple of length {}",
-                                    items.len(),
-                                    t_length,
-                                )))
+                                Err(new_validation_error(
+                                    value.py(),
+                                    format!(
+                                        "Expected a tuple of length {}, got a tuple of length {}",
+                                        items.len(),
+                                        t_length,
+                                    ),
+                                ))
                             }
                         };
                     }
@@ -534,20 +1235,24 @@ impl TypeValidator {
                                 {
                                     return Err(crate::utils::err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {} for the member {} of {}.",
-                                            index,
-                                            m,
-                                            o.repr()?
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!(
+                                                "Failed to validate item {} for the member {} of {}.",
+                                                index,
+                                                m,
+                                                o.repr()?
+                                            ),
+                                        ),
                                         cause,
                                     ));
                                 } else {
                                     return Err(crate::utils::err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {index}.",
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!("Failed to validate item {index}.",),
+                                        ),
                                         cause,
                                     ));
                                 }
@@ -563,8 +1268,11 @@ impl TypeValidator {
                     validation_error!("tuple", name, object, value)
                 }
             }
-            Self::VarTuple { item: Some(item) } => {
-                if let Ok(tuple) = value.cast_exact::<pyo3::types::PyTuple>() {
+            Self::VarTuple {
+                item: Some(item),
+                allow_subclasses,
+            } => {
+                if let Some(tuple) = cast_tuple(value, *allow_subclasses) {
                     let mut validated_items: Option<TupleBuilder<'_>> = None;
                     for (index, titem) in tuple.iter().enumerate() {
                         match item.validate(name, object, &titem) {
@@ -591,20 +1299,24 @@ impl TypeValidator {
                                 {
                                     return Err(crate::utils::err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {} for the member {} of {}.",
-                                            index,
-                                            m,
-                                            o.repr()?
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!(
+                                                "Failed to validate item {} for the member {} of {}.",
+                                                index,
+                                                m,
+                                                o.repr()?
+                                            ),
+                                        ),
                                         cause,
                                     ));
                                 } else {
                                     return Err(crate::utils::err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {index}.",
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!("Failed to validate item {index}.",),
+                                        ),
                                         cause,
                                     ));
                                 }
@@ -620,14 +1332,20 @@ impl TypeValidator {
                     validation_error!("tuple", name, object, value)
                 }
             }
-            Self::VarTuple { item: None } => {
-                if value.cast_exact::<pyo3::types::PyTuple>().is_ok() {
+            Self::VarTuple {
+                item: None,
+                allow_subclasses,
+            } => {
+                if cast_tuple(value, *allow_subclasses).is_some() {
                     Ok(value.clone())
                 } else {
                     validation_error!("tuple", name, object, value)
                 }
             }
             Self::FrozenSet { item: Some(item) } => {
+                // Frozensets are immutable so the original object is preserved
+                // when no item is changed by validation, contrary to sets which
+                // are always wrapped in a validating AtorsSet.
                 if let Ok(fset) = value.cast_exact::<pyo3::types::PyFrozenSet>() {
                     let mut validated_items: Option<Vec<Bound<'_, PyAny>>> = None;
                     for (index, titem) in fset.iter().enumerate() {
@@ -637,18 +1355,17 @@ impl TypeValidator {
                                     match &mut validated_items {
                                         Some(vec) => vec.push(v),
                                         None => {
+                                            // Frozensets do not support indexing so
+                                            // the already validated items are
+                                            // collected by iterating again.
                                             let mut vec = Vec::with_capacity(fset.len());
-                                            for i in 0..index {
-                                                vec.push(
-                                                    fset.get_item(i).expect(
-                                                        "All indexes are known to be valid.",
-                                                    ),
-                                                );
-                                            }
+                                            vec.extend(fset.iter().take(index));
                                             vec.push(v);
                                             validated_items = Some(vec);
                                         }
                                     }
+                                } else if let Some(vec) = &mut validated_items {
+                                    vec.push(titem);
                                 }
                             }
                             Err(cause) => {
@@ -657,20 +1374,24 @@ impl TypeValidator {
                                 {
                                     return Err(crate::utils::err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {} for the member {} of {}.",
-                                            index,
-                                            m,
-                                            o.repr()?
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!(
+                                                "Failed to validate item {} for the member {} of {}.",
+                                                index,
+                                                m,
+                                                o.repr()?
+                                            ),
+                                        ),
                                         cause,
                                     ));
                                 } else {
                                     return Err(crate::utils::err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {index}.",
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!("Failed to validate item {index}.",),
+                                        ),
                                         cause,
                                     ));
                                 }
@@ -694,6 +1415,9 @@ impl TypeValidator {
                 }
             }
             Self::Set { item: Some(item) } => {
+                // Sets are mutable and hence always copied into an AtorsSet so
+                // that later mutations are validated and do not affect the
+                // assigned object, even if no item is changed by validation.
                 if let Ok(ators_set) = value.cast::<crate::containers::AtorsSet>()
                     && ators_set.get().matches_assignment_context(name, object)
                 {
@@ -721,20 +1445,24 @@ impl TypeValidator {
                                 {
                                     return Err(err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {} for the member {} of {}.",
-                                            index,
-                                            m,
-                                            o.repr()?
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!(
+                                                "Failed to validate item {} for the member {} of {}.",
+                                                index,
+                                                m,
+                                                o.repr()?
+                                            ),
+                                        ),
                                         cause,
                                     ));
                                 } else {
                                     return Err(err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {index}.",
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!("Failed to validate item {index}.",),
+                                        ),
                                         cause,
                                     ));
                                 }
@@ -782,20 +1510,24 @@ impl TypeValidator {
                                 {
                                     return Err(err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {} for the member {} of {}.",
-                                            index,
-                                            m,
-                                            o.repr()?
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!(
+                                                "Failed to validate item {} for the member {} of {}.",
+                                                index,
+                                                m,
+                                                o.repr()?
+                                            ),
+                                        ),
                                         cause,
                                     ));
                                 } else {
                                     return Err(err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate item {index}.",
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!("Failed to validate item {index}.",),
+                                        ),
                                         cause,
                                     ));
                                 }
@@ -849,21 +1581,24 @@ impl TypeValidator {
                                 {
                                     return Err(err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate key '{}' for the member {} of {}.",
-                                            tk.repr()?,
-                                            m,
-                                            o.repr()?
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!(
+                                                "Failed to validate key '{}' for the member {} of {}.",
+                                                tk.repr()?,
+                                                m,
+                                                o.repr()?
+                                            ),
+                                        ),
                                         err,
                                     ));
                                 } else {
                                     return Err(err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate key '{}'.",
-                                            tk.repr()?,
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!("Failed to validate key '{}'.", tk.repr()?,),
+                                        ),
                                         err,
                                     ));
                                 }
@@ -874,23 +1609,29 @@ impl TypeValidator {
                                 {
                                     return Err(err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate value '{}' with key '{}' for the member {} of {}.",
-                                            tv.repr()?,
-                                            tk.repr()?,
-                                            m,
-                                            o.repr()?
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!(
+                                                "Failed to validate value '{}' with key '{}' for the member {} of {}.",
+                                                tv.repr()?,
+                                                tk.repr()?,
+                                                m,
+                                                o.repr()?
+                                            ),
+                                        ),
                                         err,
                                     ));
                                 } else {
                                     return Err(err_with_cause(
                                         value.py(),
-                                        pyo3::exceptions::PyTypeError::new_err(format!(
-                                            "Failed to validate value '{}' with key '{}'.",
-                                            tk.repr()?,
-                                            tv.repr()?
-                                        )),
+                                        new_validation_error(
+                                            value.py(),
+                                            format!(
+                                                "Failed to validate value '{}' with key '{}'.",
+                                                tv.repr()?,
+                                                tk.repr()?
+                                            ),
+                                        ),
                                         err,
                                     ));
                                 }
@@ -910,6 +1651,152 @@ impl TypeValidator {
                     validation_error!("dict", name, object, value)
                 }
             }
+            Self::Sequence { item } => {
+                if unsafe { PyUnicode_Check(value.as_ptr()) } != 0
+                    || unsafe { PyBytes_Check(value.as_ptr()) } != 0
+                {
+                    return validation_error!(
+                        "sequence other than str or bytes",
+                        name,
+                        object,
+                        value
+                    );
+                }
+                let Ok(sequence) = value.cast::<PySequence>() else {
+                    return validation_error!("sequence", name, object, value);
+                };
+                if let Some(item) = item {
+                    for (index, titem) in sequence.try_iter()?.enumerate() {
+                        if let Err(cause) = item.strict_validate(name, object, &titem?) {
+                            let msg = if let Some(m) = name
+                                && let Some(o) = object
+                            {
+                                format!(
+                                    "Failed to validate item {} for the member {} of {}.",
+                                    index,
+                                    m,
+                                    o.repr()?
+                                )
+                            } else {
+                                format!("Failed to validate item {index}.")
+                            };
+                            return Err(err_with_cause(
+                                value.py(),
+                                new_validation_error(value.py(), msg),
+                                cause,
+                            ));
+                        }
+                    }
+                }
+                Ok(value.clone())
+            }
+            Self::Mapping { items } => {
+                let Ok(mapping) = value.cast::<PyMapping>() else {
+                    return validation_error!("mapping", name, object, value);
+                };
+                if let Some((key_v, val_v)) = items {
+                    for pair in mapping.items()?.iter() {
+                        let (tk, tv): (Bound<'py, PyAny>, Bound<'py, PyAny>) = pair.extract()?;
+                        let (what, cause) = match key_v.strict_validate(name, object, &tk) {
+                            Err(err) => (format!("key {}", tk.repr()?), err),
+                            Ok(_) => match val_v.strict_validate(name, object, &tv) {
+                                Err(err) => {
+                                    (format!("value {} with key {}", tv.repr()?, tk.repr()?), err)
+                                }
+                                Ok(_) => continue,
+                            },
+                        };
+                        let msg = if let Some(m) = name
+                            && let Some(o) = object
+                        {
+                            format!(
+                                "Failed to validate {} for the member {} of {}.",
+                                what,
+                                m,
+                                o.repr()?
+                            )
+                        } else {
+                            format!("Failed to validate {what}.")
+                        };
+                        return Err(err_with_cause(
+                            value.py(),
+                            new_validation_error(value.py(), msg),
+                            cause,
+                        ));
+                    }
+                }
+                Ok(value.clone())
+            }
+            Self::TypedDict { type_, fields } => {
+                let Ok(dict) = value.cast::<PyDict>() else {
+                    return validation_error!(type_.bind(value.py()).repr()?, name, object, value);
+                };
+                let py = value.py();
+                let validated = PyDict::new(py);
+                for (key, validator, required) in fields {
+                    let Some(item) = dict.get_item(key)? else {
+                        if *required {
+                            return Err(new_validation_error(
+                                value.py(),
+                                format!(
+                                    "Missing required key '{}' for {}",
+                                    key,
+                                    type_.bind(py).repr()?
+                                ),
+                            ));
+                        }
+                        continue;
+                    };
+                    match validator.validate(name, object, &item) {
+                        Ok(v) => validated.set_item(key, v)?,
+                        Err(cause) => {
+                            if let Some(m) = name
+                                && let Some(o) = object
+                            {
+                                return Err(err_with_cause(
+                                    py,
+                                    new_validation_error(
+                                        value.py(),
+                                        format!(
+                                            "Failed to validate value with key '{}' for the member {} of {}.",
+                                            key,
+                                            m,
+                                            o.repr()?
+                                        ),
+                                    ),
+                                    cause,
+                                ));
+                            } else {
+                                return Err(err_with_cause(
+                                    py,
+                                    new_validation_error(
+                                        value.py(),
+                                        format!("Failed to validate value with key '{key}'.",),
+                                    ),
+                                    cause,
+                                ));
+                            }
+                        }
+                    }
+                }
+                if validated.len() != dict.len() {
+                    let unknown = dict
+                        .keys()
+                        .iter()
+                        .filter(|k| !validated.contains(k).unwrap_or(false))
+                        .map(|k| k.repr().map(|r| r.to_string()))
+                        .collect::<PyResult<Vec<_>>>()?;
+                    return Err(new_validation_error(
+                        value.py(),
+                        format!(
+                            "Unexpected key(s) {} for {}",
+                            unknown.join(", "),
+                            type_.bind(py).repr()?
+                        ),
+                    ));
+                }
+                Ok(validated.into_any())
+            }
             Self::Typed { type_ } => {
                 let t = type_.bind(value.py());
                 if value.is_instance(t)? {
@@ -922,22 +1809,28 @@ impl TypeValidator {
                 let t = type_.bind(value.py());
                 // Check if the value is a type object (including metaclasses like ABCMeta)
                 if value.cast::<PyType>().is_err() {
-                    return Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                        "Expected a type/class object, got {} ({})",
-                        value.repr()?,
-                        value.get_type().name()?
-                    )));
+                    return Err(new_validation_error(
+                        value.py(),
+                        format!(
+                            "Expected a type/class object, got {} ({})",
+                            value.repr()?,
+                            value.get_type().name()?
+                        ),
+                    ));
                 }
                 // Check if the value is a subclass of type_
                 let value_type = value.cast::<PyType>()?;
                 if value_type.is_subclass(t)? {
                     Ok(value.clone())
                 } else {
-                    Err(pyo3::exceptions::PyTypeError::new_err(format!(
-                        "Expected a subclass of {}, got {}",
-                        t.repr()?,
-                        value.repr()?
-                    )))
+                    Err(new_validation_error(
+                        value.py(),
+                        format!(
+                            "Expected a subclass of {}, got {}",
+                            t.repr()?,
+                            value.repr()?
+                        ),
+                    ))
                 }
             }
             Self::Instance { types } => {
@@ -956,17 +1849,40 @@ impl TypeValidator {
                         Err(e) => err.push(e),
                     }
                 }
-                let eg = pyo3::exceptions::PyTypeError::new_err(format!(
-                    "Value {} is not valid for any member of the union for {:?}",
-                    value.repr()?,
-                    members
-                ));
+                let eg = new_validation_error(
+                    value.py(),
+                    format!(
+                        "Value {} is not valid for any member of the union {}",
+                        value.repr()?,
+                        self.describe(value.py())
+                    ),
+                );
                 Err(crate::utils::err_with_cause(
                     value.py(),
                     eg,
                     pyo3::exceptions::PyBaseExceptionGroup::new_err(err),
                 ))
             }
+            Self::Optional { inner } => {
+                if value.is_none() {
+                    return Ok(value.clone());
+                }
+                inner.validate(name, object, value).map_err(|err| {
+                    let msg = match value.repr() {
+                        Ok(r) => format!(
+                            "Value {} is neither None nor valid for {}",
+                            r,
+                            inner.type_validator.describe(value.py())
+                        ),
+                        Err(e) => return e,
+                    };
+                    crate::utils::err_with_cause(
+                        value.py(),
+                        new_validation_error(value.py(), msg),
+                        err,
+                    )
+                })
+            }
             Self::GenericAttributes { type_, attributes } => {
                 let t = type_.bind(value.py());
                 if !value.is_instance(t)? {
@@ -984,23 +1900,29 @@ impl TypeValidator {
                             {
                                 return Err(crate::utils::err_with_cause(
                                     value.py(),
-                                    pyo3::exceptions::PyTypeError::new_err(format!(
-                                        "Failed to validate attribute '{}' of {} for the member {} of {}.",
-                                        attr_name,
-                                        value.repr()?,
-                                        m,
-                                        o.repr()?
-                                    )),
+                                    new_validation_error(
+                                        value.py(),
+                                        format!(
+                                            "Failed to validate attribute '{}' of {} for the member {} of {}.",
+                                            attr_name,
+                                            value.repr()?,
+                                            m,
+                                            o.repr()?
+                                        ),
+                                    ),
                                     cause,
                                 ));
                             } else {
                                 return Err(crate::utils::err_with_cause(
                                     value.py(),
-                                    pyo3::exceptions::PyTypeError::new_err(format!(
-                                        "Failed to validate attribute '{}' of {}.",
-                                        attr_name,
-                                        value.repr()?
-                                    )),
+                                    new_validation_error(
+                                        value.py(),
+                                        format!(
+                                            "Failed to validate attribute '{}' of {}.",
+                                            attr_name,
+                                            value.repr()?
+                                        ),
+                                    ),
                                     cause,
                                 ));
                             }
@@ -1009,6 +1931,66 @@ impl TypeValidator {
                 }
                 Ok(value.clone())
             }
+            Self::Iterable { item, materialize } => {
+                let py = value.py();
+                let Ok(iterator) = value.try_iter() else {
+                    return validation_error!("iterable", name, object, value);
+                };
+                if *materialize {
+                    // Consume the iterable, which cannot be undone for single
+                    // use iterables such as generators.
+                    let mut items = Vec::with_capacity(value.len().unwrap_or(0));
+                    for (index, titem) in iterator.enumerate() {
+                        let titem = titem?;
+                        match item {
+                            Some(item) => match item.validate(name, object, &titem) {
+                                Ok(v) => items.push(v),
+                                Err(cause) => {
+                                    let msg = if let Some(m) = name
+                                        && let Some(o) = object
+                                    {
+                                        format!(
+                                            "Failed to validate item {} for the member {} of {}.",
+                                            index,
+                                            m,
+                                            o.repr()?
+                                        )
+                                    } else {
+                                        format!("Failed to validate item {index}.")
+                                    };
+                                    return Err(crate::utils::err_with_cause(
+                                        py,
+                                        new_validation_error(py, msg),
+                                        cause,
+                                    ));
+                                }
+                            },
+                            None => items.push(titem),
+                        }
+                    }
+                    Ok(PyTuple::new(py, items)?.into_any())
+                } else if let Some(item) = item {
+                    Ok(
+                        crate::containers::AtorsIterable::new(value, (*item.0).clone(), name)?
+                            .into_any(),
+                    )
+                } else {
+                    Ok(value.clone())
+                }
+            }
+            Self::Iterator { item } => {
+                let Ok(iterator) = value.cast::<PyIterator>() else {
+                    return validation_error!("iterator", name, object, value);
+                };
+                if let Some(item) = item {
+                    Ok(
+                        crate::containers::AtorsIterator::new(iterator, (*item.0).clone(), name)?
+                            .into_any(),
+                    )
+                } else {
+                    Ok(value.clone())
+                }
+            }
             Self::ForwardValidator { late_validator } => {
                 let py = value.py();
                 let resolved_validator = late_validator.get_validator(py)?;
@@ -1040,19 +2022,29 @@ impl TypeValidator {
     pub fn is_type_mutable<'py>(&self, py: Python<'py>) -> Mutability {
         match self {
             Self::None {}
+            | Self::Never {}
             | Self::Bool {}
             | Self::Int {}
             | Self::Float {}
             | Self::Complex {}
+            | Self::Decimal {}
             | Self::Bytes {}
-            | Self::Str {} => Mutability::Immutable,
-            Self::Any {} => Mutability::Undecidable,
-            Self::FrozenSet { item } | Self::VarTuple { item } => match item {
+            | Self::Str {}
+            | Self::FlagEnum { .. }
+            | Self::Enum { .. } => Mutability::Immutable,
+            Self::ByteArray {} => Mutability::Mutable,
+            // Whether the memory can be written depends on the exporting object.
+            Self::Any {}
+            | Self::Buffer { .. }
+            | Self::MemoryView {}
+            | Self::Callable { .. }
+            | Self::Guard { .. } => Mutability::Undecidable,
+            Self::FrozenSet { item } | Self::VarTuple { item, .. } => match item {
                 None => Mutability::Immutable,
                 Some(iv) => iv.type_validator.is_type_mutable(py),
             },
             // NOTE try_fold does not seem relevant here
-            Self::Tuple { items } => {
+            Self::Tuple { items, .. } => {
                 items
                     .iter()
                     .fold(Mutability::Immutable, |acc: Mutability, e| {
@@ -1076,7 +2068,23 @@ impl TypeValidator {
             }
             Self::Set { item: _ } => Mutability::Mutable,
             Self::List { item: _ } => Mutability::Mutable,
+            // Materialized iterables are stored as tuples.
+            Self::Iterable {
+                item,
+                materialize: true,
+            } => match item {
+                None => Mutability::Immutable,
+                Some(iv) => iv.type_validator.is_type_mutable(py),
+            },
+            Self::Iterable {
+                materialize: false, ..
+            } => Mutability::Undecidable,
+            // Consuming an iterator changes its state.
+            Self::Iterator { .. } => Mutability::Mutable,
             Self::Dict { items: _ } => Mutability::Mutable,
+            Self::TypedDict { .. } => Mutability::Mutable,
+            // The value is kept as is and may be a tuple as well as a list.
+            Self::Sequence { .. } | Self::Mapping { .. } => Mutability::Undecidable,
             Self::Typed { type_ } => {
                 let mm = get_type_mutability_map(py);
                 with_critical_section(mm.as_any(), || {
@@ -1118,6 +2126,8 @@ impl TypeValidator {
                     })
             }
             Self::ForwardValidator { late_validator } => late_validator.is_type_mutable(py),
+            // None is immutable.
+            Self::Optional { inner } => inner.type_validator.is_type_mutable(py),
             Self::GenericAttributes {
                 type_,
                 attributes: _,
@@ -1153,39 +2163,272 @@ impl TypeValidator {
     }
 }
 
+impl PartialEq for TypeValidator {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Tuple {
+                    items: a,
+                    allow_subclasses: sa,
+                },
+                Self::Tuple {
+                    items: b,
+                    allow_subclasses: sb,
+                },
+            ) => a == b && sa == sb,
+            (Self::Union { members: a }, Self::Union { members: b }) => a == b,
+            (Self::Optional { inner: a }, Self::Optional { inner: b }) => a == b,
+            (
+                Self::VarTuple {
+                    item: a,
+                    allow_subclasses: sa,
+                },
+                Self::VarTuple {
+                    item: b,
+                    allow_subclasses: sb,
+                },
+            ) => a == b && sa == sb,
+            (Self::FrozenSet { item: a }, Self::FrozenSet { item: b })
+            | (Self::Set { item: a }, Self::Set { item: b })
+            | (Self::List { item: a }, Self::List { item: b })
+            | (Self::Iterator { item: a }, Self::Iterator { item: b })
+            | (Self::Sequence { item: a }, Self::Sequence { item: b }) => a == b,
+            (
+                Self::Iterable {
+                    item: a,
+                    materialize: ma,
+                },
+                Self::Iterable {
+                    item: b,
+                    materialize: mb,
+                },
+            ) => a == b && ma == mb,
+            (Self::Dict { items: a }, Self::Dict { items: b })
+            | (Self::Mapping { items: a }, Self::Mapping { items: b }) => a == b,
+            (Self::Typed { type_: a }, Self::Typed { type_: b })
+            | (Self::Subclass { type_: a }, Self::Subclass { type_: b })
+            | (Self::FlagEnum { enum_type: a }, Self::FlagEnum { enum_type: b })
+            | (Self::Enum { enum_type: a }, Self::Enum { enum_type: b }) => py_eq(a, b),
+            (Self::Instance { types: a }, Self::Instance { types: b }) => a == b,
+            (
+                Self::Buffer {
+                    format: fa,
+                    min_size: mina,
+                    max_size: maxa,
+                },
+                Self::Buffer {
+                    format: fb,
+                    min_size: minb,
+                    max_size: maxb,
+                },
+            ) => fa == fb && mina == minb && maxa == maxb,
+            (
+                Self::Callable {
+                    returns: ra,
+                    check_returns: ca,
+                    arity: aa,
+                },
+                Self::Callable {
+                    returns: rb,
+                    check_returns: cb,
+                    arity: ab,
+                },
+            ) => {
+                ca == cb
+                    && aa == ab
+                    && match (ra, rb) {
+                        (Some(a), Some(b)) => py_eq(a, b),
+                        (None, None) => true,
+                        _ => false,
+                    }
+            }
+            (Self::Guard { predicate: a }, Self::Guard { predicate: b }) => a == b,
+            (
+                Self::GenericAttributes {
+                    type_: a,
+                    attributes: a_attrs,
+                },
+                Self::GenericAttributes {
+                    type_: b,
+                    attributes: b_attrs,
+                },
+            ) => py_eq(a, b) && a_attrs == b_attrs,
+            (
+                Self::TypedDict {
+                    type_: a,
+                    fields: a_fields,
+                },
+                Self::TypedDict {
+                    type_: b,
+                    fields: b_fields,
+                },
+            ) => py_eq(a, b) && a_fields == b_fields,
+            (
+                Self::ForwardValidator { late_validator: a },
+                Self::ForwardValidator { late_validator: b },
+            ) => a == b,
+            // The remaining variants do not carry any data.
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Hash for TypeValidator {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Tuple {
+                items,
+                allow_subclasses,
+            } => {
+                items.hash(state);
+                allow_subclasses.hash(state);
+            }
+            Self::Union { members } => members.hash(state),
+            Self::Optional { inner } => inner.hash(state),
+            Self::VarTuple {
+                item,
+                allow_subclasses,
+            } => {
+                item.hash(state);
+                allow_subclasses.hash(state);
+            }
+            Self::FrozenSet { item }
+            | Self::Set { item }
+            | Self::List { item }
+            | Self::Iterator { item }
+            | Self::Sequence { item } => item.hash(state),
+            Self::Iterable { item, materialize } => {
+                item.hash(state);
+                materialize.hash(state);
+            }
+            Self::Dict { items } | Self::Mapping { items } => items.hash(state),
+            Self::Typed { type_ }
+            | Self::Subclass { type_ }
+            | Self::FlagEnum { enum_type: type_ }
+            | Self::Enum { enum_type: type_ } => py_hash(type_, state),
+            Self::Instance { types } => types.hash(state),
+            Self::Buffer {
+                format,
+                min_size,
+                max_size,
+            } => (format, min_size, max_size).hash(state),
+            Self::Callable {
+                returns,
+                check_returns,
+                arity,
+            } => {
+                if let Some(returns) = returns {
+                    py_hash(returns, state);
+                }
+                check_returns.hash(state);
+                arity.hash(state);
+            }
+            Self::Guard { predicate } => predicate.hash(state),
+            Self::GenericAttributes { type_, attributes } => {
+                py_hash(type_, state);
+                attributes.hash(state);
+            }
+            Self::TypedDict { type_, fields } => {
+                py_hash(type_, state);
+                fields.hash(state);
+            }
+            Self::ForwardValidator { late_validator } => late_validator.hash(state),
+            _ => {}
+        }
+    }
+}
+
 impl Clone for TypeValidator {
     fn clone(&self) -> Self {
         Python::attach(|py| match self {
             Self::Any {} => Self::Any {},
             Self::None {} => Self::None {},
+            Self::Never {} => Self::Never {},
             Self::Bool {} => Self::Bool {},
             Self::Int {} => Self::Int {},
             Self::Float {} => Self::Float {},
             Self::Complex {} => Self::Complex {},
+            Self::Decimal {} => Self::Decimal {},
             Self::Str {} => Self::Str {},
             Self::Bytes {} => Self::Bytes {},
-            Self::Tuple { items } => Self::Tuple {
+            Self::ByteArray {} => Self::ByteArray {},
+            Self::MemoryView {} => Self::MemoryView {},
+            Self::Buffer {
+                format,
+                min_size,
+                max_size,
+            } => Self::Buffer {
+                format: format.clone(),
+                min_size: *min_size,
+                max_size: *max_size,
+            },
+            Self::Callable {
+                returns,
+                check_returns,
+                arity,
+            } => Self::Callable {
+                returns: returns.as_ref().map(|r| r.clone_ref(py)),
+                check_returns: *check_returns,
+                arity: *arity,
+            },
+            Self::Guard { predicate } => Self::Guard {
+                predicate: tv_guard::Callable(predicate.0.clone_ref(py)),
+            },
+            Self::Tuple {
+                items,
+                allow_subclasses,
+            } => Self::Tuple {
                 items: items.to_vec(),
+                allow_subclasses: *allow_subclasses,
+            },
+            Self::VarTuple {
+                item,
+                allow_subclasses,
+            } => Self::VarTuple {
+                item: item.clone(),
+                allow_subclasses: *allow_subclasses,
             },
-            Self::VarTuple { item } => Self::VarTuple { item: item.clone() },
             Self::FrozenSet { item } => Self::FrozenSet { item: item.clone() },
             Self::Set { item } => Self::Set { item: item.clone() },
             Self::List { item } => Self::List { item: item.clone() },
+            Self::Iterable { item, materialize } => Self::Iterable {
+                item: item.clone(),
+                materialize: *materialize,
+            },
+            Self::Iterator { item } => Self::Iterator { item: item.clone() },
+            Self::Sequence { item } => Self::Sequence { item: item.clone() },
             Self::Dict { items } => Self::Dict {
                 items: items.clone(),
             },
+            Self::Mapping { items } => Self::Mapping {
+                items: items.clone(),
+            },
+            Self::TypedDict { type_, fields } => Self::TypedDict {
+                type_: type_.clone_ref(py),
+                fields: fields.clone(),
+            },
             Self::Typed { type_ } => Self::Typed {
                 type_: type_.clone_ref(py),
             },
             Self::Subclass { type_ } => Self::Subclass {
                 type_: type_.clone_ref(py),
             },
+            Self::FlagEnum { enum_type } => Self::FlagEnum {
+                enum_type: enum_type.clone_ref(py),
+            },
+            Self::Enum { enum_type } => Self::Enum {
+                enum_type: enum_type.clone_ref(py),
+            },
             Self::Instance { types } => Self::Instance {
                 types: TypesTuple(types.0.clone_ref(py)),
             },
             Self::Union { members } => Self::Union {
                 members: members.to_vec(),
             },
+            Self::Optional { inner } => Self::Optional {
+                inner: inner.clone(),
+            },
             Self::GenericAttributes { type_, attributes } => Self::GenericAttributes {
                 type_: type_.clone_ref(py),
                 attributes: attributes.clone(),
@@ -7,17 +7,23 @@
 |----------------------------------------------------------------------------*/
 /// Value validation related struct and enum definitions.
 use pyo3::{
-    Borrowed, Bound, FromPyObject, IntoPyObject, Py, PyAny, PyErr, PyResult, Python, pyclass,
+    Borrowed, Bound, FromPyObject, IntoPyObject, Py, PyAny, PyErr, PyResult, Python, intern,
+    pyclass,
+    sync::PyOnceLock,
     types::{
-        PyAnyMethods, PyFrozenSet, PyFrozenSetMethods, PySet, PySetMethods, PyString, PyTypeMethods,
+        PyAnyMethods, PyByteArray, PyBytes, PyFloat, PyFrozenSet, PyFrozenSetMethods, PyInt, PySet,
+        PySetMethods, PyString, PyTuple, PyTupleMethods, PyTypeMethods,
     },
 };
 
-use crate::utils::create_behavior_callable_checker;
+use crate::errors::new_validation_error;
+use crate::utils::{create_behavior_callable_checker, err_with_cause, py_eq, py_hash};
 use std::convert::Infallible;
+use std::hash::{Hash, Hasher};
 
 create_behavior_callable_checker!(vv_callv, ValueValidator, CallValue, 1);
 create_behavior_callable_checker!(vv_callmov, ValueValidator, CallNameObjectValue, 3);
+create_behavior_callable_checker!(vv_pred, ValueValidator, Predicate, 1);
 
 #[derive(Debug)]
 pub(crate) struct ValidValues(pub Py<PyFrozenSet>);
@@ -39,6 +45,18 @@ impl FromPyObject<'_, '_> for ValidValues {
     }
 }
 
+impl PartialEq for ValidValues {
+    fn eq(&self, other: &Self) -> bool {
+        py_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for ValidValues {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        py_hash(&self.0, state);
+    }
+}
+
 impl<'py> IntoPyObject<'py> for &ValidValues {
     type Target = PyFrozenSet;
     type Output = Bound<'py, PyFrozenSet>;
@@ -48,7 +66,242 @@ impl<'py> IntoPyObject<'py> for &ValidValues {
     }
 }
 
-#[pyclass(module = "ators._ators", frozen, from_py_object)]
+/// Literals accepted by a Literal value validator.
+///
+/// The hashable literals are indexed by (type, value) pairs in a frozenset,
+/// which matches the comparison of types and values performed by
+/// literal_matches, so that checking a value does not scan all the literals.
+/// Unhashable literals are scanned linearly.
+#[derive(Debug)]
+pub(crate) struct LiteralValues {
+    values: Py<PyTuple>,
+    keys: Py<PyFrozenSet>,
+    unhashable: Vec<Py<PyAny>>,
+}
+
+impl LiteralValues {
+    pub(crate) fn new(values: &Bound<'_, PyTuple>) -> PyResult<Self> {
+        let py = values.py();
+        let keys = PySet::empty(py)?;
+        let mut unhashable = Vec::new();
+        for literal in values.iter() {
+            let key = literal_key(&literal)?;
+            match key.hash() {
+                Ok(_) => keys.add(key)?,
+                Err(err) if err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) => {
+                    unhashable.push(literal.unbind())
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(LiteralValues {
+            values: values.clone().unbind(),
+            keys: PyFrozenSet::new(py, keys.iter())?.unbind(),
+            unhashable,
+        })
+    }
+
+    /// The literals in declaration order.
+    pub(crate) fn bind<'py>(&self, py: Python<'py>) -> &Bound<'py, PyTuple> {
+        self.values.bind(py)
+    }
+
+    fn contains<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<bool> {
+        let py = value.py();
+        match self.keys.bind(py).contains(literal_key(value)?) {
+            Ok(true) => return Ok(true),
+            Ok(false) => {}
+            // An unhashable value can only match an unhashable literal.
+            Err(err) if err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) => {}
+            Err(err) => return Err(err),
+        }
+        for literal in &self.unhashable {
+            if literal_matches(literal.bind(py), value)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Key under which a literal (or a value compared to literals) is indexed.
+fn literal_key<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyTuple>> {
+    PyTuple::new(value.py(), [value.get_type().into_any(), value.clone()])
+}
+
+impl FromPyObject<'_, '_> for LiteralValues {
+    type Error = PyErr;
+    fn extract(ob: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        LiteralValues::new(&ob.cast::<PyTuple>()?.to_owned())
+    }
+}
+
+impl Clone for LiteralValues {
+    fn clone(&self) -> Self {
+        Python::attach(|py| LiteralValues {
+            values: self.values.clone_ref(py),
+            keys: self.keys.clone_ref(py),
+            unhashable: self.unhashable.iter().map(|v| v.clone_ref(py)).collect(),
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &LiteralValues {
+    type Target = PyTuple;
+    type Output = Bound<'py, PyTuple>;
+    type Error = Infallible;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(self.values.clone_ref(py).into_bound(py))
+    }
+}
+
+/// Compiled regular expression of a Regex value validator.
+///
+/// Strings and bytes are compiled once at construction. Whether the pattern
+/// matches str or bytes is recorded to report values of the wrong kind
+/// without going through the re module.
+#[derive(Debug)]
+pub(crate) struct RegexPattern {
+    pattern: Py<PyAny>,
+    text: bool,
+}
+
+impl RegexPattern {
+    /// The source of the compiled pattern.
+    pub(crate) fn source<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.pattern.bind(py).getattr(intern!(py, "pattern"))
+    }
+}
+
+impl FromPyObject<'_, '_> for RegexPattern {
+    type Error = PyErr;
+    fn extract(ob: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        let py = ob.py();
+        let re = py.import(intern!(py, "re"))?;
+        let pattern = if ob.is_instance(&re.getattr(intern!(py, "Pattern"))?)? {
+            ob.to_owned()
+        } else if ob.is_instance_of::<PyString>() || ob.is_instance_of::<PyBytes>() {
+            re.getattr(intern!(py, "compile"))?.call1((ob,))?
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Expected a 'str', 'bytes' or 're.Pattern' for a ValueValidator.Regex, got {}",
+                ob.get_type().name()?
+            )));
+        };
+        let text = pattern
+            .getattr(intern!(py, "pattern"))?
+            .is_instance_of::<PyString>();
+        Ok(RegexPattern {
+            pattern: pattern.unbind(),
+            text,
+        })
+    }
+}
+
+impl PartialEq for RegexPattern {
+    fn eq(&self, other: &Self) -> bool {
+        py_eq(&self.pattern, &other.pattern)
+    }
+}
+
+impl Hash for RegexPattern {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        py_hash(&self.pattern, state);
+    }
+}
+
+impl Clone for RegexPattern {
+    fn clone(&self) -> Self {
+        Python::attach(|py| RegexPattern {
+            pattern: self.pattern.clone_ref(py),
+            text: self.text,
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &RegexPattern {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = Infallible;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(self.pattern.clone_ref(py).into_bound(py))
+    }
+}
+
+/// Bound of a numeric range.
+///
+/// The original object is kept so that exact numbers (int, Decimal, Fraction)
+/// can be compared without going through a lossy float conversion. The float
+/// value is only available for other numbers.
+#[derive(Debug)]
+pub(crate) struct RangeBound {
+    value: Py<PyAny>,
+    float: Option<f64>,
+}
+
+impl RangeBound {
+    /// Float approximation of the bound.
+    pub(crate) fn to_f64(&self, py: Python<'_>) -> PyResult<f64> {
+        match self.float {
+            Some(f) => Ok(f),
+            None => self.value.bind(py).extract(),
+        }
+    }
+
+    fn display(&self, py: Python<'_>) -> PyResult<String> {
+        match self.float {
+            Some(f) => Ok(f.to_string()),
+            None => Ok(self.value.bind(py).str()?.to_string()),
+        }
+    }
+}
+
+impl FromPyObject<'_, '_> for RangeBound {
+    type Error = PyErr;
+    fn extract(ob: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        let float = if ob.is_instance_of::<PyInt>() || is_exact_number(&ob)? {
+            None
+        } else {
+            Some(ob.extract::<f64>()?)
+        };
+        Ok(RangeBound {
+            value: ob.to_owned().unbind(),
+            float,
+        })
+    }
+}
+
+impl PartialEq for RangeBound {
+    fn eq(&self, other: &Self) -> bool {
+        py_eq(&self.value, &other.value)
+    }
+}
+
+impl Hash for RangeBound {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        py_hash(&self.value, state);
+    }
+}
+
+impl Clone for RangeBound {
+    fn clone(&self) -> Self {
+        Python::attach(|py| RangeBound {
+            value: self.value.clone_ref(py),
+            float: self.float,
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &RangeBound {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = Infallible;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(self.value.clone_ref(py).into_bound(py))
+    }
+}
+
+#[pyclass(module = "ators._ators", frozen, eq, hash, from_py_object)]
 #[derive(Debug)]
 pub enum ValueValidator {
     #[pyo3(constructor = (values))]
@@ -57,19 +310,110 @@ pub enum ValueValidator {
     // Python side.
     #[allow(private_interfaces)]
     Values { values: ValidValues },
+    /// Accept only the provided literal values, comparing types as well as
+    /// values so that True does not match 1, and using identity for None and
+    /// enum members.
+    #[pyo3(constructor = (values))]
+    #[allow(private_interfaces)]
+    Literal { values: LiteralValues },
     #[pyo3(constructor = (callable))]
     CallValue { callable: vv_callv::Callable },
     #[pyo3(constructor = (callable))]
     CallNameObjectValue { callable: vv_callmov::Callable },
     #[pyo3(constructor = (meth_name))]
     ObjectMethod { meth_name: Py<PyString> },
-    // #[pyo3(constructor = (min, max))]
-    // Range { min: f64, max: f64 },
+    /// Accept numbers within the bounds, a missing bound leaving the range
+    /// unbounded on that side. Float values are compared as floats to float
+    /// bounds, otherwise the original objects are compared so that ints,
+    /// Decimals and Fractions are compared exactly. NaN is always rejected.
+    #[pyo3(constructor = (min = None, max = None, inclusive = true))]
+    #[allow(private_interfaces)]
+    Range {
+        min: Option<RangeBound>,
+        max: Option<RangeBound>,
+        inclusive: bool,
+    },
+    /// Accept sized objects whose length is within the bounds.
+    #[pyo3(constructor = (min = None, max = None))]
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    /// Accept values for which value % multiple_of == 0.
+    #[pyo3(constructor = (multiple_of))]
+    MultipleOf { multiple_of: Py<PyAny> },
+    /// Accept values for which the callable returns a truthy value.
+    #[pyo3(constructor = (callable))]
+    Predicate { callable: vv_pred::Callable },
+    /// Accept str (or bytes for a bytes pattern) fully matching the pattern,
+    /// which can be a compiled re.Pattern or a string compiled once.
+    #[pyo3(constructor = (pattern))]
+    #[allow(private_interfaces)]
+    Regex { pattern: RegexPattern },
     // #[pyo3(constructor = (options))]
     // Options { options: Vec<Py<PyAny>> },
 }
 
+/// Check whether a value matches a literal.
+///
+/// None and enum members are matched by identity, other literals by type and
+/// value.
+fn literal_matches<'py>(literal: &Bound<'py, PyAny>, value: &Bound<'py, PyAny>) -> PyResult<bool> {
+    if literal.is(value) {
+        return Ok(true);
+    }
+    let py = value.py();
+    if literal.is_none() || literal.is_instance(&enum_type(py)?)? {
+        return Ok(false);
+    }
+    Ok(literal.get_type().is(value.get_type()) && literal.eq(value)?)
+}
+
+static ENUM_TYPE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static EXACT_NUMBER_TYPES: PyOnceLock<Py<PyTuple>> = PyOnceLock::new();
+
+/// Check whether a value is a number that cannot be converted to a float
+/// without loss of precision (Decimal or Fraction).
+fn is_exact_number(value: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let py = value.py();
+    let types = EXACT_NUMBER_TYPES.get_or_try_init(py, || {
+        Ok::<_, PyErr>(
+            PyTuple::new(
+                py,
+                [
+                    py.import(intern!(py, "decimal"))?
+                        .getattr(intern!(py, "Decimal"))?,
+                    py.import(intern!(py, "fractions"))?
+                        .getattr(intern!(py, "Fraction"))?,
+                ],
+            )?
+            .unbind(),
+        )
+    })?;
+    value.is_instance(types.bind(py))
+}
+
+fn enum_type(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    ENUM_TYPE
+        .get_or_try_init(py, || {
+            Ok::<_, PyErr>(
+                py.import(intern!(py, "enum"))?
+                    .getattr(intern!(py, "Enum"))?
+                    .unbind(),
+            )
+        })
+        .map(|t| t.bind(py).clone())
+}
+
 impl ValueValidator {
+    /// Whether the validator needs the object owning the member.
+    pub(crate) fn needs_object(&self) -> bool {
+        matches!(
+            self,
+            Self::CallNameObjectValue { .. } | Self::ObjectMethod { .. }
+        )
+    }
+
     /// Validate a value against this value-level validator.
     ///
     /// The validation runs after type validation and may use member/object
@@ -89,13 +433,24 @@ impl ValueValidator {
                 {
                     Ok(())
                 } else {
-                    Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    Err(new_validation_error(value.py(), format!(
                         "Value {} not in permitted list {}",
                         value.repr()?,
                         values.0.bind(value.py()).repr()?
                     )))
                 }
             }
+            Self::Literal { values } => {
+                let py = value.py();
+                if values.contains(value)? {
+                    return Ok(());
+                }
+                Err(new_validation_error(py, format!(
+                    "Value {} not in permitted literals {}",
+                    value.repr()?,
+                    values.bind(py).repr()?
+                )))
+            }
             Self::CallValue { callable } => callable
                 .0.bind(value.py())
                 .call1(
@@ -128,6 +483,269 @@ impl ValueValidator {
                     "Cannot use ObjectMethod validation when validator is not linked to a member."
                 ))?, value))
                 .map(|_| ()),
+            Self::Range { min, max, inclusive } => {
+                validate_range(value, min.as_ref(), max.as_ref(), *inclusive)
+            }
+            Self::Length { min, max } => validate_length(value, *min, *max),
+            Self::MultipleOf { multiple_of } => {
+                let py = value.py();
+                let multiple_of = multiple_of.bind(py);
+                if value.rem(multiple_of)?.eq(0)? {
+                    Ok(())
+                } else {
+                    Err(new_validation_error(py, format!(
+                        "Value {} is not a multiple of {}",
+                        value.repr()?,
+                        multiple_of.repr()?
+                    )))
+                }
+            }
+            Self::Predicate { callable } => {
+                let py = value.py();
+                let callable = callable.0.bind(py);
+                if callable.call1((value,))?.is_truthy()? {
+                    Ok(())
+                } else {
+                    Err(new_validation_error(py, format!(
+                        "Value {} does not satisfy the predicate {}",
+                        value.repr()?,
+                        callable.repr()?
+                    )))
+                }
+            }
+            Self::Regex { pattern } => validate_regex(value, pattern),
+        }
+    }
+}
+
+/// Check that a str (or bytes) fully matches a regular expression.
+fn validate_regex(value: &Bound<'_, PyAny>, pattern: &RegexPattern) -> PyResult<()> {
+    let py = value.py();
+    let (kind_ok, kind) = if pattern.text {
+        (value.is_instance_of::<PyString>(), "str")
+    } else {
+        (
+            value.is_instance_of::<PyBytes>() || value.is_instance_of::<PyByteArray>(),
+            "bytes",
+        )
+    };
+    if !kind_ok {
+        return Err(new_validation_error(
+            py,
+            format!(
+                "Value {} ({}) cannot be matched against the pattern {}, expected a {kind}",
+                value.repr()?,
+                value.get_type().name()?,
+                pattern.source(py)?.repr()?
+            ),
+        ));
+    }
+    if pattern
+        .pattern
+        .bind(py)
+        .call_method1(intern!(py, "fullmatch"), (value,))?
+        .is_none()
+    {
+        return Err(new_validation_error(
+            py,
+            format!(
+                "Value {} does not match the pattern {}",
+                value.repr()?,
+                pattern.source(py)?.repr()?
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Check that a number lies within the bounds of a range.
+fn validate_range(
+    value: &Bound<'_, PyAny>,
+    min: Option<&RangeBound>,
+    max: Option<&RangeBound>,
+    inclusive: bool,
+) -> PyResult<()> {
+    let py = value.py();
+    let not_comparable = || -> PyResult<PyErr> {
+        Ok(new_validation_error(
+            py,
+            format!(
+                "Value {} cannot be compared to a numeric range",
+                value.repr()?
+            ),
+        ))
+    };
+    // Only floats compared to float bounds use the fast path, ints being
+    // compared exactly whatever their size.
+    let exact = min.is_some_and(|m| m.float.is_none())
+        || max.is_some_and(|m| m.float.is_none())
+        || !value.is_instance_of::<PyFloat>();
+    let in_range = if exact {
+        // Compare the original objects to preserve exactness. Comparing a
+        // Decimal NaN raises, which is reported as a validation failure, but
+        // an unbounded range performs no comparison so NaN is checked first.
+        let cmp = || -> PyResult<bool> {
+            if value.ne(value)? {
+                return Ok(false);
+            }
+            let above_min = match min {
+                Some(m) if inclusive => value.ge(&m.value)?,
+                Some(m) => value.gt(&m.value)?,
+                None => true,
+            };
+            Ok(above_min
+                && match max {
+                    Some(m) if inclusive => value.le(&m.value)?,
+                    Some(m) => value.lt(&m.value)?,
+                    None => true,
+                })
+        };
+        match cmp() {
+            Ok(r) => r,
+            Err(err) => return Err(err_with_cause(py, not_comparable()?, err)),
+        }
+    } else {
+        let Ok(v) = value.extract::<f64>() else {
+            return Err(not_comparable()?);
+        };
+        // Bounds of non exact ranges always have a float value. Comparisons
+        // are written so that they fail for NaN, which is also rejected when
+        // the range is unbounded.
+        let above_min = min
+            .and_then(|m| m.float)
+            .is_none_or(|m| if inclusive { v >= m } else { v > m });
+        let below_max = max
+            .and_then(|m| m.float)
+            .is_none_or(|m| if inclusive { v <= m } else { v < m });
+        !v.is_nan() && above_min && below_max
+    };
+    if in_range {
+        return Ok(());
+    }
+    let (open, close) = if inclusive { ('[', ']') } else { ('(', ')') };
+    Err(new_validation_error(
+        py,
+        format!(
+            "Value {} is out of range {open}{}, {}{close}",
+            value.repr()?,
+            min.map_or(Ok("-inf".to_string()), |m| m.display(py))?,
+            max.map_or(Ok("inf".to_string()), |m| m.display(py))?,
+        ),
+    ))
+}
+
+/// Check that the length of a sized object lies within the bounds.
+fn validate_length(
+    value: &Bound<'_, PyAny>,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> PyResult<()> {
+    let length = match value.len() {
+        Ok(length) => length,
+        Err(err) => {
+            let py = value.py();
+            return Err(err_with_cause(
+                py,
+                new_validation_error(
+                    py,
+                    format!(
+                        "Cannot check the length of {} ({})",
+                        value.repr()?,
+                        value.get_type().name()?
+                    ),
+                ),
+                err,
+            ));
+        }
+    };
+    if min.is_none_or(|m| length >= m) && max.is_none_or(|m| length <= m) {
+        return Ok(());
+    }
+    let expected = match (min, max) {
+        (Some(min), Some(max)) => format!("between {min} and {max}"),
+        (Some(min), None) => format!("at least {min}"),
+        (None, Some(max)) => format!("at most {max}"),
+        (None, None) => unreachable!("An unbounded length is always valid"),
+    };
+    Err(new_validation_error(
+        value.py(),
+        format!("Expected {expected} items, got {length}"),
+    ))
+}
+
+impl PartialEq for ValueValidator {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Values { values: a }, Self::Values { values: b }) => a == b,
+            (Self::Literal { values: a }, Self::Literal { values: b }) => Python::attach(|py| {
+                let (a, b) = (a.bind(py), b.bind(py));
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|(x, y)| literal_matches(&x, &y).unwrap_or(false))
+            }),
+            (Self::CallValue { callable: a }, Self::CallValue { callable: b }) => a == b,
+            (
+                Self::CallNameObjectValue { callable: a },
+                Self::CallNameObjectValue { callable: b },
+            ) => a == b,
+            (Self::ObjectMethod { meth_name: a }, Self::ObjectMethod { meth_name: b }) => {
+                py_eq(a, b)
+            }
+            (
+                Self::Range {
+                    min: mina,
+                    max: maxa,
+                    inclusive: ia,
+                },
+                Self::Range {
+                    min: minb,
+                    max: maxb,
+                    inclusive: ib,
+                },
+            ) => mina == minb && maxa == maxb && ia == ib,
+            (
+                Self::Length {
+                    min: mina,
+                    max: maxa,
+                },
+                Self::Length {
+                    min: minb,
+                    max: maxb,
+                },
+            ) => mina == minb && maxa == maxb,
+            (Self::MultipleOf { multiple_of: a }, Self::MultipleOf { multiple_of: b }) => {
+                py_eq(a, b)
+            }
+            (Self::Predicate { callable: a }, Self::Predicate { callable: b }) => a == b,
+            (Self::Regex { pattern: a }, Self::Regex { pattern: b }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Hash for ValueValidator {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Values { values } => values.hash(state),
+            Self::Literal { values } => py_hash(&values.values, state),
+            Self::CallValue { callable } => callable.hash(state),
+            Self::CallNameObjectValue { callable } => callable.hash(state),
+            Self::ObjectMethod { meth_name } => py_hash(meth_name, state),
+            Self::Range {
+                min,
+                max,
+                inclusive,
+            } => {
+                min.hash(state);
+                max.hash(state);
+                inclusive.hash(state);
+            }
+            Self::Length { min, max } => (min, max).hash(state),
+            Self::MultipleOf { multiple_of } => py_hash(multiple_of, state),
+            Self::Predicate { callable } => callable.hash(state),
+            Self::Regex { pattern } => pattern.hash(state),
         }
     }
 }
@@ -138,6 +756,9 @@ impl Clone for ValueValidator {
             Self::Values { values } => Self::Values {
                 values: ValidValues(values.0.clone_ref(py)),
             },
+            Self::Literal { values } => Self::Literal {
+                values: values.clone(),
+            },
             Self::CallValue { callable } => Self::CallValue {
                 callable: vv_callv::Callable(callable.0.clone_ref(py)),
             },
@@ -147,6 +768,28 @@ impl Clone for ValueValidator {
             Self::ObjectMethod { meth_name } => Self::ObjectMethod {
                 meth_name: meth_name.clone_ref(py),
             },
+            Self::Range {
+                min,
+                max,
+                inclusive,
+            } => Self::Range {
+                min: min.clone(),
+                max: max.clone(),
+                inclusive: *inclusive,
+            },
+            Self::Length { min, max } => Self::Length {
+                min: *min,
+                max: *max,
+            },
+            Self::MultipleOf { multiple_of } => Self::MultipleOf {
+                multiple_of: multiple_of.clone_ref(py),
+            },
+            Self::Predicate { callable } => Self::Predicate {
+                callable: vv_pred::Callable(callable.0.clone_ref(py)),
+            },
+            Self::Regex { pattern } => Self::Regex {
+                pattern: pattern.clone(),
+            },
         })
     }
 }
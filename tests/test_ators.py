@@ -9,20 +9,34 @@
 
 import gc
 import weakref
+from typing import Annotated, Final
 
 import pytest
 
 from ators import (
+    UNSET,
     Ators,
+    check_value,
+    diff,
+    freeze,
     get_member,
     get_member_customization_tool,
     get_members,
     get_members_by_tag,
     get_members_by_tag_and_value,
+    iter_member_values,
     member,
+    observe,
+    reset_to_defaults,
+    set_instance_validator,
+    set_unchecked,
+    to_dict,
+    transaction,
+    update_validated,
 )
-from ators._ators import get_tracked_class_info_size
-from ators.behaviors import DelAttr, PreSetAttr
+from ators._ators import _dump_slots, get_tracked_class_info_size
+from ators.behaviors import Default, DelAttr, Observer, PreSetAttr
+from ators.validators import TypeValidator, Validator, ValueValidator
 
 
 def test_member_slot_do_not_overlap():
@@ -95,6 +109,391 @@ def test_member_access_fucntions():
         assert list(get_members_by_tag_and_value(obj, "t", 1)) == ["a"]
 
 
+def test_iter_member_values():
+    calls = []
+
+    class A(Ators):
+        a: int
+        b: int = member().default(Default.Call(lambda: calls.append(1) or 2))
+        c: int | None = None
+
+    a = A(a=1)
+    values = {n: (m, v) for n, m, v in iter_member_values(a)}
+    assert values == {"a": (A.a, 1), "b": (A.b, UNSET), "c": (A.c, UNSET)}
+    # Defaults are not computed
+    assert not calls
+    assert not UNSET
+    assert repr(UNSET) == "UNSET"
+
+    a.c = None
+    assert {n: v for n, _, v in iter_member_values(a)}["c"] is None
+
+    with pytest.raises(TypeError):
+        iter_member_values(A)
+
+
+def test_diff():
+    class A(Ators):
+        a: int
+        b: list[int]
+        c: str = "c"
+
+    x = A(a=1, b=[1])
+    y = A(a=1, b=[1])
+    assert diff(x, y) == {}
+
+    y.a = 2
+    y.c = "d"
+    assert diff(x, y) == {"a": (1, 2), "c": (UNSET, "d")}
+    assert diff(y, x) == {"a": (2, 1), "c": ("d", UNSET)}
+
+    # Unset and default values are reported as different
+    x.c
+    assert diff(x, A(a=1, b=[1])) == {"c": ("c", UNSET)}
+
+    class B(Ators):
+        a: int
+
+    with pytest.raises(TypeError, match="different classes"):
+        diff(x, B(a=1))
+
+
+def test_to_dict():
+    class A(Ators):
+        user_id: int = member().alias("user-id")
+        name: str = "x"
+        other: int = member(init=False)
+
+    a = A(**{"user-id": 1})
+    assert to_dict(a) == {"user_id": 1}
+    a.name = "y"
+    assert to_dict(a) == {"user_id": 1, "name": "y"}
+    assert to_dict(a, by_alias=True) == {"user-id": 1, "name": "y"}
+    assert to_dict(A(**to_dict(a, by_alias=True))) == to_dict(a)
+
+
+def test_reset_to_defaults():
+    class A(Ators):
+        a: int = 1
+        b: list[int] = member(default_factory=lambda: [0])
+        c: int
+        d: Final[int] = member(init=False).preset(PreSetAttr.ReadOnly()).default(2)
+        e: int = member().del_(DelAttr.Undeletable()).default(3)
+        f: int = 4
+
+    obj = A(a=5, b=[1, 2], c=6, e=7)
+    obj.d = 8
+    assert sorted(reset_to_defaults(obj)) == ["c", "d", "e"]
+    assert (obj.a, obj.b, obj.c, obj.d, obj.e, obj.f) == (1, [0], 6, 8, 7, 4)
+    # Members whose value is unset are not reported.
+    assert reset_to_defaults(A(c=1)) == ["c"]
+
+    class B(Ators, frozen=True):
+        a: int = 1
+
+    with pytest.raises(TypeError, match="frozen"):
+        reset_to_defaults(B())
+
+
+def test_reset_to_defaults_notifies_observers():
+    changes = []
+
+    class A(Ators, observable=True):
+        a: int = member(default=1).observe(Observer.CallChange(changes.append))
+        b: int = member(default=2).observe(Observer.CallChange(changes.append))
+
+    obj = A(a=3)
+    changes.clear()
+    assert reset_to_defaults(obj) == []
+    assert [(c.type, c.name, c.old) for c in changes] == [("delete", "a", 3)]
+    assert obj.a == 1
+
+
+def test_transaction():
+    class A(Ators):
+        a: int
+        b: int = 0
+        c: list[int] = member(default_factory=lambda: [])
+
+    obj = A(a=1)
+    with transaction(obj) as o:
+        assert o is obj
+        obj.a = 2
+        obj.b = 3
+    assert (obj.a, obj.b) == (2, 3)
+
+    with pytest.raises(TypeError):
+        with transaction(obj):
+            obj.a = 4
+            obj.c = [1]
+            obj.b = "x"
+    assert (obj.a, obj.b) == (2, 3)
+    # Unset slots are restored as unset.
+    assert _dump_slots(obj)[get_member(A, "c").slot_index] is None
+
+    tr = transaction(obj)
+    with tr:
+        obj.a = 5
+        with pytest.raises(ValueError):
+            with tr:
+                obj.a = 6
+                raise ValueError()
+        assert obj.a == 5
+    assert obj.a == 5
+
+
+def test_transaction_and_frozen_objects():
+    class A(Ators):
+        a: int
+
+    obj = A(a=1)
+    freeze(obj)
+    with pytest.raises(TypeError, match="frozen"):
+        with transaction(obj):
+            pass
+
+    obj = A(a=1)
+    with pytest.raises(ValueError):
+        with transaction(obj):
+            obj.a = 2
+            freeze(obj)
+            raise ValueError()
+    assert obj.a == 2
+
+
+def test_transaction_is_collected_in_cycles():
+    class A(Ators):
+        a: object = None
+
+    class Sentinel:
+        pass
+
+    obj = A()
+    tr = transaction(obj)
+    tr.__enter__()
+    assert obj in gc.get_referents(tr)
+    sentinel = Sentinel()
+    obj.a = (tr, sentinel)
+    ref = weakref.ref(sentinel)
+    del obj, tr, sentinel
+    gc.collect()
+    assert ref() is None
+
+
+def test_update_validated():
+    class A(Ators):
+        a: int
+        b: str = "b"
+        c: float = 1.0
+
+    obj = A(a=1)
+    update_validated(obj, {"a": 2, "b": "c"})
+    assert (obj.a, obj.b) == (2, "c")
+
+    # Unknown names are rejected before any member is set.
+    with pytest.raises(AttributeError, match="'bb' \\(did you mean 'b'\\?\\)") as e:
+        update_validated(obj, {"a": 3, "bb": "d", "zzz": 1})
+    assert "'zzz'" in str(e.value)
+    assert obj.a == 2
+
+    # All validation errors are reported.
+    with pytest.raises(ExceptionGroup) as e:
+        update_validated(obj, {"a": "x", "b": 1, "c": 2.0})
+    assert len(e.value.exceptions) == 2
+    assert obj.c == 2.0
+
+    class F(Ators, frozen=True):
+        a: int
+
+    with pytest.raises(TypeError, match="frozen"):
+        update_validated(F(a=1), {"a": 2})
+
+
+def test_update_validated_raises_base_exceptions():
+    def interrupt(value):
+        if value:
+            raise KeyboardInterrupt()
+
+    class A(Ators):
+        a: int = 0
+        b: int = member(default=0).append_value_validator(
+            ValueValidator.CallValue(interrupt)
+        )
+
+    obj = A()
+    with pytest.raises(KeyboardInterrupt):
+        update_validated(obj, {"a": "x", "b": 1})
+
+
+def test_set_instance_validator():
+    class Slider(Ators):
+        maximum: int = 10
+        value: int = 0
+
+    s = Slider()
+    other = Slider()
+    set_instance_validator(
+        s,
+        "value",
+        Validator(
+            TypeValidator.Any(),
+            value_validators=(ValueValidator.Range(max=s.maximum),),
+        ),
+    )
+    s.value = 10
+    with pytest.raises(ValueError) as e:
+        s.value = 11
+    assert "out of range" in str(e.value.__cause__)
+    assert s.value == 10
+    other.value = 11
+
+    # Overriding another member keeps the existing overrides.
+    set_instance_validator(
+        s,
+        "maximum",
+        Validator(
+            TypeValidator.Any(), value_validators=(ValueValidator.Range(min=0),)
+        ),
+    )
+    with pytest.raises(ValueError):
+        s.value = 11
+    with pytest.raises(ValueError):
+        s.maximum = -1
+
+    # The member validator still runs after the instance one.
+    with pytest.raises(TypeError):
+        s.value = "a"
+
+    # Passing None removes the override.
+    set_instance_validator(s, "value", None)
+    set_instance_validator(s, "value", None)
+    s.value = 11
+    assert s.value == 11
+
+    with pytest.raises(AttributeError, match="Slider has no member 'v'"):
+        set_instance_validator(s, "v", None)
+
+    class F(Ators, frozen=True):
+        a: int = 0
+
+    with pytest.raises(TypeError, match="frozen"):
+        set_instance_validator(F(), "a", Validator(TypeValidator.Int()))
+
+
+def test_as_mapping():
+    class A(Ators):
+        a: int
+        b: str = "b"
+
+        def method(self):
+            pass
+
+    obj = A(a=1)
+    view = obj.as_mapping()
+    assert view["a"] == 1
+    assert view["b"] == "b"
+    assert len(view) == 2
+    assert sorted(view) == ["a", "b"]
+    assert "a" in view and "method" not in view
+
+    view["a"] = 2
+    assert obj.a == 2
+    with pytest.raises(TypeError):
+        view["a"] = "x"
+    assert obj.a == 2
+
+    for key in ("method", "c", 1):
+        with pytest.raises(KeyError):
+            view[key]
+        with pytest.raises(KeyError):
+            view[key] = 1
+
+    assert dict(view) == {"a": 2, "b": "b"}
+    assert dict(**view) == {"a": 2, "b": "b"}
+
+
+class Doc:
+    """Minimal stand-in for PEP 727 typing_extensions.Doc."""
+
+    def __init__(self, documentation):
+        self.documentation = documentation
+
+
+def test_annotated_doc():
+    class A(Ators):
+        a: Annotated[int, Doc("The a value."), "other"]
+        b: Final[Annotated[int, Doc("The b value.")]] = 1
+        c: int = member()
+
+    class B(A):
+        pass
+
+    assert A.a.__doc__ == "The a value."
+    assert A.b.__doc__ == "The b value."
+    assert A.c.__doc__ is None
+    assert B.a.__doc__ == "The a value."
+
+    with pytest.raises(TypeError) as e:
+
+        class C(Ators):
+            a: Annotated[int, Doc("a"), Doc("b")]
+
+    assert "multiple Doc" in str(e.value.__cause__)
+
+    with pytest.raises(TypeError) as e:
+
+        class D(Ators):
+            a: Annotated[int, Doc(1)]
+
+    assert "str documentation" in str(e.value.__cause__)
+
+
+def test_annotated_typing_extensions_doc():
+    te = pytest.importorskip("typing_extensions")
+    if not hasattr(te, "Doc"):
+        pytest.skip("typing_extensions does not provide Doc")
+
+    class A(Ators):
+        a: Annotated[int, te.Doc("The a value.")]
+
+    assert A.a.__doc__ == "The a value."
+
+
+def test_member_metadata_is_read_only():
+    class A(Ators):
+        a = member().tag(t=1)
+        b = member()
+
+    assert A.a.metadata == {"t": 1}
+    assert A.b.metadata is None
+    with pytest.raises(TypeError):
+        A.a.metadata["t"] = 2
+    with pytest.raises(TypeError):
+        del A.a.metadata["t"]
+    assert A.a.metadata["t"] == 1
+
+
+def test_get_members_by_tag_and_value_exact():
+    class Uncomparable:
+        def __eq__(self, other):
+            raise RuntimeError("Cannot compare")
+
+    class A(Ators):
+        a = member().tag(t=1)
+        b = member().tag(t=True)
+        c = member().tag(t=1.0)
+
+    assert sorted(get_members_by_tag_and_value(A, "t", 1)) == ["a", "b", "c"]
+    assert list(get_members_by_tag_and_value(A, "t", 1, exact=True)) == ["a"]
+    assert list(get_members_by_tag_and_value(A, "t", True, exact=True)) == ["b"]
+
+    class B(Ators):
+        a = member().tag(t=Uncomparable())
+
+    with pytest.raises(RuntimeError):
+        get_members_by_tag_and_value(B, "t", 1)
+
+
 def test_get_member_on_non_ators_class_reports_clear_error():
     with pytest.raises(TypeError, match="Expected an Ators class or instance, got int"):
         get_member(int, "imag")
@@ -105,6 +504,35 @@ def test_get_member_on_non_ators_instance_reports_clear_error():
         get_member("foo", "upper")
 
 
+def test_unknown_member_error_types():
+    class A(Ators, observable=True):
+        a: int = member().tag(t=1)
+
+    a = A(a=1)
+    # Functions looking up a member by name behave like attribute access.
+    for lookup in (
+        lambda: get_member(A, "b"),
+        lambda: check_value(A, "b", 1),
+        lambda: set_unchecked(a, "b", 1),
+        lambda: update_validated(a, {"b": 1}),
+        lambda: observe(a, "b", print),
+    ):
+        with pytest.raises(AttributeError) as e:
+            lookup()
+        assert "A has no member" in str(e.value)
+
+    # Mappings behave like dict.
+    for mapping in (get_members(A), a.as_mapping()):
+        for key in ("b", 1):
+            with pytest.raises(KeyError):
+                mapping[key]
+            assert key not in mapping
+
+    # Tag filters return an empty dict.
+    assert get_members_by_tag(A, "u") == {}
+    assert get_members_by_tag_and_value(A, "t", 2) == {}
+
+
 def test_member_init_subclass():
     class A(Ators):
         a = member().constant()
@@ -173,3 +601,232 @@ def test_class_info_is_removed_when_class_is_collected():
     gc.collect()
     assert w() is None
     assert get_tracked_class_info_size() == before
+
+
+def test_track_instances():
+    class A(Ators, enable_weakrefs=True, track_instances=True):
+        a: int = member(default=0)
+
+    class B(A):
+        pass
+
+    a1, a2, b = A(), A(a=1), B()
+    assert set(map(id, A.instances())) == {id(a1), id(a2), id(b)}
+    assert B.instances() == [b]
+
+    del a1
+    gc.collect()
+    assert set(map(id, A.instances())) == {id(a2), id(b)}
+
+
+def test_track_instances_requires_weakrefs():
+    with pytest.raises(TypeError, match="enable_weakrefs"):
+
+        class A(Ators, track_instances=True):
+            pass
+
+
+def test_compatible_non_ators_bases():
+    class Empty:
+        __slots__ = ()
+
+        def hello(self):
+            return "hello"
+
+    class WeakRefable:
+        __slots__ = ("__weakref__",)
+
+    class Plain:
+        pass
+
+    class A(Ators, Empty):
+        a: int = 1
+
+    class B(WeakRefable, Ators):
+        a: int = 1
+
+    class C(Ators, Plain):
+        a: int = 1
+
+    assert A().hello() == "hello"
+    b = B()
+    assert weakref.ref(b)() is b
+    c = C()
+    c.extra = 1
+    assert c.a == 1
+
+
+class _Slotted:
+    __slots__ = ("x", "__weakref__")
+
+
+class _SlottedChild(_Slotted):
+    pass
+
+
+@pytest.mark.parametrize(
+    "base, match",
+    [
+        (_Slotted, "_Slotted defines the slots 'x'"),
+        (_SlottedChild, "_Slotted defines the slots 'x'"),
+        (type("_One", (), {"__slots__": "y"}), "_One defines the slots 'y'"),
+        (dict, "dict has an instance layout of its own"),
+        (Exception, "Exception has an instance layout of its own"),
+    ],
+)
+def test_incompatible_non_ators_bases(base, match):
+    with pytest.raises(TypeError, match=match):
+
+        class A(Ators, base):
+            pass
+
+    with pytest.raises(TypeError, match=match):
+
+        class B(base, Ators):
+            pass
+
+
+def test_instances_requires_tracking():
+    class A(Ators):
+        pass
+
+    with pytest.raises(TypeError, match="track_instances"):
+        A.instances()
+
+
+class TrackedInstance(Ators, enable_weakrefs=True, track_instances=True):
+    a: int
+
+
+def test_track_instances_unpickled():
+    import pickle
+
+    b = pickle.loads(pickle.dumps(TrackedInstance(a=1)))
+    assert any(o is b for o in TrackedInstance.instances())
+
+
+class PlainBase:
+    a: int
+    b: str = "b"
+
+
+def test_plain_base_annotations_ignored_by_default():
+    class A(Ators, PlainBase):
+        pass
+
+    assert set(get_members(A)) == set()
+
+
+def test_plain_base_members():
+    class A(Ators, PlainBase, plain_base_members=True):
+        c: float = 1.0
+
+    assert set(get_members(A)) == {"a", "b", "c"}
+    a = A(a=1)
+    assert (a.a, a.b, a.c) == (1, "b", 1.0)
+    with pytest.raises(TypeError):
+        a.a = "1"  # type: ignore[invalid-assignment]
+
+    class B(A):
+        d: int = 0
+
+    assert set(get_members(B)) == {"a", "b", "c", "d"}
+    assert B(a=2).b == "b"
+
+
+def test_plain_base_members_precedence():
+    class A(Ators, PlainBase, plain_base_members=True):
+        b: bytes = b"b"
+
+    assert A(a=1).b == b"b"
+
+    class B(Ators):
+        a: str = ""
+
+    class C(B, PlainBase, plain_base_members=True):
+        pass
+
+    assert C().a == ""
+    assert get_member(C, "a") is get_member(B, "a")
+
+
+def test_strict_body():
+    from typing import ClassVar
+
+    class A(Ators, strict_body=True):
+        """Docstring."""
+
+        a: int
+        b = member()
+        c: ClassVar[int] = 1
+
+        def meth(self):
+            pass
+
+        @property
+        def prop(self):
+            return 1
+
+        @staticmethod
+        def smeth():
+            pass
+
+    assert A(a=1, b=2).prop == 1
+
+    with pytest.raises(TypeError, match="'tpyo'"):
+
+        class B(Ators, strict_body=True):
+            a: int
+            tpyo = 1
+
+    class C(Ators):
+        a: int
+        extra = 1
+
+    assert C.extra == 1
+
+
+def test_dump_slots():
+    class A(Ators):
+        a: int
+        b: str = "b"
+
+    class B(A):
+        c: float
+
+    obj = B(a=1, c=2.0)
+    slots = _dump_slots(obj)
+    assert len(slots) == 3
+    for name in ("a", "c"):
+        assert slots[get_member(B, name).slot_index] == getattr(obj, name)
+    assert slots[get_member(B, "b").slot_index] is None
+    obj.b
+    assert _dump_slots(obj)[get_member(B, "b").slot_index] == "b"
+
+
+@pytest.mark.parametrize("observable", [False, True])
+def test_slot_indexes_within_bounds_deep_inheritance(observable):
+    class Base(Ators, observable=observable):
+        m0: int = 0
+
+    classes = [Base]
+    for i in range(1, 10):
+        # Redefine a member of the base in some subclasses to reshuffle indexes.
+        namespace = {"__annotations__": {f"m{i}": int}, f"m{i}": i}
+        if i % 3 == 0:
+            namespace["__annotations__"]["m0"] = int
+            namespace["m0"] = -i
+        classes.append(type(f"C{i}", (classes[-1],), namespace))
+
+    for cls in classes:
+        obj = cls()
+        slots = _dump_slots(obj)
+        members = [get_member(cls, name) for name in get_members(cls)]
+        indexes = [m.slot_index for m in members]
+        assert len(set(indexes)) == len(indexes)
+        assert all(0 <= i < len(slots) for i in indexes)
+        assert len(slots) == len(indexes) + observable
+        # Materializing the defaults writes each value in its own slot.
+        for m in members:
+            value = getattr(obj, m.name)
+            assert _dump_slots(obj)[m.slot_index] == value
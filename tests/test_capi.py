@@ -0,0 +1,51 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2025-2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Test the C API exposed to native extensions through a capsule."""
+
+import ctypes
+from typing import Any
+
+import pytest
+
+from ators import Ators, AtorsValidationError, Member, get_member, member
+from ators import _ators
+
+ValidateFn = ctypes.PYFUNCTYPE(
+    ctypes.py_object, ctypes.py_object, ctypes.py_object, ctypes.py_object
+)
+
+
+class CApi(ctypes.Structure):
+    _fields_ = [("version", ctypes.c_uint32), ("validate", ValidateFn)]
+
+
+def _get_api():
+    get_pointer = ctypes.pythonapi.PyCapsule_GetPointer
+    get_pointer.restype = ctypes.c_void_p
+    get_pointer.argtypes = [ctypes.py_object, ctypes.c_char_p]
+    return CApi.from_address(get_pointer(_ators._C_API, b"ators._ators._C_API"))
+
+
+def test_capi_validate():
+    api = _get_api()
+    assert api.version == 1
+
+    class A(Ators):
+        a: int
+        b: Member[float, Any] = member(init=False).coerce()
+
+    a = A(a=1)
+    assert api.validate(get_member(A, "a"), None, 2) == 2
+    # Coercion applies and the object is not modified.
+    assert api.validate(get_member(A, "b"), a, "2.5") == 2.5
+    assert _ators._dump_slots(a)[get_member(A, "b").slot_index] is None
+
+    with pytest.raises(AtorsValidationError):
+        api.validate(get_member(A, "a"), a, "x")
+    with pytest.raises(TypeError):
+        api.validate(1, None, 2)
@@ -0,0 +1,109 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2025-2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Test class members storing validated values on the class."""
+
+from typing import ClassVar
+
+import pytest
+
+from ators import Ators, ClassMember, class_member, get_members, member
+
+
+def test_class_member_access():
+    class A(Ators):
+        x: ClassVar[int] = class_member(default=1)
+        a: int = member()
+
+    assert isinstance(A.__dict__["x"], ClassMember)
+    assert A.x == 1
+    assert "x" not in get_members(A)
+
+    a = A(a=2)
+    assert a.x == 1
+
+    A.x = 3
+    assert A.x == 3
+    assert a.x == 3
+
+
+def test_class_member_validation():
+    class A(Ators):
+        x: ClassVar[int] = class_member(default=1)
+
+    with pytest.raises(TypeError) as e:
+        A.x = "a"
+    assert "class member 'x'" in str(e.value)
+    assert A.x == 1
+
+
+def test_class_member_invalid_default():
+    with pytest.raises(TypeError) as e:
+
+        class A(Ators):
+            x: ClassVar[int] = class_member(default="a")
+
+    assert "Invalid default value for class member 'x'" in str(e.value)
+
+
+def test_class_member_without_default():
+    class A(Ators):
+        x: ClassVar[int] = class_member()
+
+    with pytest.raises(AttributeError):
+        A.x
+    A.x = 2
+    assert A.x == 2
+
+
+def test_class_member_instance_access():
+    class A(Ators):
+        x: ClassVar[int] = class_member(default=1)
+
+    a = A()
+    with pytest.raises(AttributeError):
+        a.x = 2
+    with pytest.raises(AttributeError):
+        del a.x
+
+
+def test_class_member_shared_with_subclass():
+    class A(Ators):
+        x: ClassVar[int] = class_member(default=1)
+
+    class B(A):
+        pass
+
+    B.x = 2
+    assert A.x == 2
+
+    with pytest.raises(TypeError):
+        B.x = "a"
+
+
+def test_unannotated_class_member():
+    class A(Ators):
+        x = class_member(default=1)
+
+    assert isinstance(A.__dict__["x"], ClassMember)
+    A.x = "a"
+    assert A.x == "a"
+
+
+def test_previous_value_dropped_outside_lock():
+    seen = []
+
+    class A(Ators):
+        x = class_member(default=None)
+
+    class Probe:
+        def __del__(self):
+            seen.append(A.x)
+
+    A.x = Probe()
+    A.x = 2
+    assert seen == [2]
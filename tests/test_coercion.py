@@ -7,12 +7,28 @@
 # --------------------------------------------------------------------------------------
 """Test coercion behavior for ators object"""
 
-from typing import Any
+from decimal import Decimal
+from types import MappingProxyType
+from typing import Annotated, Any, Dict, NotRequired, TypedDict
 
 import pytest
 
-from ators import Ators, Member, member
-from ators.behaviors import Coercer, coerce, coerce_init
+from ators import (
+    Ators,
+    AtorsCoercionError,
+    AtorsValidationError,
+    Member,
+    codec_view,
+    coercing_members,
+    init_coercing_members,
+    member,
+)
+from ators.behaviors import Coerce, Coercer, ValueValidator, coerce, coerce_init
+
+
+class MyTypedDict(TypedDict):
+    a: int
+    b: NotRequired[str]
 
 
 @pytest.mark.parametrize(
@@ -30,12 +46,35 @@ from ators.behaviors import Coercer, coerce, coerce_init
         # bool uses Python's bool(...) semantics (non-empty strings => True)
         (bool, False, ["False", ""], [True, False]),
         (bool, True, ["False", ""], [True, TypeError("")]),
-        # str: int -> "1", bytes -> "b'...'"
-        (str, False, [1, b"abc"], ["1", "b'abc'"]),
+        # str: int -> "1", bytes are rejected unless an encoding is provided
+        (str, False, [1, b"abc"], ["1", TypeError("")]),
         (str, True, [1, b"abc"], ["1", TypeError("")]),
         # complex: string or complex -> complex object
         (complex, False, ["1+2j", 3 + 4j], [complex("1+2j"), complex(3 + 4j)]),
         (complex, True, ["1+2j", "3 + 4j"], [complex("1+2j"), TypeError("")]),
+        # Decimal: str or int -> Decimal, floats are rejected
+        (
+            Decimal,
+            False,
+            ["1.10", 2, 0.1, "a"],
+            [Decimal("1.10"), Decimal(2), TypeError(""), TypeError("")],
+        ),
+        (Decimal, True, ["1.10", "2"], [Decimal("1.10"), TypeError("")]),
+        # bytearray: bytes or iterable of ints, str and int are rejected
+        (
+            bytearray,
+            False,
+            [b"ab", [1, 2], "ab", 2],
+            [bytearray(b"ab"), bytearray(b"\x01\x02"), TypeError(""), TypeError("")],
+        ),
+        (bytearray, True, [b"ab", b"cd"], [bytearray(b"ab"), TypeError("")]),
+        # memoryview: any object supporting the buffer protocol
+        (
+            memoryview,
+            False,
+            [b"ab", bytearray(b"cd"), 1],
+            [b"ab", b"cd", TypeError("")],
+        ),
         # fixed-length tuple: sequence coerced and items coerced
         (tuple[int, int], False, [["1", "2"], (3, 4)], [(1, 2), (3, 4)]),
         (tuple[int, int], True, [["1", "2"], (3, "4")], [(1, 2), TypeError("")]),
@@ -63,6 +102,17 @@ from ators.behaviors import Coercer, coerce, coerce_init
             [{1: "2", "3": 4}, [(5, "6")]],
             [{"1": 2, "3": 4}, TypeError("")],
         ),
+        # untyped dict coercion from mapping and iterable-of-pairs
+        (
+            Dict,
+            False,
+            [{1: 2}, [(3, 4)], MappingProxyType({5: 6}), [(1, 2, 3)]],
+            [{1: 2}, {3: 4}, {5: 6}, TypeError("")],
+        ),
+        # TypedDict: values of known keys are coerced
+        (MyTypedDict, False, [{"a": "1"}, {"a": 2, "b": 3}], [{"a": 1}, {"a": 2, "b": "3"}]),
+        (MyTypedDict, True, [{"a": "1"}, {"a": "2"}], [{"a": 1}, TypeError("")]),
+        (MyTypedDict, False, [{"a": 1}, {"b": "1"}], [{"a": 1}, TypeError("")]),
         # Union: first matching member is used
         (int | complex, False, ["1", "1j", "a"], [1, 1j, TypeError("")]),
         (int | complex, True, ["1j", "a"], [1j, TypeError("")]),
@@ -89,6 +139,504 @@ def test_type_inferred_coercion(ty, init, inputs, expected):
             assert a.a == exp
 
 
+def test_decimal_coercion_rejects_floats():
+    class A(Ators):
+        a: Member[Decimal, Any] = member(default=Decimal(0)).coerce()
+
+    with pytest.raises(AtorsCoercionError) as e:
+        A().a = 0.1
+    assert "Cannot coerce the float 0.1 to Decimal" in str(e.value.__cause__)
+    assert "Decimal(str(value))" in str(e.value.__cause__)
+
+
+def test_decimal_coercion_rejects_non_numeric_strings():
+    class A(Ators):
+        a: Member[Decimal, Any] = member(default=Decimal(0)).coerce()
+
+    with pytest.raises(AtorsCoercionError) as e:
+        A().a = "abc"
+    assert "Cannot coerce 'abc' to Decimal" in str(e.value.__cause__)
+
+
+def test_bytearray_coercion_from_bytes_default():
+    class A(Ators):
+        a: Annotated[bytearray, Coerce] = b"ab"
+        b: bytearray = member().coerce_init()
+
+    a = A()
+    assert a.a == bytearray(b"ab")
+    assert isinstance(a.a, bytearray)
+    # Each object gets its own bytearray built from the immutable default.
+    a.a.append(ord("c"))
+    assert A().a == bytearray(b"ab")
+
+    a = A(b=b"ef")
+    assert isinstance(a.b, bytearray)
+    assert a.b == bytearray(b"ef")
+
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = "ab"
+    assert "encode it to bytes first" in str(e.value.__cause__)
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = 3
+    assert "Cannot coerce the int 3 to bytearray" in str(e.value.__cause__)
+
+
+def test_bytes_and_bytearray_errors_are_distinguished():
+    class A(Ators):
+        a: bytes = b""
+        b: bytearray = member(default_factory=lambda: bytearray())
+
+    a = A()
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = bytearray(b"a")
+    assert "bytearray is mutable and hence not accepted" in str(e.value.__cause__)
+    with pytest.raises(AtorsValidationError) as e:
+        a.b = b"a"
+    assert "bytes is immutable and hence not accepted" in str(e.value.__cause__)
+
+
+@pytest.mark.parametrize(
+    "coercer, inputs, expected",
+    [
+        (Coercer.ParseInt(base=16), ["ff", "0x1F", "-a"], [255, 31, -10]),
+        (Coercer.ParseInt(base=2), ["0b101", "11"], [5, 3]),
+        (Coercer.ParseInt(base=0), ["0x10", "0o10", "0b10", "10"], [16, 8, 2, 10]),
+        (Coercer.ParseInt(), ["12", " 7 "], [12, 7]),
+    ],
+)
+def test_parse_int_coercion(coercer, inputs, expected):
+    class A(Ators):
+        a: Member[int, Any] = member().coerce(coercer)
+
+    a = A()
+    for inp, exp in zip(inputs, expected):
+        a.a = inp
+        assert a.a == exp
+
+
+@pytest.mark.parametrize(
+    "coercer, value, message",
+    [
+        (Coercer.ParseInt(base=16), "0xZZ", "Cannot parse '0xZZ' as an int in base 16"),
+        (Coercer.ParseInt(base=2), "102", "Cannot parse '102' as an int in base 2"),
+        (Coercer.ParseInt(base=16), 1.5, "ParseInt expects a str, got 1.5"),
+    ],
+)
+def test_parse_int_coercion_failure(coercer, value, message):
+    class A(Ators):
+        a: Member[int, Any] = member().coerce(coercer)
+
+    a = A()
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = value
+    assert message in str(e.value.__cause__)
+
+
+@pytest.mark.parametrize(
+    "coercer, inputs, expected",
+    [
+        (Coercer.ParseFloat(), ["1.5", "1e3", "-inf"], [1.5, 1000.0, float("-inf")]),
+        (
+            Coercer.ParseFloat(thousands_separator=","),
+            ["1,234.5", "1,000,000"],
+            [1234.5, 1000000.0],
+        ),
+        (
+            Coercer.ParseFloat(thousands_separator=" ", scientific=False),
+            ["1 234.5", "Infinity"],
+            [1234.5, float("inf")],
+        ),
+    ],
+)
+def test_parse_float_coercion(coercer, inputs, expected):
+    class A(Ators):
+        a: Member[float, Any] = member().coerce(coercer)
+
+    a = A()
+    for inp, exp in zip(inputs, expected):
+        a.a = inp
+        assert a.a == exp
+
+
+@pytest.mark.parametrize(
+    "coercer, value, message",
+    [
+        (Coercer.ParseFloat(), "1,5", "Cannot parse '1,5' as a float"),
+        (
+            Coercer.ParseFloat(scientific=False),
+            "1e3",
+            "scientific notation is not allowed",
+        ),
+        (Coercer.ParseFloat(), b"1.5", "ParseFloat expects a str"),
+    ],
+)
+def test_parse_float_coercion_failure(coercer, value, message):
+    class A(Ators):
+        a: Member[float, Any] = member().coerce(coercer)
+
+    a = A()
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = value
+    assert message in str(e.value.__cause__)
+
+
+def test_parse_coercers_equality():
+    assert Coercer.ParseInt(base=16) == Coercer.ParseInt(base=16)
+    assert Coercer.ParseInt(base=16) != Coercer.ParseInt(base=8)
+    assert Coercer.ParseFloat(thousands_separator=",") == Coercer.ParseFloat(
+        thousands_separator=","
+    )
+    assert Coercer.ParseFloat() != Coercer.ParseFloat(scientific=False)
+
+
+@pytest.mark.parametrize("base", [1, 37])
+def test_parse_int_invalid_base(base):
+    with pytest.raises(ValueError) as e:
+        Coercer.ParseInt(base=base)
+    assert "base of 0 or between 2 and 36" in str(e.value)
+
+
+def test_parse_int_base_attribute():
+    assert Coercer.ParseInt().base == 10
+    assert Coercer.ParseInt(base=0).base == 0
+
+
+@pytest.mark.parametrize(
+    "coercer, inputs, expected",
+    [
+        (Coercer.NullLike(), ["", None, 1], [None, None, 1]),
+        (Coercer.NullLike(values=("", "N/A")), ["N/A", ""], [None, None]),
+        (
+            Coercer.NullLike(coercer=Coercer.TypeInferred()),
+            ["", "12", 3.0],
+            [None, 12, 3],
+        ),
+        (Coercer.NullLike(coercer=Coercer.ParseInt(base=16)), ["", "ff"], [None, 255]),
+    ],
+)
+def test_null_like_coercion(coercer, inputs, expected):
+    class A(Ators):
+        a: Member[int | None, Any] = member().coerce(coercer)
+
+    a = A()
+    for inp, exp in zip(inputs, expected):
+        a.a = inp
+        assert a.a == exp
+
+
+def test_null_like_coercion_failure():
+    class A(Ators):
+        a: Member[int | None, Any] = member().coerce(Coercer.NullLike())
+        b: Member[int, Any] = member().coerce(Coercer.NullLike())
+
+    a = A()
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = "12"
+    assert "'12' is not a null-like value" in str(e.value.__cause__)
+
+    with pytest.raises(AtorsCoercionError):
+        a.b = ""
+
+
+def test_null_like_coercer_equality():
+    assert Coercer.NullLike() == Coercer.NullLike()
+    assert Coercer.NullLike(values=("",)) != Coercer.NullLike()
+    assert Coercer.NullLike(coercer=Coercer.ParseInt()) == Coercer.NullLike(
+        coercer=Coercer.ParseInt()
+    )
+    assert Coercer.NullLike(coercer=Coercer.ParseInt()) != Coercer.NullLike(
+        coercer=Coercer.ParseInt(base=16)
+    )
+
+
+def _strip(value):
+    return value.strip()
+
+
+def _clamp(value):
+    if value > 100:
+        raise ValueError(f"{value} is out of range")
+    return value
+
+
+def test_chain_coercion():
+    class A(Ators):
+        a: Member[int, Any] = member().coerce(
+            Coercer.Chain(Coercer.CallValue(_strip), Coercer.ParseInt(base=16))
+        )
+
+    a = A()
+    a.a = " ff "
+    assert a.a == 255
+
+
+def test_chain_coercion_failure_reports_stage():
+    class A(Ators):
+        a: Member[int, Any] = member().coerce(
+            Coercer.Chain(
+                Coercer.CallValue(_strip),
+                Coercer.ParseInt(),
+                Coercer.CallValue(_clamp),
+            )
+        )
+
+    a = A()
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = 1.5
+    stage_err = e.value.__cause__
+    assert "Stage 0 (CallValue) of the coercion chain failed for 1.5" in str(stage_err)
+    assert isinstance(stage_err.__cause__, AttributeError)
+
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = " 1a "
+    stage_err = e.value.__cause__
+    assert "Stage 1 (ParseInt) of the coercion chain failed for '1a'" in str(
+        stage_err
+    )
+    assert "Cannot parse '1a' as an int in base 10" in str(stage_err.__cause__)
+    assert isinstance(stage_err.__cause__.__cause__, ValueError)
+
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = " 101 "
+    stage_err = e.value.__cause__
+    assert "Stage 2 (CallValue) of the coercion chain failed for 101" in str(
+        stage_err
+    )
+    assert "101 is out of range" in str(stage_err.__cause__)
+
+
+def test_custom_coercer_container_revalidation():
+    class A(Ators):
+        a: Member[list[int], Any] = member(init=False).coerce(
+            Coercer.CallValue(lambda v: set(v))
+        )
+        b: Member[list[int], Any] = member(init=False).coerce(
+            Coercer.CallValue(lambda v: list(v))
+        )
+        c: Member[dict[str, int], Any] = member(init=False).coerce(
+            Coercer.CallValue(lambda v: {k: str(v) for k, v in v})
+        )
+
+    a = A()
+    a.b = (1, 2)
+    assert a.b == [1, 2]
+    # The coerced list was validated and hence validates its items.
+    with pytest.raises(TypeError):
+        a.b.append("x")
+
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = (1, 2)
+    assert "CallValue coercer returned a value of type set" in str(e.value.__cause__)
+    assert "expects a list" in str(e.value.__cause__.__cause__)
+
+    with pytest.raises(AtorsCoercionError) as e:
+        a.c = [("a", 1)]
+    assert "returned a value of type dict" in str(e.value.__cause__)
+
+
+def test_coerce_strict():
+    class A(Ators):
+        a: Member[int, Any] = (
+            member(coerce_strict=True)
+            .coerce(Coercer.TypeInferred())
+            .append_value_validator(ValueValidator.Values(frozenset({1, 2})))
+        )
+        b: Member[int, Any] = member(init=False, coerce_strict=True).coerce(
+            Coercer.CallValue(lambda v: v.strip())
+        )
+        c: Member[int, Any] = (
+            member(init=False)
+            .coerce(Coercer.TypeInferred())
+            .append_value_validator(ValueValidator.Values(frozenset({1, 2})))
+        )
+
+    assert A.a.validator.coerce_strict
+    assert not A.c.validator.coerce_strict
+
+    a = A(a="1")
+    assert a.a == 1
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = "3"
+    assert "The value 3 produced by the TypeInferred coercer" in str(e.value.__cause__)
+    assert isinstance(e.value.__cause__.__cause__, AtorsValidationError)
+    with pytest.raises(AtorsCoercionError):
+        A(a="3")
+
+    with pytest.raises(AtorsCoercionError) as e:
+        a.b = " 1 "
+    assert "The value '1' produced by the CallValue coercer" in str(
+        e.value.__cause__
+    )
+
+    # By default coerced values are trusted.
+    a.c = "3"
+    assert a.c == 3
+
+
+def test_coerce_strict_is_inherited():
+    class A(Ators):
+        a: Member[int, Any] = member(coerce_strict=True).coerce(
+            Coercer.CallValue(lambda v: str(v))
+        )
+
+    class B(A):
+        a = member().inherit()
+
+    assert B.a.validator.coerce_strict
+    b = B(a=1)
+    with pytest.raises(AtorsCoercionError) as e:
+        b.a = 1.5
+    assert "produced by the CallValue coercer" in str(e.value.__cause__)
+
+
+def test_coercing_members():
+    class A(Ators):
+        a: Member[int, Any] = member().coerce()
+        b: int = member().coerce_init()
+        c: Annotated[int, Coerce]
+        d: int
+
+    class B(A):
+        e: Member[str, Any] = member().coerce(Coercer.CallValue(lambda v: str(v)))
+
+    assert coercing_members(A) == {"a", "c"}
+    assert init_coercing_members(A) == {"b"}
+    assert coercing_members(B) == {"a", "c", "e"}
+    assert coercing_members(B(a=1, b=1, c=1, d=1, e="")) == {"a", "c", "e"}
+    assert init_coercing_members(B) == {"b"}
+    assert coercing_members(Ators) == set()
+
+
+def test_chain_coercer_equality():
+    assert Coercer.Chain(Coercer.ParseInt()) == Coercer.Chain(Coercer.ParseInt())
+    assert Coercer.Chain(Coercer.ParseInt()) != Coercer.Chain(
+        Coercer.ParseInt(), Coercer.ParseInt()
+    )
+    assert Coercer.Chain(Coercer.ParseInt()) != Coercer.Chain(
+        Coercer.ParseInt(base=16)
+    )
+
+
+@pytest.mark.parametrize("ty", [Dict, dict[str, int]])
+def test_dict_coercion_from_non_pair_element(ty):
+    class A(Ators):
+        a: Member[ty, Any] = member().coerce()
+
+    a = A(a={})
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = [("3", 4), 5]
+    assert "element 1 (5) is not a key/value pair" in str(e.value.__cause__)
+
+
+@pytest.mark.parametrize(
+    "ty, value, expected",
+    [
+        (str, "é".encode(), "é"),
+        (list[str], [b"a", "b"], ["a", "b"]),
+        (int | str, "é".encode(), "é"),
+        (bytes, "é", "é".encode()),
+        (list[bytes], ["a", b"b"], [b"a", b"b"]),
+    ],
+)
+def test_type_inferred_bytes_encoding_coercion(ty, value, expected):
+    class A(Ators):
+        a: Member[ty, Any] = member().coerce(
+            Coercer.TypeInferred(bytes_encoding="utf-8")
+        )
+
+    a = A()
+    a.a = value
+    assert a.a == expected
+
+
+@pytest.mark.parametrize(
+    "ty, value, message",
+    [
+        (str, b"\xff", "Cannot decode b'\\xff' using the 'ascii' codec"),
+        (bytes, "é", "Cannot encode 'é' using the 'ascii' codec"),
+    ],
+)
+def test_type_inferred_bytes_encoding_coercion_failure(ty, value, message):
+    class A(Ators):
+        a: Member[ty, Any] = member().coerce(
+            Coercer.TypeInferred(bytes_encoding="ascii")
+        )
+
+    a = A()
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = value
+    assert message in str(e.value.__cause__)
+
+
+def test_str_to_bytes_coercion_requires_encoding():
+    class A(Ators):
+        a: Member[bytes, Any] = member().coerce()
+
+    a = A()
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = "a"
+    assert "explicit encoding" in str(e.value.__cause__)
+
+
+@pytest.mark.parametrize("ty", [str, list[str]])
+def test_str_coercion_rejects_none(ty):
+    class A(Ators):
+        a: Member[ty, Any] = member().coerce()
+
+    a = A()
+    value = [None] if ty == list[str] else None
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = value
+    assert "Cannot coerce None to str" in str(e.value.__cause__)
+
+    class B(Ators):
+        a: Member[int | str, Any] = member().coerce()
+
+    with pytest.raises(AtorsCoercionError):
+        B().a = None
+
+
+def test_str_coercion_none_to_str():
+    class A(Ators):
+        a: Member[str, Any] = member().coerce(Coercer.TypeInferred(none_to_str=True))
+        b: Member[str | None, Any] = member().coerce()
+
+    a = A()
+    a.a = None
+    assert a.a == "None"
+    a.b = None
+    assert a.b is None
+    assert Coercer.TypeInferred() != Coercer.TypeInferred(none_to_str=True)
+
+
+def test_codec_view():
+    class A(Ators):
+        data: Member[bytes, Any] = member().coerce(
+            Coercer.TypeInferred(bytes_encoding="utf-8")
+        )
+        text = codec_view("data", "utf-8")
+        raw = codec_view("text_member", "latin-1")
+        text_member: str = member(default="")
+
+    a = A(data="é")
+    assert a.data == "é".encode()
+    assert a.text == "é"
+    a.text = "ü"
+    assert a.data == "ü".encode()
+    assert isinstance(A.text, codec_view)
+
+    a.raw = b"\xe9"
+    assert a.text_member == "é"
+    assert a.raw == b"\xe9"
+
+    a.data = b"\xff"
+    with pytest.raises(UnicodeDecodeError, match="utf-8"):
+        a.text
+    with pytest.raises(TypeError, match="text expects a str or bytes"):
+        a.text = 1
+
+
 @pytest.mark.parametrize(
     "init, inputs, called, expected",
     [
@@ -397,3 +945,167 @@ def test_member_annotation_coerce_init_valid():
 
     a = A(x="7")
     assert a.x == 7
+
+
+def test_annotated_coercer():
+    """A Coercer in Annotated metadata sets the coercer of the member."""
+
+    class A(Ators):
+        x: Annotated[int, "doc", Coercer.TypeInferred()]
+        y: Annotated[int, Coercer.CallValue(lambda v: len(v))] = member(default=0)
+
+    a = A(x=1)
+    a.x = "42"
+    assert a.x == 42
+    a.y = "abc"
+    assert a.y == 3
+    with pytest.raises(AtorsCoercionError):
+        a.x = "a"
+
+
+def test_annotated_coerce_marker():
+    """The Coerce marker enables the type inferred coercion of a member."""
+
+    class A(Ators):
+        x: Annotated[int, Coerce] = 0
+        y: Annotated[list[float], "doc", Coerce] = member(default_factory=lambda: [])
+        z: int = 0
+
+    a = A(x="3")
+    assert a.x == 3
+    a.y = ["1.5", 2]
+    assert a.y == [1.5, 2.0]
+    with pytest.raises(TypeError):
+        a.z = "3"
+    assert Coerce == Coercer.TypeInferred()
+
+    with pytest.raises(TypeError) as e:
+
+        class B(Ators):
+            x: Annotated[int, Coerce, Coercer.CallValue(lambda v: v)]
+
+    assert "multiple coercers" in str(e.value.__cause__)
+
+
+def test_annotated_coercer_and_explicit_coerce_warns():
+    """An explicit coercer takes precedence over the one in Annotated metadata."""
+    with pytest.warns(UserWarning, match="coerce"):
+
+        class A(Ators):
+            x: Annotated[int, Coercer.TypeInferred()] = member().coerce(
+                Coercer.CallValue(lambda v: 1)
+            )
+
+    a = A()
+    a.x = "42"
+    assert a.x == 1
+
+
+def test_annotated_multiple_coercers_raises():
+    with pytest.raises(TypeError) as e:
+
+        class A(Ators):
+            x: Annotated[int, Coercer.TypeInferred(), Coercer.TypeInferred()]
+
+    assert "multiple coercers" in str(e.value.__cause__)
+
+
+def test_annotated_without_coercer_does_not_coerce():
+    class A(Ators):
+        x: Annotated[list[Annotated[int, "item"]], "doc"]
+
+    a = A(x=[1])
+    with pytest.raises(TypeError):
+        a.x = ["1"]
+
+
+def test_coercion_failure_raises_ators_coercion_error():
+    class A(Ators):
+        a: Member[int, Any] = member().coerce(Coercer.TypeInferred())
+        b: Member[str, Any] = member().coerce(Coercer.CallValue(lambda v: v.missing))
+
+    a = A()
+    with pytest.raises(AtorsCoercionError) as e:
+        a.a = "a"
+    # The original error is preserved and the builtin exceptions still match.
+    assert isinstance(e.value, (TypeError, ValueError))
+    assert isinstance(e.value.__cause__, AtorsCoercionError)
+    assert isinstance(e.value.__cause__.__cause__, ValueError)
+
+    with pytest.raises(AtorsCoercionError) as e:
+        a.b = 1
+    assert isinstance(e.value.__cause__.__cause__, AttributeError)
+    assert "missing" in e.value.__cause__.args[0]
+
+
+def test_on_error_replacement():
+    calls = []
+
+    def fallback(m, obj, value, error):
+        calls.append((m.name, value, type(error)))
+        return 0
+
+    class A(Ators):
+        a: int = member(default=1).on_error(fallback)
+
+    obj = A(a="x")
+    assert obj.a == 0
+    obj.a = 2
+    assert obj.a == 2
+    obj.a = "y"
+    assert obj.a == 0
+    assert [c[:2] for c in calls] == [("a", "x"), ("a", "y")]
+    assert all(issubclass(c[2], AtorsValidationError) for c in calls)
+
+
+def test_on_error_reraise_and_single_retry():
+    calls = []
+
+    def reraise(m, obj, value, error):
+        raise error
+
+    def invalid(m, obj, value, error):
+        calls.append(value)
+        return "still invalid"
+
+    class A(Ators):
+        a: int = member(default=1).on_error(reraise)
+        b: int = member(default=1).on_error(invalid)
+
+    obj = A()
+    with pytest.raises(AtorsValidationError):
+        obj.a = "x"
+    assert obj.a == 1
+
+    # The replacement is validated once without calling the callback again.
+    with pytest.raises(AtorsValidationError) as e:
+        obj.b = "x"
+    assert "on_error callback of member 'b'" in str(e.value)
+    assert calls == ["x"]
+    assert obj.b == 1
+
+    with pytest.raises(TypeError, match="on_error expects a callable"):
+        member().on_error(1)
+
+
+def test_on_error_retry_failure_is_a_validation_error():
+    class StrictError(ValueError):
+        def __init__(self, value, reason):
+            super().__init__(value, reason)
+
+    def check(value):
+        if value < 0:
+            raise StrictError(value, "negative")
+
+    class A(Ators):
+        a: int = (
+            member(default=1)
+            .append_value_validator(ValueValidator.CallValue(check))
+            .on_error(lambda m, obj, value, error: -1)
+        )
+
+    obj = A()
+    with pytest.raises(AtorsValidationError) as e:
+        obj.a = "x"
+    assert "on_error callback of member 'a'" in str(e.value)
+    assert isinstance(e.value.__cause__, StrictError)
@@ -30,14 +30,35 @@ def ators_list_object():
         ("append", ("e",), [1, 2, 3], TypeError),
         ("insert", (0, 0), [0, 1, 2, 3], None),
         ("insert", (0, "e"), [1, 2, 3], TypeError),
+        # Arbitrary indices behave as for list.insert
+        ("insert", (2, 0), [1, 2, 0, 3], None),
+        ("insert", (3, 0), [1, 2, 3, 0], None),
+        ("insert", (10, 0), [1, 2, 3, 0], None),
+        ("insert", (-1, 0), [1, 2, 0, 3], None),
+        ("insert", (-3, 0), [0, 1, 2, 3], None),
+        ("insert", (-10, 0), [0, 1, 2, 3], None),
+        ("insert", (-1, "e"), [1, 2, 3], TypeError),
         ("__setitem__", (0, 10), [10, 2, 3], None),
         ("__setitem__", (0, "e"), [1, 2, 3], TypeError),
         ("__setitem__", (-1, 10), [1, 2, 10], None),
         ("__setitem__", (3, 10), [1, 2, 3], IndexError),
         ("__setitem__", (slice(0, 2), [10, 20]), [10, 20, 3], None),
         ("__setitem__", (slice(0, 2), [10, "e"]), [1, 2, 3], TypeError),
+        # Contiguous slices can change the length of the list
+        ("__setitem__", (slice(1, 2), [10, 20, 30]), [1, 10, 20, 30, 3], None),
+        ("__setitem__", (slice(0, 3), (7,)), [7], None),
+        ("__setitem__", (slice(-2, None), [8, 9, 10]), [1, 8, 9, 10], None),
+        ("__setitem__", (slice(3, 3), [4]), [1, 2, 3, 4], None),
+        ("__setitem__", (slice(1, 2), [10, 20, "e"]), [1, 2, 3], TypeError),
+        ("__setitem__", (slice(0, 1), 5), [1, 2, 3], TypeError),
+        # Every item of an arbitrary iterable is validated
+        ("__setitem__", (slice(0, 2), iter([10, 20])), [10, 20, 3], None),
+        ("__setitem__", (slice(0, 2), iter([10, "e"])), [1, 2, 3], TypeError),
         # Extended slice (step != 1)
         ("__setitem__", (slice(0, 3, 2), [9, 10]), [9, 2, 10], None),
+        ("__setitem__", (slice(None, None, 2), [9, 10, 11]), [1, 2, 3], ValueError),
+        ("__setitem__", (slice(None, None, -1), (4, 5, 6)), [6, 5, 4], None),
+        ("__setitem__", (slice(-1, -4, -2), ["e", 9]), [1, 2, 3], TypeError),
         ("__setitem__", (slice(0, 3, 2), [9]), [1, 2, 3], ValueError),
         ("__setitem__", (slice(0, 3, 2), [9, "e"]), [1, 2, 3], TypeError),
         # Negative step
@@ -69,6 +90,10 @@ def ators_list_object():
         ("__delitem__", ("0",), [1, 2, 3], TypeError),
         ("reverse", (), [3, 2, 1], None),
         ("sort", (), [1, 2, 3], None),
+        # Calling __init__ again replaces the items
+        ("__init__", ([4, 5],), [4, 5], None),
+        ("__init__", ([4, "5"],), [1, 2, 3], TypeError),
+        ("__init__", (), [], None),
     ],
 )
 def test_list_container_validation(
@@ -163,6 +188,10 @@ def ators_dict_object():
         ("__ior__", ({"a": 3},), {}, {"a": 3}, None),
         ("__ior__", ({1: 3},), {}, {"a": 2}, TypeError),
         ("__ior__", ({"a": "3"},), {}, {"a": 2}, TypeError),
+        # Calling __init__ again replaces the items
+        ("__init__", ({"b": 3},), {}, {"b": 3}, None),
+        ("__init__", ({"b": "3"},), {}, {"a": 2}, TypeError),
+        ("__init__", None, {"b": 3}, {"b": 3}, None),
     ],
 )
 def test_dict_container_validation(
@@ -176,6 +205,51 @@ def test_dict_container_validation(
     assert ators_dict_object.a == expected
 
 
+def test_dict_container_validation_messages(ators_dict_object):
+    from ators import AtorsValidationError
+
+    d = ators_dict_object.a
+    with pytest.raises(AtorsValidationError) as e:
+        d[1] = 3
+    assert str(e.value).startswith("Failed to validate key '1' for the member a of")
+    assert "expects a str" in str(e.value.__cause__)
+
+    with pytest.raises(AtorsValidationError) as e:
+        d["b"] = "3"
+    assert str(e.value).startswith(
+        "Failed to validate value ''3'' with key ''b'' for the member a of"
+    )
+    assert "expects a int" in str(e.value.__cause__)
+
+    with pytest.raises(AtorsValidationError, match="Failed to validate value"):
+        d.setdefault("c", "3")
+    with pytest.raises(AtorsValidationError, match="Failed to validate key"):
+        d.update({1: 3})
+    assert d == {"a": 2}
+
+
+def test_set_reinit_validation(ators_set_object):
+    s = ators_set_object.a
+    with pytest.raises(TypeError):
+        s.__init__(None, {4, "5"})
+    assert s == {1, 2, 3}
+    s.__init__(None, {4, 5})
+    assert s == {4, 5}
+
+
+def test_container_base_class_methods_bypass_validation(
+    ators_list_object, ators_set_object, ators_dict_object
+):
+    # Calling the methods of the builtin base class directly is not validated
+    # (see the documentation of containers).
+    list.append(ators_list_object.a, "e")
+    assert ators_list_object.a[-1] == "e"
+    set.add(ators_set_object.a, "e")
+    assert "e" in ators_set_object.a
+    dict.__setitem__(ators_dict_object.a, "b", "e")
+    assert ators_dict_object.a["b"] == "e"
+
+
 def test_list_same_owner_member_reassignment_copies_container():
     from ators import Ators
 
@@ -208,6 +282,64 @@ def test_set_same_owner_member_reassignment_copies_container():
     assert type(obj.a) is type(original)
 
 
+def test_set_assignment_always_wraps_while_frozenset_is_preserved():
+    from ators import Ators
+
+    class A(Ators):
+        s: set[int]
+        f: frozenset[int]
+
+    value = {1, 2}
+    fvalue = frozenset({1, 2})
+    obj = A(s=value, f=fvalue)
+
+    # Mutable sets are copied so that later mutations are validated
+    assert obj.s == value
+    assert obj.s is not value
+    # Frozensets cannot be mutated and are kept as is when valid
+    assert obj.f is fvalue
+
+
+def test_frozenset_items_changed_by_validation():
+    from ators._ators import AtorsSet
+    from ators.validators import Coercer, TypeValidator, Validator
+
+    item = Validator(TypeValidator.Int(), coercer=Coercer.TypeInferred())
+    s = AtorsSet(Validator(TypeValidator.FrozenSet(item)))
+    # 0 is always iterated first so the str items are coerced after an item
+    # that was left untouched.
+    s.add(frozenset({0, "1", "2", 3}))
+    assert s == {frozenset({0, 1, 2, 3})}
+
+    unchanged = frozenset({0, 1})
+    s.add(unchanged)
+    assert any(v is unchanged for v in s)
+
+
+def test_standalone_set_construction():
+    from ators._ators import AtorsSet
+    from ators.validators import Coercer, TypeValidator, Validator
+
+    s = AtorsSet(Validator(TypeValidator.Int()), [1, 2, 3])
+    assert s == {1, 2, 3}
+    assert isinstance(s, set)
+
+    s.add(4)
+    assert s == {1, 2, 3, 4}
+    with pytest.raises(TypeError):
+        s.add("e")
+    assert s == {1, 2, 3, 4}
+
+    assert AtorsSet(Validator(TypeValidator.Int())) == set()
+    with pytest.raises(TypeError):
+        AtorsSet(Validator(TypeValidator.Int()), [1, "e"])
+
+    coercing = AtorsSet(
+        Validator(TypeValidator.Int(), coercer=Coercer.TypeInferred()), ["1", 2]
+    )
+    assert coercing == {1, 2}
+
+
 def test_dict_same_owner_member_reassignment_copies_container():
     from ators import Ators
 
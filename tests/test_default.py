@@ -7,10 +7,12 @@
 # --------------------------------------------------------------------------------------
 """Test default behavior for ators object"""
 
+from typing import Any
+
 import pytest
 
-from ators import Ators, Member, member
-from ators.behaviors import Default, default
+from ators import UNSET, Ators, Member, get_member, iter_member_values, member
+from ators.behaviors import Default, ValueValidator, default
 
 
 def test_no_default():
@@ -74,6 +76,50 @@ def test_static_dict_default():
     assert a.a is not default  # Ensure a copy is made
 
 
+def test_invalid_static_default_is_rejected_at_class_creation():
+    with pytest.raises(TypeError, match="Invalid default value for member 'a'") as e:
+
+        class A(Ators):
+            a: list[int] = member(default=[1, "bad", 3])
+
+    assert "item 1" in str(e.value.__cause__)
+
+    with pytest.raises(TypeError, match="Invalid default value for member 'a'"):
+
+        class B(Ators):
+            a: int = "bad"  # type: ignore
+
+
+def test_static_default_runs_the_full_validator():
+    with pytest.raises(ValueError, match="Invalid default value for member 'a'"):
+
+        class A(Ators):
+            a: int = member(default=12).append_value_validator(
+                ValueValidator.Range(max=10)
+            )
+
+    class B(Ators):
+        a: Member[int, Any] = member(default="3").coerce()
+        b: int = member(default=1).append_value_validator(
+            ValueValidator.ObjectMethod("_check")
+        )
+
+        def _check(self, name, value):
+            raise ValueError("checked on access")
+
+    assert B().a == 3
+    with pytest.raises(ValueError):
+        B().b
+
+
+def test_factory_default_is_validated_lazily():
+    class A(Ators):
+        a: list[int] = member(default_factory=lambda: [1, "bad"])
+
+    with pytest.raises(TypeError):
+        A().a
+
+
 def test_call_default():
     i = 0
 
@@ -207,6 +253,41 @@ def test_bad_signature_of_method():
     assert "Method signature for 'default'" in e.exconly()
 
 
+def test_bad_signature_of_object_method():
+    with pytest.raises(TypeError) as e:
+
+        class A(Ators):
+            a: int = member().default(Default.ObjectMethod("f"))
+
+            def f(self):
+                return 1
+
+    assert "incompatible with the expected signature (self, member)" in e.exconly()
+
+
+def test_bad_signature_of_inherited_object_method():
+    class A(Ators):
+        def f(self):
+            return 1
+
+    with pytest.raises(TypeError) as e:
+
+        class B(A):
+            a: int = member().default(Default.ObjectMethod("f"))
+
+    assert "incompatible with the expected signature (self, member)" in e.exconly()
+
+
+def test_object_method_with_compatible_signature():
+    class A(Ators):
+        a: int = member().default(Default.ObjectMethod("f"))
+
+        def f(self, *args, extra=1):
+            return extra
+
+    assert A().a == 1
+
+
 def test_warn_on_multiple_setting_of_default():
     with pytest.warns(UserWarning):
 
@@ -330,3 +411,126 @@ def test_member_ctor_default_factory_inherits():
         a = member().inherit()
 
     assert B().a == 55
+
+
+def test_ellipsis_marks_member_without_default():
+    class A(Ators):
+        a: int = ...  # type: ignore[assignment]
+
+    a = A()  # type: ignore[missing-argument]
+    with pytest.raises(TypeError) as e:
+        a.a
+    assert "value is unset and has no default" in e.value.__cause__.args[0]
+
+    assert A(a=1).a == 1
+
+
+def test_dependent_defaults():
+    class A(Ators):
+        a: int = 1
+        b: int = member().default(Default.CallMemberObject(lambda m, o: o.a + 1))
+        c: int = member()
+
+        @default(c)
+        def _default_c(self, member):
+            return self.b * 2
+
+    assert A().c == 4
+    assert A(a=2).c == 6
+
+
+def test_default_dependency_cycle_is_reported():
+    class A(Ators):
+        a: int = member().default(Default.CallMemberObject(lambda m, o: o.b))
+        b: int = member().default(Default.CallMemberObject(lambda m, o: o.c))
+        c: int = member().default(Default.CallMemberObject(lambda m, o: o.a))
+        d: int = 1
+
+    a = A()
+    with pytest.raises(RuntimeError) as e:
+        a.a
+    cause = e.value
+    while cause.__cause__ is not None:
+        cause = cause.__cause__
+    assert "a -> b -> c -> a" in str(cause)
+    # The failed computation leaves no trace
+    assert a.d == 1
+    a.c = 3
+    assert a.a == 3
+
+
+def test_default_not_materialized():
+    calls = 0
+
+    def factory():
+        nonlocal calls
+        calls += 1
+        return calls
+
+    class A(Ators):
+        a: int = member(materialize_default=False).default(Default.Call(factory))
+        b: int = member(default=1, materialize_default=False)
+        c: int = member().default(Default.Call(factory))
+
+    class B(A):
+        a: int = member().inherit()
+
+    assert get_member(A, "a").materialize_default is False
+    assert get_member(A, "c").materialize_default is True
+    assert get_member(B, "a").materialize_default is False
+
+    obj = A()
+    assert obj.a == 1
+    assert obj.a == 2
+    assert obj.b == 1
+    values = dict((n, v) for n, _, v in iter_member_values(obj))
+    assert values["a"] is UNSET and values["b"] is UNSET
+
+    # Materialized defaults are computed once
+    assert obj.c == 3
+    assert obj.c == 3
+
+    obj.a = 5
+    assert obj.a == 5
+    del obj.a
+    assert obj.a == 4
+
+
+def test_env_var_default(monkeypatch):
+    class A(Ators):
+        port: int = member().default(Default.EnvVar("ATORS_TEST_PORT", int, 80))
+        host: str = member().default(Default.EnvVar("ATORS_TEST_HOST"))
+        level: int | None = member().default(
+            Default.EnvVar("ATORS_TEST_LEVEL", default=None)
+        )
+        workers: Member[int, Any] = member().coerce().default(
+            Default.EnvVar("ATORS_TEST_WORKERS")
+        )
+
+    for name in ("PORT", "HOST", "LEVEL", "WORKERS"):
+        monkeypatch.delenv(f"ATORS_TEST_{name}", raising=False)
+
+    # The fallback default is used when the variable is not set.
+    assert A().port == 80
+    assert A().level is None
+    with pytest.raises(KeyError) as e:
+        A().host
+    assert "'ATORS_TEST_HOST' is not set" in str(e.value.__cause__)
+
+    # The environment is read when the default is computed, not at class creation.
+    monkeypatch.setenv("ATORS_TEST_PORT", "8080")
+    monkeypatch.setenv("ATORS_TEST_HOST", "localhost")
+    monkeypatch.setenv("ATORS_TEST_WORKERS", "4")
+    a = A()
+    assert (a.port, a.host, a.workers) == (8080, "localhost", 4)
+
+    monkeypatch.setenv("ATORS_TEST_PORT", "http")
+    with pytest.raises(ValueError) as e:
+        A().port
+    assert "Failed to parse the value 'http'" in str(e.value.__cause__)
+    assert isinstance(e.value.__cause__.__cause__, ValueError)
+
+    # Without parser the raw string goes through the validation of the member.
+    monkeypatch.setenv("ATORS_TEST_LEVEL", "3")
+    with pytest.raises(TypeError):
+        A().level
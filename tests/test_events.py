@@ -163,7 +163,7 @@ def test_event_set_valid_notifies():
         clicked: Event[int]
 
     a = A()
-    observe(a, "clicked", lambda c: hits.append(c.newvalue))
+    observe(a, "clicked", lambda c: hits.append(c.newvalue), strong=True)
     a.clicked = 42
     assert hits == [42]
 
@@ -189,7 +189,7 @@ def test_event_set_invalid_does_not_notify():
         clicked: Event[int]
 
     a = A()
-    observe(a, "clicked", lambda c: hits.append(c.newvalue))
+    observe(a, "clicked", lambda c: hits.append(c.newvalue), strong=True)
     with pytest.raises((TypeError, ValueError)):
         a.clicked = "not an int"
     assert hits == []
@@ -203,7 +203,7 @@ def test_event_each_valid_set_notifies():
         clicked: Event[int]
 
     a = A()
-    observe(a, "clicked", lambda c: hits.append(c.newvalue))
+    observe(a, "clicked", lambda c: hits.append(c.newvalue), strong=True)
     a.clicked = 1
     a.clicked = 1  # same value — still notifies
     a.clicked = 1
@@ -232,7 +232,7 @@ def test_event_notification_disabled_silently_skips():
 
     a = A()
     hits = []
-    observe(a, "clicked", lambda c: hits.append(c.newvalue))
+    observe(a, "clicked", lambda c: hits.append(c.newvalue), strong=True)
 
     disable_notifications(a)
     # Setting an event while notifications are disabled must NOT raise.
@@ -272,7 +272,7 @@ def test_event_inherited_by_subclass():
     assert isinstance(Child.clicked, Event)
     c = Child()
     hits = []
-    observe(c, "clicked", lambda ch: hits.append(ch.newvalue))
+    observe(c, "clicked", lambda ch: hits.append(ch.newvalue), strong=True)
     c.clicked = 3
     assert hits == [3]
 
@@ -402,7 +402,7 @@ def test_observe_event_works():
         clicked: Event[int]
 
     a = A()
-    observe(a, "clicked", lambda c: hits.append(c.newvalue))
+    observe(a, "clicked", lambda c: hits.append(c.newvalue), strong=True)
     a.clicked = 10
     assert hits == [10]
 
@@ -638,7 +638,7 @@ def test_event_builder_append_value_validator():
 
     a = A()
     hits = []
-    observe(a, "clicked", lambda c: hits.append(c.newvalue))
+    observe(a, "clicked", lambda c: hits.append(c.newvalue), strong=True)
 
     a.clicked = 2
     assert hits == [2]
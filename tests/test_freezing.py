@@ -49,7 +49,7 @@ def test_freezing(frozen, should_work):
 
     with pytest.raises(TypeError) as e:
         a.a = 1
-    assert "Cannot modify" in e.exconly()
+    assert "Cannot set member 'a' on frozen" in e.exconly()
 
 
 class ForwardFrozenA(Ators, frozen=True):
@@ -83,6 +83,26 @@ def test_frozen_class_custom_init_without_super_still_freezes():
         a.a = 2
 
 
+def test_frozen_class_is_mutable_during_init():
+    class A(Ators, frozen=True):
+        a: int
+        b: tuple[int, ...] = member(init=False)
+
+        def __init__(self, a: int):
+            super().__init__(a=a)
+            self.a += 1
+            self.b = (self.a,)
+            assert not is_frozen(self)
+
+    a = A(1)
+    assert (a.a, a.b) == (2, (2,))
+    assert is_frozen(a)
+    with pytest.raises(TypeError, match="frozen"):
+        a.a = 3
+    with pytest.raises(TypeError, match="frozen"):
+        a.b = ()
+
+
 class ForwardMutableA(Ators):
     a: LateMutableB
 
@@ -334,4 +354,96 @@ def test_frozen_object_is_immutable():
 
     with pytest.raises(TypeError) as e:
         a.a = 2
-    assert "Cannot modify" in e.exconly()
+    assert "Cannot set member 'a' on frozen" in e.exconly()
+
+
+def test_frozen_error_names_the_member():
+    """The error raised when setting a member of a frozen object names it."""
+
+    class A(Ators):
+        a: int
+        b: str
+
+    a = A(a=1, b="b")
+    freeze(a)
+
+    # Frozen takes precedence over validation errors.
+    with pytest.raises(TypeError) as e:
+        a.b = 1
+    assert f"Cannot set member 'b' on frozen {a!r}." in e.exconly()
+    assert a.b == "b"
+
+
+class LazyContainers(Ators):
+    a: IntList = member(default_factory=lambda: [1])
+    b: IntSet = member(default_factory=lambda: {1})
+    c: IntDict = member(default_factory=lambda: {1: 1})
+
+
+IntList = list[int]
+IntSet = set[int]
+IntDict = dict[int, int]
+
+
+@pytest.mark.parametrize(
+    "member_name, operation, args",
+    [
+        ("a", "append", (2,)),
+        ("a", "extend", ([2],)),
+        ("a", "insert", (0, 2)),
+        ("a", "__setitem__", (0, 2)),
+        ("a", "__delitem__", (0,)),
+        ("a", "__iadd__", ([2],)),
+        ("a", "__imul__", (2,)),
+        ("a", "pop", ()),
+        ("a", "remove", (1,)),
+        ("a", "clear", ()),
+        ("a", "sort", ()),
+        ("a", "reverse", ()),
+        ("b", "add", (2,)),
+        ("b", "update", ({2},)),
+        ("b", "__ior__", ({2},)),
+        ("b", "__ixor__", ({2},)),
+        ("b", "__isub__", ({1},)),
+        ("b", "__iand__", ({1},)),
+        ("b", "difference_update", ({1},)),
+        ("b", "intersection_update", ({1},)),
+        ("b", "symmetric_difference_update", ({2},)),
+        ("b", "remove", (1,)),
+        ("b", "discard", (1,)),
+        ("b", "pop", ()),
+        ("b", "clear", ()),
+        ("c", "__setitem__", (2, 2)),
+        ("c", "__delitem__", (1,)),
+        ("c", "update", ({2: 2},)),
+        ("c", "__ior__", ({2: 2},)),
+        ("c", "setdefault", (2, 2)),
+        ("c", "pop", (1,)),
+        ("c", "popitem", ()),
+        ("c", "clear", ()),
+    ],
+)
+def test_containers_of_frozen_object_are_immutable(member_name, operation, args):
+    """Test that containers owned by a frozen object reject in place mutations"""
+    obj = LazyContainers()
+    freeze(obj)
+    container = getattr(obj, member_name)
+    before = container.copy()
+
+    with pytest.raises(TypeError) as e:
+        getattr(container, operation)(*args)
+    assert "frozen" in e.exconly()
+    assert container == before
+
+
+def test_containers_of_unfrozen_object_are_mutable():
+    obj = LazyContainers()
+    obj.a.pop()
+    obj.a *= 2
+    obj.b -= {1}
+    obj.b &= {2}
+    obj.c.popitem()
+    assert obj.a == [] and obj.b == set() and obj.c == {}
+
+    with pytest.raises(TypeError):
+        obj.b -= [1]  # type: ignore
@@ -7,9 +7,12 @@
 # --------------------------------------------------------------------------------------
 """Unit tests for init support in member and AtorsMeta."""
 
+from typing import Final
+
 import pytest
 
 from ators import Ators, get_members, member
+from ators._ators import get_ators_init_member_names
 
 
 def test_public_member_init_default_true():
@@ -105,3 +108,142 @@ def test_subclass_can_override_init_flag():
     assert b.x == 1
     with pytest.raises(TypeError, match="non-init member"):
         Child(x=1)
+
+
+def test_constant_members_are_not_init_members():
+    """Constant members are excluded from init while read-only ones are not."""
+
+    class A(Ators):
+        c: Final[int] = member(default=1).constant()
+        r: Final[int] = 2
+        n: int = 0
+
+    assert sorted(get_ators_init_member_names(A)) == ["n", "r"]
+    assert not get_members(A)["c"].init
+
+    a = A(r=3, n=4)
+    assert (a.c, a.r, a.n) == (1, 3, 4)
+    with pytest.raises(TypeError, match="non-init member"):
+        A(c=5)
+
+    # Read-only members can be assigned once, through init or afterwards.
+    with pytest.raises(TypeError):
+        a.r = 5
+    b = A()
+    b.r = 5
+    assert b.r == 5
+
+
+def test_constant_member_explicit_init_true():
+    """Explicitly requesting a constant member at init is an error."""
+
+    with pytest.raises(TypeError, match="constant"):
+
+        class A(Ators):
+            c: Final[int] = member(default=1, init=True).constant()
+
+
+def test_post_init_sees_all_members():
+    """__post_init__ is called after all members, including defaults, are set."""
+    seen = []
+
+    class A(Ators):
+        a: int
+        b: int = 2
+        c: int = member(init=False, default=3)
+
+        def __post_init__(self):
+            seen.append((self.a, self.b, self.c))
+
+    A(a=1)
+    A(a=1, b=5)
+    assert seen == [(1, 2, 3), (1, 5, 3)]
+
+
+def test_post_init_called_without_kwargs():
+    """__post_init__ is called even when no keyword argument is passed."""
+    calls = []
+
+    class A(Ators):
+        a: int = 1
+
+        def __post_init__(self):
+            calls.append(self.a)
+
+    A()
+    assert calls == [1]
+
+
+def test_post_init_can_abort_construction():
+    """Raising in __post_init__ propagates out of the class call."""
+
+    class A(Ators):
+        low: int
+        high: int
+
+        def __post_init__(self):
+            if self.low > self.high:
+                raise ValueError("low > high")
+
+    A(low=1, high=2)
+    with pytest.raises(ValueError, match="low > high"):
+        A(low=2, high=1)
+
+
+def test_post_init_runs_before_freezing():
+    """__post_init__ can set members of frozen classes before they are frozen."""
+
+    class A(Ators, frozen=True):
+        low: int
+        high: int
+        span: int = member(init=False)
+
+        def __post_init__(self):
+            self.span = self.high - self.low
+
+    a = A(low=1, high=4)
+    assert a.span == 3
+    with pytest.raises(TypeError):
+        a.span = 2
+
+
+def test_member_aliases_at_init():
+    """Aliases can be used in place of the member name at init and in __replace__."""
+
+    class A(Ators):
+        user_id: int = member().alias("user-id", "userId")
+        name: str = "x"
+
+    assert get_members(A)["user_id"].metadata == {"aliases": ("user-id", "userId")}
+    assert A(**{"user-id": 1}).user_id == 1
+    assert A(userId=2, name="y").user_id == 2
+    assert A(user_id=3).user_id == 3
+    assert A(userId=2).__replace__(**{"user-id": 4}).user_id == 4
+
+    with pytest.raises(TypeError, match="multiple values for member 'user_id'"):
+        A(**{"user-id": 1, "user_id": 2})
+    with pytest.raises(TypeError, match="multiple values for member 'user_id'"):
+        A(**{"user-id": 1, "userId": 2})
+    with pytest.raises(TypeError, match="Missing required"):
+        A(name="y")
+
+
+def test_member_alias_collisions():
+    """Aliases colliding with a member name or another alias are rejected."""
+
+    with pytest.raises(TypeError, match="is the name of another member"):
+
+        class A(Ators):
+            a: int = member().alias("b")
+            b: int = 1
+
+    with pytest.raises(TypeError, match="used by both members 'a' and 'b'"):
+
+        class B(Ators):
+            a: int = member().alias("c")
+            b: int = member().alias("c")
+
+    with pytest.raises(TypeError, match="must be str"):
+        member().alias(1)
+    with pytest.raises(ValueError, match="cannot be empty"):
+        member().alias("")
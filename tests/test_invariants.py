@@ -0,0 +1,144 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Test invariants spanning several members."""
+
+import pytest
+
+from ators import Ators, AtorsValidationError, member
+from ators._ators import _dump_slots
+from ators.behaviors import invariant
+
+
+class Interval(Ators):
+    start: int
+    end: int
+    label: str = ""
+
+    @invariant("start", "end")
+    def _ordered(self):
+        return self.start <= self.end
+
+
+def test_invariant_checked_at_init():
+    Interval(start=1, end=2)
+    with pytest.raises(
+        AtorsValidationError, match="Invariant '_ordered' .* is violated"
+    ):
+        Interval(start=3, end=2)
+
+
+def test_invariant_checked_after_set():
+    i = Interval(start=1, end=5)
+    i.start = 5
+    with pytest.raises(AtorsValidationError, match="violated after setting 'start'"):
+        i.start = 6
+    # The change violating the invariant is reverted.
+    assert i.start == 5
+    with pytest.raises(ValueError, match="after setting 'end'"):
+        i.end = 4
+    assert i.end == 5
+
+    # Members not involved in the invariant do not trigger it.
+    i.label = "a"
+
+
+def test_raising_invariant():
+    class A(Ators):
+        a: int = 0
+        b: int = 1
+
+        @invariant("a", "b")
+        def _check(self):
+            if self.a == self.b:
+                raise RuntimeError("a and b must differ")
+
+    obj = A()
+    with pytest.raises(AtorsValidationError, match="Invariant '_check' .* failed") as e:
+        obj.a = 1
+    assert isinstance(e.value.__cause__, RuntimeError)
+    assert obj.a == 0
+
+
+def test_invariant_base_exceptions_propagate():
+    class A(Ators):
+        a: int = 0
+
+        @invariant("a")
+        def _check(self):
+            if self.a:
+                raise KeyboardInterrupt()
+
+    obj = A()
+    with pytest.raises(KeyboardInterrupt):
+        obj.a = 1
+    assert obj.a == 0
+
+
+def test_invariant_restores_unset_value():
+    class A(Ators):
+        a: int = member(init=False)
+
+        @invariant("a")
+        def _positive(self):
+            try:
+                return self.a > 0
+            except TypeError:
+                # The member is unset until explicitly assigned.
+                return True
+
+    obj = A()
+    with pytest.raises(ValueError):
+        obj.a = -1
+    assert _dump_slots(obj)[A.a.slot_index] is None
+    obj.a = 1
+    assert obj.a == 1
+
+
+def test_invariant_during_post_init():
+    class A(Ators):
+        start: int
+        end: int = member(init=False)
+
+        def __post_init__(self):
+            # Intermediate states are not checked during initialization.
+            self.end = self.start - 1
+            self.end = self.start + 1
+
+        @invariant("start", "end")
+        def _ordered(self):
+            return self.start <= self.end
+
+    assert A(start=1).end == 2
+
+
+def test_inherited_and_overridden_invariants():
+    class B(Interval):
+        pass
+
+    with pytest.raises(ValueError):
+        B(start=3, end=2)
+
+    class C(Interval):
+        def _ordered(self):
+            return True
+
+    C(start=3, end=2)
+
+
+def test_invariant_unknown_member():
+    with pytest.raises(TypeError, match="member\\(s\\) c which do not exist"):
+
+        class A(Ators):
+            a: int
+
+            @invariant("a", "c")
+            def _check(self):
+                return True
+
+    with pytest.raises(TypeError, match="member names"):
+        invariant()
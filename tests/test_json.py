@@ -0,0 +1,208 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Test the JSON serialization of Ators objects."""
+
+import datetime
+import enum
+import json
+import uuid
+from decimal import Decimal
+
+import pytest
+
+from ators import (
+    Ators,
+    AtorsValidationError,
+    from_json,
+    is_frozen,
+    member,
+    register_json_codec,
+    to_json,
+)
+
+
+class Color(enum.Enum):
+    RED = "red"
+    BLUE = "blue"
+
+
+class Point(Ators):
+    x: int
+    y: int = 0
+
+
+class Shape(Ators):
+    name: str
+    color: Color = Color.RED
+    points: list[Point] = member(default_factory=lambda: [])
+    origin: Point | None = None
+    tags: set[str] = member(default_factory=lambda: set())
+    size: tuple[float, float] = (1.0, 1.0)
+    weights: dict[int, float] = member(default_factory=lambda: {})
+    created: datetime.datetime = datetime.datetime(2026, 1, 2, 3, 4, 5)
+    price: Decimal = Decimal("1.10")
+    uid: uuid.UUID = uuid.UUID(int=1)
+    raw: bytes = b"\x00\x01"
+
+
+def test_round_trip():
+    s = Shape(
+        name="tri",
+        color=Color.BLUE,
+        points=[Point(x=1), Point(x=2, y=3)],
+        origin=Point(x=0),
+        tags={"a"},
+        size=(2.0, 3.5),
+        weights={1: 0.5},
+    )
+    s.created = s.created
+    s.price = s.price
+    s.uid = s.uid
+    s.raw = s.raw
+
+    data = json.loads(to_json(s))
+    assert data["color"] == "blue"
+    assert data["points"] == [{"x": 1}, {"x": 2, "y": 3}]
+    assert data["weights"] == {"1": 0.5}
+    assert data["created"] == "2026-01-02T03:04:05"
+    assert data["price"] == "1.10"
+    assert data["uid"] == uuid.UUID(int=1).hex
+    assert data["raw"] == "AAE="
+
+    t = from_json(Shape, to_json(s))
+    assert isinstance(t, Shape)
+    assert t.color is Color.BLUE
+    assert [(p.x, p.y) for p in t.points] == [(1, 0), (2, 3)]
+    assert t.origin.x == 0
+    assert t.tags == {"a"}
+    assert t.size == (2.0, 3.5)
+    assert t.weights == {1: 0.5}
+    assert t.created == s.created
+    assert t.price == Decimal("1.10")
+    assert t.uid == uuid.UUID(int=1)
+    assert t.raw == b"\x00\x01"
+
+
+def test_unset_members_are_omitted():
+    assert json.loads(to_json(Point(x=1))) == {"x": 1}
+    assert to_json(Point(x=1), indent=2) == '{\n  "x": 1\n}'
+
+
+def test_non_init_members_are_restored():
+    class A(Ators):
+        a: int = member(init=False, default=0)
+
+    obj = A()
+    obj.a = 2
+    assert from_json(A, to_json(obj)).a == 2
+
+
+def test_non_init_members_of_frozen_objects_are_restored():
+    class A(Ators, frozen=True):
+        a: int = member()
+        b: int = member(init=False, default=0)
+
+        def __init__(self, a):
+            super().__init__(a=a)
+            self.b = 2 * a
+
+    obj = from_json(A, to_json(A(3)))
+    assert (obj.a, obj.b) == (3, 6)
+    assert is_frozen(obj)
+
+
+def test_decoded_values_are_validated():
+    with pytest.raises(AtorsValidationError):
+        from_json(Point, '{"x": "a"}')
+    with pytest.raises(ValueError, match="member 'color' of Shape") as e:
+        from_json(Shape, '{"name": "a", "color": "green"}')
+    assert isinstance(e.value.__cause__, ValueError)
+    with pytest.raises(TypeError, match="Expected a JSON object"):
+        from_json(Point, "[1]")
+
+
+class Opaque:
+    def __init__(self, value):
+        self.value = value
+
+
+def test_unserializable_member():
+    class A(Ators):
+        a: list[object]
+
+    with pytest.raises(TypeError, match=r"member 'a\[1\]' of A .* for Opaque$"):
+        to_json(A(a=[1, Opaque(1)]))
+
+    class B(Ators):
+        a: A
+
+    with pytest.raises(TypeError, match=r"member 'a.a\[0\]' of B"):
+        to_json(B(a=A(a=[Opaque(1)])))
+
+
+def test_non_finite_floats_are_rejected():
+    class A(Ators):
+        a: float
+        b: list[float]
+
+    with pytest.raises(TypeError, match=r"member 'a' of A .* float nan"):
+        to_json(A(a=float("nan"), b=[]))
+
+    with pytest.raises(TypeError, match=r"member 'b\[0\]' of A .* float inf"):
+        to_json(A(a=1.0, b=[float("inf")]))
+
+
+def test_circular_reference():
+    class A(Ators):
+        a: list[object]
+
+    a = A(a=[])
+    a.a.append(a)
+    with pytest.raises(ValueError, match=r"Circular reference .* 'a\[0\]' of A"):
+        to_json(a)
+
+
+class Meters:
+    def __init__(self, value):
+        self.value = value
+
+
+def test_register_json_codec():
+    class A(Ators):
+        d: Meters
+
+    with pytest.raises(TypeError, match="no JSON codec is registered for"):
+        to_json(A(d=Meters(2)))
+
+    register_json_codec(Meters, lambda m: m.value, Meters)
+    assert to_json(A(d=Meters(2))) == '{"d": 2}'
+    assert from_json(A, '{"d": 3}').d.value == 3
+
+    with pytest.raises(TypeError, match="expects callables"):
+        register_json_codec(Meters, 1, Meters)
+
+
+def test_binary_buffers_round_trip():
+    class A(Ators):
+        a: bytearray
+        b: memoryview
+
+    data = to_json(A(a=bytearray(b"\x00\x01"), b=memoryview(b"\x02")))
+    assert json.loads(data) == {"a": "AAE=", "b": "Ag=="}
+    obj = from_json(A, data)
+    assert obj.a == bytearray(b"\x00\x01")
+    assert isinstance(obj.a, bytearray)
+    assert obj.b == b"\x02"
+    assert isinstance(obj.b, memoryview)
+
+
+def test_stdlib_modules_are_not_exported():
+    import ators
+
+    for name in ("base64", "datetime", "decimal", "operator", "uuid"):
+        assert not hasattr(ators, name)
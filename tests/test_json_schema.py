@@ -0,0 +1,157 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2025-2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Test the generation of JSON schema from Ators classes."""
+
+import enum
+from collections.abc import Mapping, Sequence
+from decimal import Decimal
+from typing import Literal
+
+import pytest
+
+from ators import Ators, json_schema, member
+from ators.validators import ValueValidator
+
+
+class Color(enum.Enum):
+    RED = "red"
+    BLUE = "blue"
+
+
+@pytest.mark.parametrize(
+    "ann, fragment",
+    [
+        (bool, {"type": "boolean"}),
+        (int, {"type": "integer"}),
+        (float, {"type": "number"}),
+        (str, {"type": "string"}),
+        (bytes, {"type": "string", "format": "binary"}),
+        (bytearray, {"type": "string", "format": "binary"}),
+        (memoryview, {"type": "string", "format": "binary"}),
+        (Decimal, {"type": "string", "format": "decimal"}),
+        (list[int], {"type": "array", "items": {"type": "integer"}}),
+        (
+            set[str],
+            {"type": "array", "items": {"type": "string"}, "uniqueItems": True},
+        ),
+        (
+            tuple[int, str],
+            {
+                "type": "array",
+                "prefixItems": [{"type": "integer"}, {"type": "string"}],
+                "minItems": 2,
+                "maxItems": 2,
+            },
+        ),
+        (tuple[float, ...], {"type": "array", "items": {"type": "number"}}),
+        (
+            dict[str, int],
+            {"type": "object", "additionalProperties": {"type": "integer"}},
+        ),
+        (Sequence[int], {"type": "array", "items": {"type": "integer"}}),
+        (
+            Mapping[str, int],
+            {"type": "object", "additionalProperties": {"type": "integer"}},
+        ),
+        (int | None, {"anyOf": [{"type": "integer"}, {"type": "null"}]}),
+        (Literal[1, 2], {"enum": [1, 2]}),
+        (Color, {"enum": ["blue", "red"]}),
+    ],
+)
+def test_json_schema_member_fragments(ann, fragment):
+    class A(Ators):
+        a: ann  # type: ignore
+
+    schema = json_schema(A)
+    prop = schema["properties"]["a"]
+    if "enum" in prop:
+        prop["enum"] = sorted(prop["enum"])
+    assert prop == fragment
+
+
+def test_json_schema_constraints():
+    class A(Ators):
+        a: int = member().append_value_validator(
+            ValueValidator.Range(min=0, max=10, inclusive=False)
+        )
+        b: str = member().append_value_validator(ValueValidator.Length(min=1))
+        c: list[int] = member().append_value_validator(ValueValidator.Length(max=3))
+        d: int = member().append_value_validator(ValueValidator.MultipleOf(5))
+        e: str = member().append_value_validator(ValueValidator.Regex("[a-z]+"))
+
+    props = json_schema(A)["properties"]
+    assert props["a"] == {
+        "type": "integer",
+        "exclusiveMinimum": 0,
+        "exclusiveMaximum": 10,
+    }
+    assert props["b"] == {"type": "string", "minLength": 1}
+    assert props["c"] == {"type": "array", "items": {"type": "integer"}, "maxItems": 3}
+    assert props["d"] == {"type": "integer", "multipleOf": 5}
+    assert props["e"] == {"type": "string", "pattern": "^(?:[a-z]+)$"}
+
+
+def test_json_schema_class_description():
+    class A(Ators):
+        b: str = member(default="s")
+        a: int
+
+    schema = json_schema(A)
+    assert schema["title"] == "A"
+    assert schema["type"] == "object"
+    assert list(schema["properties"]) == ["a", "b"]
+    assert schema["properties"]["b"]["default"] == "s"
+    assert schema["required"] == ["a"]
+
+
+def test_json_schema_from_instance():
+    class A(Ators):
+        a: int = member(default=1)
+
+    assert json_schema(A()) == json_schema(A)
+
+
+def test_json_schema_nested_ators_class():
+    class B(Ators):
+        x: int
+
+    class A(Ators):
+        b: B
+
+    assert json_schema(A)["properties"]["b"] == json_schema(B)
+
+
+class RecursiveA(Ators):
+    a: RecursiveA | None = member(default=None)
+
+
+def test_json_schema_recursive_class():
+    prop = json_schema(RecursiveA)["properties"]["a"]
+    assert prop["anyOf"][0]["title"] == "RecursiveA"
+    assert "$comment" in prop["anyOf"][0]
+
+
+def test_json_schema_unmappable_validators():
+    class Custom:
+        pass
+
+    class A(Ators):
+        a: Custom
+        b: int = member().append_value_validator(
+            ValueValidator.CallValue(lambda v: None)
+        )
+
+    schema = json_schema(A)
+    assert set(schema["properties"]["a"]) == {"$comment"}
+    assert schema["properties"]["b"]["type"] == "integer"
+    assert "callable" in schema["properties"]["b"]["$comment"]
+
+
+def test_json_schema_rejects_non_ators():
+    with pytest.raises(TypeError):
+        json_schema(int)
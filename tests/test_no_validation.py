@@ -0,0 +1,157 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2025-2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Test disabling validation through the no_validation context manager."""
+
+import asyncio
+import contextvars
+import threading
+
+import pytest
+
+from ators import Ators, member, no_validation, observe, set_unchecked
+from ators.behaviors import postset
+
+
+class A(Ators, observable=True):
+    a: int = member(default=0)
+    b: list[int] = member(default_factory=lambda: [])
+    calls: list[str] = member(default_factory=lambda: [])
+
+    @postset(a)
+    def _postset_a(self, m, o, n):
+        self.calls.append("post_set")
+
+
+def test_no_validation_skips_validation():
+    obj = A()
+    with no_validation():
+        obj.a = "not an int"  # type: ignore
+    assert obj.a == "not an int"
+
+    with pytest.raises(TypeError):
+        obj.a = "still not an int"  # type: ignore
+
+
+def test_no_validation_runs_behaviors_and_notifies():
+    obj = A()
+    changes = []
+    observe(obj, "a", changes.append)
+    with no_validation():
+        obj.a = "x"  # type: ignore
+    assert obj.calls == ["post_set"]
+    assert len(changes) == 1
+
+
+def test_no_validation_does_not_wrap_containers():
+    obj = A()
+    with no_validation():
+        obj.b = [1, "e"]  # type: ignore
+    assert type(obj.b) is list
+
+
+def test_no_validation_is_reset_on_error():
+    obj = A()
+    with pytest.raises(RuntimeError):
+        with no_validation():
+            raise RuntimeError()
+    with pytest.raises(TypeError):
+        obj.a = "x"  # type: ignore
+
+
+def test_nested_no_validation():
+    obj = A()
+    with no_validation():
+        with no_validation():
+            obj.a = "x"  # type: ignore
+        obj.a = "y"  # type: ignore
+    assert obj.a == "y"
+    with pytest.raises(TypeError):
+        obj.a = "z"  # type: ignore
+
+
+def test_reentering_the_same_no_validation():
+    obj = A()
+    nv = no_validation()
+    with nv:
+        with nv:
+            obj.a = "x"  # type: ignore
+        obj.a = "y"  # type: ignore
+    assert obj.a == "y"
+    with pytest.raises(TypeError):
+        obj.a = "z"  # type: ignore
+
+
+def test_copied_context_validates_once_no_validation_exits():
+    obj = A()
+    with no_validation():
+        ctx = contextvars.copy_context()
+        ctx.run(setattr, obj, "a", "x")
+    assert obj.a == "x"
+    with pytest.raises(TypeError):
+        ctx.run(setattr, obj, "a", "y")
+
+
+def test_no_validation_is_scoped_to_thread():
+    obj = A()
+    errors = []
+
+    def set_in_thread():
+        try:
+            obj.a = "x"  # type: ignore
+        except TypeError as e:
+            errors.append(e)
+
+    with no_validation():
+        t = threading.Thread(target=set_in_thread)
+        t.start()
+        t.join()
+    assert len(errors) == 1
+
+
+def test_no_validation_is_scoped_to_task():
+    obj = A()
+
+    async def validated():
+        await asyncio.sleep(0)
+        with pytest.raises(TypeError):
+            obj.a = "x"  # type: ignore
+
+    async def unvalidated():
+        with no_validation():
+            await asyncio.sleep(0)
+            obj.a = "y"  # type: ignore
+
+    async def main():
+        await asyncio.gather(unvalidated(), validated())
+
+    asyncio.run(main())
+    assert obj.a == "y"
+
+
+def test_set_unchecked():
+    obj = A()
+    changes = []
+    observe(obj, "a", changes.append)
+    set_unchecked(obj, "a", "x")
+    assert obj.a == "x"
+    # Neither behaviors nor observers are run.
+    assert obj.calls == []
+    assert changes == []
+
+    with pytest.raises(AttributeError, match="A has no member 'c'"):
+        set_unchecked(obj, "c", 1)
+
+
+    class F(Ators, frozen=True):
+        a: int = 0
+
+    f = F()
+    with pytest.raises(TypeError) as e:
+        set_unchecked(f, "a", 1)
+    assert f"Cannot set member 'a' on frozen {f!r}." in e.exconly()
+    assert f.a == 0
@@ -8,19 +8,25 @@
 """Test observer behavior for ators object"""
 
 import gc
+import weakref
 
 import pytest
 
 from ators import (
     Ators,
     AtorsChange,
+    ChangeEvent,
     disable_notifications,
     enable_notifications,
+    is_frozen,
     is_notifications_enabled,
     member,
+    notifications_enabled,
     observe,
+    suppress_notifications,
     unobserve,
 )
+from ators.behaviors import Observer, observer
 
 
 def test_observable_member_indexes_are_shifted():
@@ -103,6 +109,23 @@ def test_notification_controls_and_invariant():
         enable_notifications(a)
 
 
+@pytest.mark.parametrize("frozen", [False, True])
+def test_notification_controls_frozen_and_mutable(frozen):
+    class A(Ators, observable=True, frozen=frozen):
+        a: int = 0
+
+    obj = A()
+    assert is_frozen(obj) is frozen
+
+    # Toggling notifications does not modify the members and is hence allowed
+    # on frozen instances.
+    disable_notifications(obj)
+    assert is_notifications_enabled(obj) is False
+    enable_notifications(obj)
+    assert is_notifications_enabled(obj) is True
+    assert is_frozen(obj) is frozen
+
+
 def test_notifications_disabled_skip_callbacks():
     i = 0
 
@@ -125,6 +148,97 @@ def test_notifications_disabled_skip_callbacks():
     assert i == 1
 
 
+def test_notifications_enabled_context_manager():
+    calls = []
+
+    class A(Ators, observable=True):
+        a = member()
+
+    a = A()
+    observe(a, "a", calls.append)
+    disable_notifications(a)
+
+    with notifications_enabled(a) as obj:
+        assert obj is a
+        a.a = 1
+        with notifications_enabled(a):
+            a.a = 2
+        assert is_notifications_enabled(a)
+    assert len(calls) == 2
+    assert not is_notifications_enabled(a)
+
+    with pytest.raises(RuntimeError):
+        with notifications_enabled(a):
+            raise RuntimeError()
+    assert not is_notifications_enabled(a)
+
+    enable_notifications(a)
+    with notifications_enabled(a):
+        pass
+    assert is_notifications_enabled(a)
+
+    # The same context manager can be re-entered
+    disable_notifications(a)
+    cm = notifications_enabled(a)
+    with cm:
+        with cm:
+            pass
+        assert is_notifications_enabled(a)
+    assert not is_notifications_enabled(a)
+
+
+def test_notifications_enabled_context_manager_non_observable():
+    class A(Ators):
+        a = member()
+
+    with pytest.raises(TypeError):
+        with notifications_enabled(A()):
+            pass
+
+
+def test_suppress_notifications_context_manager():
+    calls = []
+
+    class A(Ators, observable=True):
+        a = member()
+
+    a = A()
+    observe(a, "a", calls.append)
+
+    with suppress_notifications(a) as obj:
+        assert obj is a
+        a.a = 1
+        with suppress_notifications(a):
+            a.a = 2
+        assert not is_notifications_enabled(a)
+        a.a = 3
+    assert calls == []
+    assert is_notifications_enabled(a)
+    a.a = 4
+    assert len(calls) == 1
+
+    with pytest.raises(RuntimeError):
+        with suppress_notifications(a):
+            raise RuntimeError()
+    assert is_notifications_enabled(a)
+
+    # A disabled state is preserved on exit.
+    disable_notifications(a)
+    with suppress_notifications(a):
+        pass
+    assert not is_notifications_enabled(a)
+
+    # The same context manager can be re-entered.
+    enable_notifications(a)
+    cm = suppress_notifications(a)
+    assert isinstance(cm, notifications_enabled)
+    with cm:
+        with cm:
+            pass
+        assert not is_notifications_enabled(a)
+    assert is_notifications_enabled(a)
+
+
 def test_observer_errors_grouped_after_all_callbacks():
     seen = []
 
@@ -181,6 +295,50 @@ def test_bound_method_uses_weakref_and_is_pruned_after_fire():
     assert calls == ["alive"]
 
 
+def test_function_uses_weakref_unless_strong():
+    calls = []
+
+    class A(Ators, observable=True):
+        a = member()
+
+    def weak(change):
+        calls.append("weak")
+
+    def strong(change):
+        calls.append("strong")
+
+    a = A()
+    observe(a, "a", weak)
+    observe(a, "a", strong, strong=True)
+    del weak, strong
+    gc.collect()
+
+    a.a = 1
+    assert calls == ["strong"]
+
+
+def test_strong_bound_method_keeps_owner_alive():
+    calls = []
+
+    class Recorder:
+        def cb(self, change):
+            calls.append(change.newvalue)
+
+    class A(Ators, observable=True):
+        a = member()
+
+    a = A()
+    rec = Recorder()
+    ref = weakref.ref(rec)
+    observe(a, "a", rec.cb, strong=True)
+    del rec
+    gc.collect()
+
+    assert ref() is not None
+    a.a = 1
+    assert calls == [1]
+
+
 def test_observable_is_inherited():
     class A(Ators, observable=True):
         a = member()
@@ -194,7 +352,7 @@ def test_observable_is_inherited():
 
     b = B()
     hits = []
-    observe(b, "a", lambda change: hits.append(change.newvalue))
+    observe(b, "a", lambda change: hits.append(change.newvalue), strong=True)
     b.a = 5
     assert hits == [5]
 
@@ -223,3 +381,116 @@ def test_observer_not_called_when_value_unchanged():
     # Setting a different value triggers again
     a.a = 2
     assert len(calls) == 2
+
+
+def test_static_observer():
+    changes = []
+
+    class A(Ators, observable=True):
+        a: int = member(default=1).observe(
+            Observer.CallChange(lambda c: changes.append((c.name, c.old, c.new)))
+        )
+        b: int = member(default=0)
+
+        @observer(b)
+        def _observe_b(self, change):
+            changes.append((change.name, change.old, change.new))
+
+    obj = A()
+    # Materializing the default does not notify the observer.
+    assert obj.a == 1
+    assert changes == []
+
+    obj.a = 2
+    obj.b = 3
+    assert changes == [("a", 1, 2), ("b", None, 3)]
+
+    # Setting the same value again does not notify.
+    changes.clear()
+    obj.a = 2
+    assert changes == []
+
+    disable_notifications(obj)
+    obj.a = 4
+    assert changes == []
+    enable_notifications(obj)
+    obj.a = 5
+    assert changes == [("a", 4, 5)]
+
+    # Instances of subclasses share the static observer.
+    class B(A):
+        pass
+
+    changes.clear()
+    B(a=2)
+    assert changes == [("a", None, 2)]
+
+
+def test_static_observer_method_is_checked_at_class_creation():
+    with pytest.raises(TypeError) as e:
+
+        class A(Ators, observable=True):
+            a: int = member().observe(Observer.ObjectMethod("_obseve_a"))
+
+            def _observe_a(self, change):
+                pass
+
+    assert "observer behavior reference method '_obseve_a'" in str(e.value)
+
+    with pytest.raises(TypeError) as e:
+
+        class B(Ators, observable=True):
+            a: int = member().observe(Observer.ObjectMethod("_observe_a"))
+
+            def _observe_a(self):
+                pass
+
+    assert "incompatible with the expected signature" in str(e.value)
+
+
+def test_static_observer_change_event_types():
+    events = []
+
+    class A(Ators, observable=True):
+        a: int = member().observe(Observer.CallChange(events.append))
+
+    obj = A()
+    obj.a = 1
+    obj.a = 2
+    del obj.a
+    # Deleting an unset member does not notify.
+    del obj.a
+
+    assert [e.type for e in events] == ["create", "update", "delete"]
+    assert [(e.old, e.new) for e in events] == [(None, 1), (1, 2), (2, None)]
+    assert all(e.object is obj and e.name == "a" for e in events)
+    assert all(isinstance(e, ChangeEvent) for e in events)
+    assert "type='update'" in repr(events[1])
+
+    with pytest.raises(AttributeError):
+        events[0].type = "delete"
+
+
+def test_static_observer_requires_notifications():
+    changes = []
+
+    class A(Ators):
+        a: int = member(default=1).observe(Observer.CallChange(changes.append))
+
+    A().a = 2
+    assert changes == []
+
+
+def test_static_observer_frozen():
+    changes = []
+
+    class A(Ators, observable=True, frozen=True):
+        a: int = member(default=1).observe(
+            Observer.CallChange(lambda c: changes.append((c.type, c.old, c.new)))
+        )
+
+    obj = A(a=2)
+    assert changes == [("create", None, 2)]
+    with pytest.raises(TypeError, match="frozen"):
+        obj.a = 3
+    assert changes == [("create", None, 2)]
@@ -173,3 +173,18 @@ def test_warn_on_multiple_setting_of_postget():
                 .postset(PostSetAttr.CallMemberObjectOldNew(lambda m, o, ol, n: 1))
                 .postset(PostSetAttr.NoOp())
             )
+
+
+def test_backed_by_postset():
+    store = {}
+
+    class A(Ators):
+        a: int = member().postset(PostSetAttr.BackedBy(store))
+        b: int = member(default=0).postset(PostSetAttr.BackedBy(store, "key_b"))
+
+    a = A(a=1)
+    assert store == {"a": 1}
+    a.b = 2
+    assert store == {"a": 1, "key_b": 2}
+    a.a = 3
+    assert store["a"] == 3
@@ -9,7 +9,7 @@
 
 import pytest
 
-from ators import Ators, Member, member
+from ators import Ators, Member, member, observe
 from ators.behaviors import PreGetAttr, preget
 
 
@@ -72,6 +72,38 @@ def test_method_preget():
     assert i == 4
 
 
+def test_compute_member_object_preget():
+    source = {"a": 1}
+
+    class A(Ators):
+        a: int = member().preget(
+            PreGetAttr.ComputeMemberObject(lambda m, o: source[m.name])
+        )
+
+    a = A()
+    assert a.a == 1
+    source["a"] = 3
+    assert a.a == 3
+    # The slot is bypassed so assigned values are never returned.
+    a.a = 2
+    assert a.a == 3
+
+
+def test_compute_method_preget():
+    class A(Ators):
+        a: int = member()
+        b: str = member()
+
+        @preget(b, compute=True)
+        def _compute_b(self, m):
+            return f"{m.name}{self.a}"
+
+    a = A(a=1)
+    assert a.b == "b1"
+    a.a = 2
+    assert a.b == "b2"
+
+
 def test_inherited_preget_behavior():
     i = 0
 
@@ -93,7 +125,10 @@ def test_inherited_preget_behavior():
 
 @pytest.mark.parametrize(
     "behavior, callable, expected, got",
-    [(PreGetAttr.CallMemberObject, lambda: 1, 2, 0)],
+    [
+        (PreGetAttr.CallMemberObject, lambda: 1, 2, 0),
+        (PreGetAttr.ComputeMemberObject, lambda: 1, 2, 0),
+    ],
 )
 def test_bad_signature(behavior, callable, expected, got):
     with pytest.raises(ValueError) as e:
@@ -152,3 +187,139 @@ def test_warn_on_multiple_setting_of_preget():
                 .preget(PreGetAttr.CallMemberObject(lambda m, o: 1))
                 .preget(PreGetAttr.NoOp())
             )
+
+
+@pytest.mark.parametrize("cache", [True, False])
+def test_backed_by_preget(cache):
+    store = {"a": 1}
+
+    class A(Ators):
+        a: int = member().preget(PreGetAttr.BackedBy(store, cache=cache))
+        b: int = member(default=3).preget(PreGetAttr.BackedBy(store, "key_b"))
+
+    a = A()
+    assert a.a == 1
+    store["a"] = 2
+    assert a.a == (1 if cache else 2)
+
+    # Missing keys fall back to the default
+    assert a.b == 3
+    store["key_b"] = 4
+    assert a.b == 3
+
+    store["a"] = "x"
+    del a.a
+    with pytest.raises(TypeError) as e:
+        a.a
+    assert "expects a int" in str(e.value.__cause__)
+
+
+def test_backed_by_member():
+    store = {"b": 1}
+
+    class A(Ators):
+        a: int = member().backed_by(store, "key_a")
+        b: int = member().backed_by(store, cache=False)
+
+    a = A(a=2)
+    assert store == {"b": 1, "key_a": 2}
+    assert a.a == 2 and a.b == 1
+
+    a.b = 5
+    assert store["b"] == 5
+    store["b"] = 6
+    assert a.b == 6
+
+    with pytest.raises(TypeError):
+        a.b = "x"
+    assert store["b"] == 6
+
+    # Store backed members are optional at init
+    assert A().b == 6
+
+
+def test_getter_setter_member():
+    class A(Ators, observable=True):
+        _celsius: float = 0.0
+
+        def _get_fahrenheit(self):
+            return self._celsius * 9 / 5 + 32
+
+        def _set_fahrenheit(self, value):
+            self._celsius = (value - 32) * 5 / 9
+
+        fahrenheit: float = member().getter(_get_fahrenheit).setter("_set_fahrenheit")
+
+    a = A()
+    assert a.fahrenheit == 32.0
+    changes = []
+    observe(a, "_celsius", changes.append)
+    observe(a, "fahrenheit", changes.append)
+    a.fahrenheit = 212.0
+    assert a._celsius == 100.0
+    assert a.fahrenheit == 212.0
+    # Only the backing member notifies.
+    assert [c.member_name for c in changes] == ["_celsius"]
+
+    # The setter receives validated values only.
+    with pytest.raises(TypeError) as e:
+        a.fahrenheit = "hot"
+    assert "Validation failed for member 'fahrenheit'" in str(e.value)
+    assert a._celsius == 100.0
+
+    # Values can be passed at init and go through the setter.
+    assert A(fahrenheit=50.0)._celsius == 10.0
+
+    # The value returned by the getter is validated too.
+    a._celsius = 0.0
+    A._get_fahrenheit = lambda self: "cold"
+    with pytest.raises(TypeError) as e:
+        a.fahrenheit
+    assert "expects a float" in str(e.value.__cause__)
+
+
+def test_getter_only_member():
+    class A(Ators):
+        items: list[int] = member(default_factory=lambda: [])
+
+        def _count(self):
+            return len(self.items)
+
+        count: int = member().getter(_count)
+
+    a = A(items=[1, 2])
+    assert a.count == 2
+    with pytest.raises(TypeError):
+        a.count = 3
+    with pytest.raises(TypeError):
+        A(count=1)
+
+
+def test_getter_setter_member_errors():
+    with pytest.raises(TypeError) as e:
+
+        class A(Ators):
+            def _set(self, value):
+                pass
+
+            a: int = member().setter(_set)
+
+    assert "has a setter but no getter" in str(e.value)
+
+    with pytest.raises(TypeError) as e:
+
+        class B(Ators):
+            def _get(self, member):
+                return 1
+
+            a: int = member().getter(_get)
+
+    assert "incompatible with the expected signature (self)" in str(e.value)
+
+    with pytest.raises(TypeError) as e:
+
+        class C(Ators):
+            a: int = member().getter("_missing")
+
+    with pytest.raises(TypeError):
+        member().getter(1)
@@ -0,0 +1,71 @@
+# --------------------------------------------------------------------------------------
+# Copyright (c) 2026, Ators contributors, see git history for details
+#
+# Distributed under the terms of the Modified BSD License.
+#
+# The full license is in the file LICENSE, distributed with this software.
+# --------------------------------------------------------------------------------------
+"""Test the __replace__ protocol used by copy.replace"""
+
+import copy
+
+import pytest
+
+from ators import Ators, AtorsValidationError, freeze, is_frozen, member
+
+
+class Point(Ators):
+    x: int
+    y: int = member(default=0)
+    label: str = member(default="p")
+
+
+def test_replace_returns_new_validated_instance():
+    p = Point(x=1, y=2)
+    q = copy.replace(p, x=5)
+
+    assert q is not p
+    assert (q.x, q.y, q.label) == (5, 2, "p")
+    assert p.x == 1
+
+    with pytest.raises(AtorsValidationError):
+        copy.replace(p, y="a")
+
+
+def test_replace_unknown_member():
+    with pytest.raises(TypeError, match="non-init member"):
+        copy.replace(Point(x=1), z=1)
+
+
+def test_replace_non_init_member():
+    class A(Ators):
+        a: int
+        b: int = member(default=0, init=False)
+
+    a = A(a=1)
+    a.b = 2
+    b = copy.replace(a, a=3)
+    assert b.a == 3
+    assert b.b == 0
+
+    with pytest.raises(ValueError, match="cannot be replaced"):
+        copy.replace(a, b=1)
+
+
+def test_replace_preserves_frozen_state():
+    class Frozen(Ators, frozen=True):
+        a: int
+
+    f = copy.replace(Frozen(a=1), a=2)
+    assert f.a == 2
+    assert is_frozen(f)
+
+    class A(Ators):
+        a: int
+
+    a = A(a=1)
+    assert not is_frozen(copy.replace(a, a=2))
+    freeze(a)
+    b = copy.replace(a, a=2)
+    assert b.a == 2
+    assert is_frozen(b)
@@ -7,13 +7,61 @@
 # --------------------------------------------------------------------------------------
 """Test type validation for ators object"""
 
+import array
+import collections.abc
+import ctypes
+import enum
+import functools
+import sys
+import typing
+import warnings
 from abc import ABC
+from collections.abc import (
+    Buffer,
+    Callable,
+    Iterable,
+    Iterator,
+    Mapping,
+    Sequence,
+)
 from annotationlib import ForwardRef
-from typing import TYPE_CHECKING, Any, Literal, TypeVar
+from decimal import Decimal
+from types import MappingProxyType
+from typing import (
+    TYPE_CHECKING,
+    Annotated,
+    Any,
+    Generic,
+    Literal,
+    NamedTuple,
+    Never,
+    NoReturn,
+    NotRequired,
+    ReadOnly,
+    Required,
+    TypedDict,
+    TypeGuard,
+    TypeVar,
+)
 
 import pytest
 
-from ators import Ators, add_generic_type_attributes, member
+from ators import (
+    Ators,
+    AtorsCoercionError,
+    AtorsValidationError,
+    Member,
+    add_generic_type_attributes,
+    get_member,
+    member,
+)
+from ators.validators import (
+    Coercer,
+    TypeValidator,
+    Validator,
+    ValueValidator,
+    validator_for,
+)
 
 if TYPE_CHECKING:
     from logging import Logger
@@ -54,6 +102,113 @@ class UnknownGen[T]:
 type MyInt = int
 
 
+class MyTypedDict(TypedDict):
+    a: int
+    b: NotRequired[str]
+
+
+class MyPartialTypedDict(TypedDict, total=False):
+    a: Required[int]
+    b: ReadOnly[str]
+
+
+class MyDerivedTypedDict(MyPartialTypedDict):
+    c: float
+
+
+class Color(enum.Enum):
+    RED = "red"
+    BLUE = "blue"
+
+
+class Level(enum.IntEnum):
+    LOW = 1
+    HIGH = 2
+
+
+class Perm(enum.Flag):
+    R = 1
+    W = 2
+
+
+class IntPerm(enum.IntFlag):
+    R = 1
+    W = 2
+
+
+class KeepPerm(enum.Flag, boundary=enum.KEEP):
+    R = 1
+    W = 2
+
+
+class EjectPerm(enum.Flag, boundary=enum.EJECT):
+    R = 1
+    W = 2
+
+
+# ByteString is looked up in the module namespaces since it is deprecated and
+# removed in recent Python versions.
+_BUFFER_ANNOTATIONS = [Buffer] + [
+    m.__dict__["ByteString"]
+    for m in (typing, collections.abc)
+    if "ByteString" in m.__dict__
+]
+
+
+def _returns_int(x) -> int:
+    return x
+
+
+def _returns_bool(x) -> bool:
+    return x
+
+
+def _returns_str(x) -> str:
+    return x
+
+
+def _returns_none() -> None:
+    pass
+
+
+class _Point(NamedTuple):
+    x: int
+    y: int
+
+
+def _two_args(x, y):
+    pass
+
+
+class _Pair:
+    def __init__(self, x, y=0):
+        pass
+
+
+_INT_VALIDATOR = Validator(TypeValidator.Int())
+
+
+def _class_for(ann, **kwargs):
+    """Create a class whose member a is validated according to ann.
+
+    ann is either an annotation, a type validator or a partial creating one (so
+    that distinct but equal validators can be built).
+    """
+    if isinstance(ann, functools.partial):
+        ann = ann()
+    if isinstance(ann, TypeValidator):
+
+        class A(Ators):
+            a = member(**kwargs).validate(ann)
+
+    else:
+
+        class A(Ators):
+            a: ann = member(**kwargs)  # type: ignore
+
+    return A
+
+
 # FIXME validate error messages
 @pytest.mark.parametrize(
     "ann, goods, bads, warn",
@@ -65,8 +220,11 @@ type MyInt = int
         (MyInt, [0, 1, -1], [1.0, ""], False),
         (float, [0.0, 0.1], [1, ""], False),
         (complex, [0.0 + 0j, 0.1j], [1, 1.0, ""], False),
+        (Decimal, [Decimal("1.1")], [1, 1.1, "1.1"], False),
         (str, ["a"], [1], False),
-        (bytes, [b"a"], [""], False),
+        (bytes, [b"a"], ["", bytearray(b"a")], False),
+        (bytearray, [bytearray(b"a")], [b"a", memoryview(b"a")], False),
+        (memoryview, [memoryview(b"a")], [b"a", bytearray(b"a")], False),
         (OB, [OB()], [""], False),
         (tuple, [()], [1, ""], False),
         (tuple[int, ...], [(), (1,), (1, 2, 3)], [1, ("a",)], False),
@@ -87,11 +245,40 @@ type MyInt = int
         (set[int], [set(), {1}], [1, (), {1, "a"}], False),
         (dict, [{}, {1: 1}, {1: "a"}], [1, ()], False),
         (dict[int, int], [{}, {1: 1}], [1, (), {1: "a"}, {"1": 1}, {"1": "a"}], False),
+        (Sequence, [[], (1, "a"), range(2)], [1, "a", b"a", {1}], False),
+        (Sequence[int], [[1], (1, 2)], [[1, "a"], ("a",), {1}], False),
+        (Mapping, [{}, MappingProxyType({1: "a"})], [1, [(1, 2)]], False),
+        (
+            Mapping[str, int],
+            [{"a": 1}, MappingProxyType({"a": 1})],
+            [{1: 1}, {"a": "1"}, [("a", 1)]],
+            False,
+        ),
+        (
+            MyTypedDict,
+            [{"a": 1}, {"a": 1, "b": "b"}],
+            [1, {}, {"b": "b"}, {"a": "1"}, {"a": 1, "b": 1}, {"a": 1, "c": 1}],
+            False,
+        ),
+        (
+            MyPartialTypedDict,
+            [{"a": 1}, {"a": 1, "b": "b"}],
+            [{}, {"b": "b"}, {"a": 1, "b": 1}],
+            False,
+        ),
+        (
+            MyDerivedTypedDict,
+            [{"a": 1, "c": 1.0}, {"a": 1, "b": "b", "c": 1.0}],
+            [{"a": 1}, {"c": 1.0}, {"a": 1, "c": 1}],
+            False,
+        ),
         # NOTE Not a type validation
         (Literal[1, 2, 3], [1, 2, 3], [0, 4, "a"], False),
         (CustomBase, [CustomObj()], ["", 1, object()], False),
         (int | str, [1, "a"], [1.0, object()], False),
         (int | str | None, [1, "a", None], [1.0, object()], False),
+        (int | None, [1, None], ["1", 1.0], False),
+        (None | str, [None, "a"], [1, b"a"], False),
         (int | tuple[int, int], [1, (1, 2)], [1.0, (1, 2, 3), "c", object()], False),
         (int | Literal["a", "b"], [1, "a", "b"], [1.0, "c", object()], False),
         (MyGen[int], [MyGen(1)], [MyGen("a"), MyGen(object()), 1, object()], False),
@@ -112,19 +299,97 @@ type MyInt = int
             False,
         ),
         (type, [int, str, object, type], [1, "a", object()], False),
+        (Color, [Color.RED, Color.BLUE], ["blue", Level.LOW], False),
+        (Level, [Level.LOW, Level.HIGH], [1, Color.RED], False),
+        (Perm, [Perm.R, Perm.R | Perm.W, Perm(0)], [1, IntPerm.R], False),
+        (IntPerm, [IntPerm.R, IntPerm.R | IntPerm.W], [1, Perm.R], False),
+        # Loose flags accept any combination of bits
+        (KeepPerm, [KeepPerm.R, KeepPerm(4)], [4], False),
+        (Callable[[int], int], [_returns_str, len], [1, "a"], False),
+        # Defaults, *args and classes are accounted for, and uninspectable
+        # callables are accepted.
+        (
+            Callable[[int, int], int],
+            [
+                _two_args,
+                lambda x, y, z=1: x,
+                lambda *args: 0,
+                lambda x, *args, **kwargs: x,
+                _Pair,
+                max,
+            ],
+            [_returns_int, lambda x, y, z: x, lambda x, y, *, z: x, 1],
+            False,
+        ),
+        (Callable[..., int], [_two_args, _returns_int, _returns_none], [1], False),
+        (Callable[[], None], [_returns_none], [_returns_int], False),
+        (Never, [], [None, 1, object()], False),
+        # Exact tuples remain the default.
+        (tuple[int, int], [(1, 2)], [_Point(1, 2)], False),
+        (
+            TypeValidator.Tuple([_INT_VALIDATOR] * 2, allow_subclasses=True),
+            [(1, 2), _Point(1, 2)],
+            [_Point(1, "a"), [1, 2]],
+            False,
+        ),
+        (
+            TypeValidator.VarTuple(_INT_VALIDATOR, allow_subclasses=True),
+            [(), _Point(1, 2)],
+            [_Point(1, "a"), [1]],
+            False,
+        ),
+        (
+            TypeValidator.VarTuple(None, allow_subclasses=True),
+            [(), _Point(1, "a")],
+            [[1]],
+            False,
+        ),
+        # Items are validated lazily, when iterating.
+        (Iterable[int], [], [1, None], False),
+        (Iterator[int], [], [[1, 2], 1], False),
+        (
+            TypeValidator.Iterable(_INT_VALIDATOR, materialize=True),
+            [(), (1, 2)],
+            [1, (1, "a")],
+            False,
+        ),
+        (NoReturn, [], [None, 1, object()], False),
+        *(
+            (
+                ann,
+                [b"ab", bytearray(b"ab"), memoryview(b"ab"), array.array("b", [1])],
+                ["ab", 1],
+                False,
+            )
+            for ann in _BUFFER_ANNOTATIONS
+        ),
+        (
+            TypeValidator.Buffer(),
+            [b"ab", bytearray(b"ab"), memoryview(b"ab"), array.array("d", [1.0])],
+            ["ab", 1, None],
+            False,
+        ),
+        (
+            TypeValidator.Buffer(format="d", max_size=16),
+            [array.array("d", [1.0, 2.0])],
+            [array.array("i", [1]), array.array("d", [1.0, 2.0, 3.0]), b"ab"],
+            False,
+        ),
+        (
+            TypeValidator.Buffer(format="=B", min_size=2),
+            [b"ab", bytearray(b"abc")],
+            [b"a", array.array("i", [1])],
+            False,
+        ),
     ],
 )
 def test_type_validators(ann, goods, bads, warn):
 
     if warn:
         with pytest.warns(UserWarning, match="No specific validation strategy"):
-
-            class A(Ators):
-                a: ann = member()
+            A = _class_for(ann)
     else:
-
-        class A(Ators):
-            a: ann = member()
+        A = _class_for(ann)
 
     a = A()
     for good in goods:
@@ -207,7 +472,14 @@ def test_forward_ref_failed_to_resolve():
 
 
 @pytest.mark.parametrize(
-    "resolver", [lambda: __import__("logging").__dict__, "logging", ["logging"]]
+    "resolver",
+    [
+        lambda: __import__("logging").__dict__,
+        "logging",
+        ["logging"],
+        # Callable without an introspectable signature
+        functools.partial(dict, __import__("logging").__dict__),
+    ],
 )
 def test_forward_ref_support_callable_and_type_alias(resolver):
     type L = Logger
@@ -277,6 +549,15 @@ class DelayedGenericPair[T, U](Ators):
     second: U = member()
 
 
+LT = TypeVar("LT")
+LU = TypeVar("LU")
+
+
+class LegacyGenericPair(Ators, Generic[LT, LU]):
+    first: LT = member()
+    second: LU = member()
+
+
 def test_generic_specialization_is_cached_class():
     int_box = GenericBox[int]
     assert int_box is GenericBox[int]
@@ -329,6 +610,33 @@ def test_specialized_nested_typevar_narrows_validator():
         box.items = ["a"]
 
 
+def test_unspecialized_typevar_validators():
+    TB = TypeVar("TB", bound=list[int])
+    TC = TypeVar("TC", int, str)
+    TA = TypeVar("TA")
+
+    class A(Ators, Generic[TB, TC, TA]):
+        b: TB
+        c: TC
+        a: TA
+
+    def tv(name):
+        return get_member(A, name).validator.type_validator
+
+    assert tv("b") == TypeValidator.List(Validator(TypeValidator.Int()))
+    assert [m.type_validator for m in tv("c").members] == [
+        TypeValidator.Int(),
+        TypeValidator.Str(),
+    ]
+    assert tv("a") == TypeValidator.Any()
+
+    a = A(b=[1], c="c", a=object())
+    with pytest.raises(TypeError):
+        a.b = ["a"]
+    with pytest.raises(TypeError):
+        a.c = 1.0
+
+
 def test_partial_specialization_keeps_generic_parameter():
     T2 = TypeVar("T2", bound=int)
     partial = GenericPair[int, T2]
@@ -355,6 +663,53 @@ def test_partial_specialization_can_be_fully_specialized_later():
         pair.second = "a"
 
 
+def test_legacy_generic_specialization():
+    assert LegacyGenericPair.__type_params__ == (LT, LU)
+
+    pair = LegacyGenericPair[int, str](first=1, second="a")
+    assert pair.first == 1
+    with pytest.raises(TypeError):
+        pair.second = 1
+
+
+def test_legacy_generic_partial_specialization_with_own_typevar():
+    partial = LegacyGenericPair[int, LU]
+    assert partial.__type_params__ == (LU,)
+
+    pair = partial(first=1, second="a")
+    pair.second = 1
+    with pytest.raises(TypeError):
+        pair.first = "a"
+    assert partial[str] is LegacyGenericPair[int, str]
+
+
+def test_subclassing_legacy_generic_with_concrete_argument():
+    class IntStrPair(LegacyGenericPair[int, str]):
+        pass
+
+    pair = IntStrPair(first=1, second="a")
+    with pytest.raises(TypeError):
+        pair.first = "a"
+    with pytest.raises(TypeError):
+        pair.second = 1
+
+
+def test_subclassing_legacy_generic_with_typevar_argument():
+    class Pair(LegacyGenericPair[int, LU]):
+        pass
+
+    assert Pair.__type_params__ == (LU,)
+    pair = Pair[str](first=1, second="a")
+    with pytest.raises(TypeError):
+        pair.second = 1
+
+    class Other(LegacyGenericPair[LT, LU], Generic[LT, LU]):
+        pass
+
+    with pytest.raises(TypeError):
+        Other[int, str](first="a", second="a")
+
+
 def test_partial_specialization_typevar_bound_must_be_narrower():
     narrower = TypeVar("narrower", bound=bool)
     _ = BoundedPair[int, narrower]
@@ -437,6 +792,71 @@ def test_constrained_typevar_matches_union_behavior():
             ubox.item = val
 
 
+def test_union_members_follow_annotation_order():
+    """Union members are tried in the order of the annotation arguments."""
+
+    class A(Ators):
+        a: int | bool
+        b: bool | int
+        c: Member[int | str, Any] = member().coerce(Coercer.TypeInferred())
+        d: Member[str | int, Any] = member().coerce(Coercer.TypeInferred())
+
+    def members(name):
+        tv = get_member(A, name).validator.type_validator
+        return [m.type_validator for m in tv.members]
+
+    assert members("a") == [TypeValidator.Int(), TypeValidator.Bool()]
+    assert members("b") == [TypeValidator.Bool(), TypeValidator.Int()]
+
+    # The first member accepting the value, possibly after coercion, wins.
+    obj = A(a=True, b=True, c="1", d="1")
+    assert obj.c == 1
+    assert obj.d == "1"
+
+
+def test_optional_validator():
+    """Unions of None and a single type use a dedicated validator."""
+
+    class A(Ators):
+        a: int | None
+        b: None | str
+        c: int | str | None
+        d: Member[float | None, Any] = member().coerce(Coercer.TypeInferred())
+
+    def tv(name):
+        return get_member(A, name).validator.type_validator
+
+    assert tv("a") == TypeValidator.Optional(Validator(TypeValidator.Int()))
+    assert tv("b") == TypeValidator.Optional(Validator(TypeValidator.Str()))
+    assert isinstance(tv("c"), TypeValidator.Union)
+
+    obj = A(a=None, b=None, c=None, d=None)
+    with pytest.raises(AtorsValidationError) as e:
+        obj.a = "1"
+    assert "'1' is neither None nor valid for int" in str(e.value.__cause__)
+    assert "expects a int" in str(e.value.__cause__.__cause__)
+
+    assert obj.d is None
+    obj.d = 1
+    assert obj.d == 1.0 and isinstance(obj.d, float)
+    with pytest.raises(AtorsCoercionError):
+        obj.d = "a"
+
+
+def test_optional_validator_error_names_the_type():
+    class A(Ators):
+        a: OB | None = None
+        b: list[int] | None = None
+
+    obj = A()
+    with pytest.raises(AtorsValidationError) as e:
+        obj.a = 1
+    assert str(e.value.__cause__) == "Value 1 is neither None nor valid for OB"
+    with pytest.raises(AtorsValidationError) as e:
+        obj.b = 1
+    assert str(e.value.__cause__) == "Value 1 is neither None nor valid for list[int]"
+
+
 # ---------------------------------------------------------------------------
 # Constrained TypeVar generic class specialization tests
 # ---------------------------------------------------------------------------
@@ -575,3 +995,652 @@ def test_faulty_multiple_subscript_type_annotation():
 
         class A(Ators):
             a: type[int, str] = member()  # type: ignore[misc]
+
+
+# ============================================================================
+# Tests for Instance validators built directly
+# ============================================================================
+
+
+def test_instance_validator_accepts_generic_alias():
+    with pytest.warns(UserWarning, match="only its origin list is used"):
+        v = TypeValidator.Instance(list[int])
+    assert v.types == (list,)
+
+
+def test_instance_validator_accepts_generic_alias_in_tuple():
+    with pytest.warns(UserWarning, match="only its origin dict is used"):
+        v = TypeValidator.Instance((int, dict[str, int]))
+    assert v.types == (int, dict)
+
+
+def test_instance_validator_rejects_non_type():
+    with pytest.raises(TypeError, match="Expected a 'type'"):
+        TypeValidator.Instance(1)
+
+
+# ============================================================================
+# Tests for validators equality
+# ============================================================================
+
+
+def _validator(ann, **kwargs):
+    return _class_for(ann, **kwargs).a.validator  # type: ignore[attr-defined]
+
+
+@pytest.mark.parametrize(
+    "ann",
+    [
+        int,
+        str | None,
+        list[int],
+        dict[str, list[int]],
+        tuple[int, ...],
+        Literal[1, 2],
+        Color,
+        Perm,
+        functools.partial(TypeValidator.Buffer, format="d"),
+        functools.partial(TypeValidator.Buffer, min_size=1, max_size=4),
+        Callable[[int], int],
+        functools.partial(TypeValidator.Callable, returns=int),
+        functools.partial(TypeValidator.Callable, arity=1),
+        Never,
+        functools.partial(
+            TypeValidator.VarTuple, _INT_VALIDATOR, allow_subclasses=True
+        ),
+        Iterable[int],
+        functools.partial(TypeValidator.Iterable, _INT_VALIDATOR, materialize=True),
+    ],
+)
+def test_validator_equality(ann):
+    v1, v2 = _validator(ann), _validator(ann)
+    assert v1 is not v2
+    assert v1 == v2
+    assert hash(v1) == hash(v2)
+    assert v1.type_validator == v2.type_validator
+    assert len({v1, v2}) == 1
+
+
+@pytest.mark.parametrize(
+    "ann1, ann2",
+    [
+        (int, float),
+        (list[int], list[str]),
+        (int | None, int | str),
+        (Literal[1, 2], Literal[1, 3]),
+        (Color, Level),
+        (Perm, IntPerm),
+        (TypeValidator.Buffer(), TypeValidator.Buffer(max_size=1)),
+        (TypeValidator.Buffer(format="d"), TypeValidator.Buffer(format="f")),
+        (TypeValidator.Callable(returns=int), TypeValidator.Callable()),
+        (
+            TypeValidator.Callable(returns=int),
+            TypeValidator.Callable(returns=int, check_returns=True),
+        ),
+        (TypeValidator.Callable(arity=1), TypeValidator.Callable()),
+        (
+            TypeValidator.Tuple([_INT_VALIDATOR]),
+            TypeValidator.Tuple([_INT_VALIDATOR], allow_subclasses=True),
+        ),
+        (Iterable[int], Iterator[int]),
+        (
+            TypeValidator.Iterable(None),
+            TypeValidator.Iterable(None, materialize=True),
+        ),
+    ],
+)
+def test_validator_inequality(ann1, ann2):
+    assert _validator(ann1) != _validator(ann2)
+
+
+def test_validator_equality_with_behaviors():
+    def check(v):
+        pass
+
+    coercer = Coercer.CallValue(lambda v: int(v))
+    vv = ValueValidator.CallValue(check)
+    v1 = Validator(TypeValidator.Int(), [vv], coercer)
+    v2 = Validator(TypeValidator.Int(), [ValueValidator.CallValue(check)], coercer)
+    assert v1 == v2
+    assert hash(v1) == hash(v2)
+    assert v1 != Validator(TypeValidator.Int(), [vv])
+    assert v1 != Validator(TypeValidator.Int(), [ValueValidator.CallValue(lambda v: None)])
+    assert v1 != Validator(TypeValidator.Int(), [vv], Coercer.TypeInferred())
+    assert Coercer.ObjectMethod("f") == Coercer.ObjectMethod("f")
+    assert Coercer.ObjectMethod("f") != Coercer.ObjectMethod("g")
+    assert ValueValidator.Values({1, 2}) == ValueValidator.Values(frozenset({2, 1}))
+
+
+def test_validator_coercer_attributes():
+    coercer = Coercer.TypeInferred()
+    init_coercer = Coercer.CallValue(lambda v: int(v))
+    v = Validator(TypeValidator.Int(), coercer=coercer, init_coercer=init_coercer)
+    assert v.coercer == coercer
+    assert v.init_coercer == init_coercer
+
+
+@pytest.mark.parametrize(
+    "ann, value",
+    [(int, "a"), (list[int], ["a"]), (int | None, "a"), (Literal[1, 2], 3)],
+)
+def test_validation_failure_raises_ators_validation_error(ann, value):
+    class A(Ators):
+        a: ann  # type: ignore
+
+    with pytest.raises(AtorsValidationError) as e:
+        A().a = value
+    assert isinstance(e.value, TypeError)
+    assert isinstance(e.value, ValueError)
+    assert not isinstance(e.value, AtorsCoercionError)
+
+
+def test_explicit_type_validator():
+    class A(Ators):
+        a = member().validate(TypeValidator.Int())
+        b = (
+            member()
+            .validate(TypeValidator.Str())
+            .append_value_validator(ValueValidator.Values({"x", "y"}))
+        )
+
+    a = A()
+    a.a = 1
+    with pytest.raises(AtorsValidationError):
+        a.a = "1"
+    a.b = "x"
+    with pytest.raises(AtorsValidationError):
+        a.b = 1
+    with pytest.raises(AtorsValidationError):
+        a.b = "z"
+    assert A.a.validator.type_validator == TypeValidator.Int()
+
+
+def test_explicit_type_validator_overrides_annotation():
+    with pytest.warns(UserWarning, match="explicit type validator"):
+
+        class A(Ators):
+            a: int = member().validate(TypeValidator.Str())
+
+    a = A()
+    a.a = "1"
+    with pytest.raises(AtorsValidationError):
+        a.a = 1
+
+
+def _is_pair(value: object) -> TypeGuard[tuple[int, int]]:
+    return isinstance(value, tuple) and len(value) == 2
+
+
+def test_type_guard_validation():
+    # Pairing a type guard with the annotation of the guarded type does not warn.
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+
+        class A(Ators):
+            a: tuple[int, int] = member().type_guard(_is_pair)
+            b: Member[tuple[int, int], Any] = (
+                member().type_guard(_is_pair).coerce(Coercer.CallValue(tuple))
+            )
+            c: Member[tuple[int, int], Any] = member().type_guard(_is_pair).coerce()
+
+    a = A(a=(1, 2), b=(3, 4), c=(5, 6))
+    assert A.a.validator.type_validator == TypeValidator.Guard(_is_pair)
+    assert A.a.validator.type_validator.predicate is _is_pair
+
+    # Any value accepted by the guard is stored as is.
+    value = ("a", None)
+    a.a = value
+    assert a.a is value
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = [1, 2]
+    assert "satisfying the type guard _is_pair" in str(e.value.__cause__)
+
+    # The coercer runs when the guard rejects the value.
+    a.b = [1, 2]
+    assert a.b == (1, 2)
+    with pytest.raises(AtorsCoercionError):
+        a.c = [1, 2]
+
+    with pytest.raises(ValueError):
+        TypeValidator.Guard(lambda a, b: True)
+
+
+class LitColor(enum.Enum):
+    RED = 1
+    BLUE = 2
+
+
+@pytest.mark.parametrize(
+    "ann, goods, bads",
+    [
+        (Literal[True], [True], [1, 1.0, False]),
+        (Literal[1], [1], [True, 1.0, [1]]),
+        (Literal[0, False], [0, False], [0.0, True, 1]),
+        (Literal[None], [None], [0, False, "", ()]),
+        (Literal[b"x"], [b"x"], ["x", bytearray(b"x"), b"y"]),
+        (Literal["a", b"a"], ["a", b"a"], ["b", b"b"]),
+        (Literal[LitColor.RED], [LitColor.RED], [1, LitColor.BLUE]),
+        (Literal[1, None], [1, None], [True, 0]),
+    ],
+)
+def test_literal_validation(ann, goods, bads):
+    class A(Ators):
+        a: ann  # type: ignore
+
+    a = A()
+    for good in goods:
+        a.a = good
+        assert a.a is good
+    for bad in bads:
+        with pytest.raises(AtorsValidationError):
+            a.a = bad
+
+
+def test_literal_validator_with_unhashable_literals():
+    class A(Ators):
+        a = member().append_value_validator(ValueValidator.Literal(([1], 2)))
+
+    a = A()
+    for good in ([1], 2):
+        a.a = good
+    for bad in ([2], (1,), 2.0):
+        with pytest.raises(AtorsValidationError):
+            a.a = bad
+    assert ValueValidator.Literal(([1], 2)).values == ([1], 2)
+
+
+def test_literal_validator_equality_distinguishes_bool():
+    assert _validator(Literal[True]) != _validator(Literal[1])
+    assert _validator(Literal[True]) == _validator(Literal[True])
+
+
+def test_buffer_validation():
+    class A(Ators):
+        a = member().validate(TypeValidator.Buffer())
+        b = member(init=False).validate(TypeValidator.Buffer(format="d", max_size=16))
+        c = (
+            member(init=False)
+            .validate(TypeValidator.Buffer(min_size=2))
+            .coerce(Coercer.TypeInferred())
+        )
+
+    # Buffers are exposed as memoryview sharing the memory of the object.
+    data = bytearray(b"abc")
+    obj = A(a=data)
+    assert isinstance(obj.a, memoryview)
+    assert obj.a.obj is data
+    data[0] = ord("z")
+    assert bytes(obj.a) == b"zbc"
+
+    view = memoryview(b"xy")
+    obj.a = view
+    assert obj.a is view
+
+    with pytest.raises(AtorsValidationError) as e:
+        obj.b = array.array("i", [1])
+    assert "format 'd'" in str(e.value.__cause__)
+    with pytest.raises(AtorsValidationError) as e:
+        obj.b = array.array("d", [1.0, 2.0, 3.0])
+    assert "24 bytes" in str(e.value.__cause__)
+
+    obj.c = [1, 2]
+    assert bytes(obj.c) == b"\x01\x02"
+    with pytest.raises(AtorsCoercionError):
+        obj.c = "ab"
+
+
+def test_buffer_format_byte_order():
+    class A(Ators):
+        a = member().validate(TypeValidator.Buffer(format="=B"))
+        b = member().validate(TypeValidator.Buffer(format="d"))
+        c = member(init=False).validate(TypeValidator.Buffer(format=">i"))
+
+    # ctypes arrays expose explicit byte order formats such as "<B" and "<d".
+    obj = A(a=(ctypes.c_ubyte * 2)(), b=array.array("d", [1.0]))
+    assert obj.a.format == "<B"
+    obj.a = b"ab"
+    if sys.byteorder == "little":
+        obj.b = (ctypes.c_double * 2)()
+        assert obj.b.format == "<d"
+        # A non native byte order changes the layout of multi-byte items.
+        with pytest.raises(AtorsValidationError):
+            obj.c = array.array("i", [1])
+
+
+def test_buffer_export_locks_the_object():
+    class A(Ators):
+        a = member().validate(TypeValidator.Buffer())
+
+    data = bytearray(b"abc")
+    obj = A(a=data)
+    with pytest.raises(BufferError):
+        data.append(0)
+
+    obj.a.release()
+    data.append(0)
+    assert data == b"abc\x00"
+
+
+def test_tuple_subclasses():
+    float_v = Validator(TypeValidator.Float(), coercer=Coercer.TypeInferred())
+
+    class A(Ators):
+        fixed = member(init=False).validate(
+            TypeValidator.Tuple([_INT_VALIDATOR] * 2, allow_subclasses=True)
+        )
+        coerced = member(init=False).validate(
+            TypeValidator.Tuple([float_v, float_v], allow_subclasses=True)
+        )
+
+    # Accepted subclasses are stored as is.
+    a = A()
+    p = _Point(1, 2)
+    a.fixed = p
+    assert a.fixed is p
+
+    # Items modified by validation are stored in a plain tuple.
+    a.coerced = p
+    assert type(a.coerced) is tuple and a.coerced == (1.0, 2.0)
+
+
+@pytest.mark.parametrize("ann", _BUFFER_ANNOTATIONS)
+def test_buffer_annotation(ann):
+    class A(Ators):
+        a: ann
+
+    assert _validator(ann) == _validator(Buffer)
+    obj = A(a=b"ab")
+    assert isinstance(obj.a, memoryview)
+    with pytest.raises(AtorsValidationError) as e:
+        obj.a = "ab"
+    assert "expects a buffer" in str(e.value.__cause__)
+
+
+@pytest.mark.parametrize("ann", [Never, NoReturn])
+def test_never_validation(ann):
+    class A(Ators):
+        a: ann = member(init=False)  # type: ignore
+
+    assert get_member(A, "a").validator.type_validator == TypeValidator.Never()
+    with pytest.raises(AtorsValidationError) as e:
+        A().a = 1
+    assert "'a'" in str(e.value.__cause__)
+    assert "has type Never and cannot hold any value" in str(e.value.__cause__)
+
+    # Subclasses can redefine the member with a usable type.
+    class B(A):
+        a: int = 1
+
+    assert B().a == 1
+
+
+def test_callable_annotation():
+    assert _validator(Callable[[int], int]).type_validator == TypeValidator.Callable(
+        returns=int, arity=1
+    )
+
+
+def test_callable_arity():
+    class A(Ators):
+        a: Callable[[int, int], int]
+
+    assert _validator(Callable[..., int]).type_validator == TypeValidator.Callable(
+        returns=int
+    )
+    assert _validator(Callable[[], int]).type_validator == TypeValidator.Callable(
+        returns=int, arity=0
+    )
+
+    a = A(a=_two_args)
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = _returns_int
+    assert isinstance(e.value.__cause__, ValueError)
+    assert "accepting 2 positional argument(s)" in str(e.value.__cause__)
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = 1
+    assert isinstance(e.value.__cause__, TypeError)
+
+
+def test_callable_return_check():
+    class A(Ators):
+        a = member().validate(TypeValidator.Callable(returns=int, check_returns=True))
+        b = member(init=False).validate(
+            TypeValidator.Callable(returns=type(None), check_returns=True)
+        )
+
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        a = A(a=_returns_int)
+        # Subclasses, undeclared annotations and uninspectable callables are
+        # accepted.
+        a.a = _returns_bool
+        a.a = lambda x: x
+        a.a = len
+        a.b = _returns_none
+
+    # Mismatches only warn.
+    with pytest.warns(UserWarning, match="declared return type is <class 'str'>"):
+        a.a = _returns_str
+    assert a.a is _returns_str
+    with pytest.warns(UserWarning, match="callable returning <class 'NoneType'>"):
+        a.b = _returns_int
+
+
+def test_flag_coercion():
+    class A(Ators):
+        i: Member[IntPerm, Any] = member(default=IntPerm.R).coerce(
+            Coercer.TypeInferred()
+        )
+        c: Member[Perm, Any] = member(default=Perm.R).coerce(Coercer.TypeInferred())
+        e: Member[EjectPerm, Any] = member(default=EjectPerm.R).coerce(
+            Coercer.TypeInferred()
+        )
+
+    assert _validator(Perm).type_validator == TypeValidator.FlagEnum(Perm)
+
+    a = A()
+    # Raw ints are combined into the flag when coercing
+    a.i = 3
+    assert a.i is IntPerm.R | IntPerm.W
+    a.c = 3
+    assert a.c == Perm.R | Perm.W
+    with pytest.raises(AtorsCoercionError):
+        a.c = 4
+    with pytest.raises(AtorsCoercionError):
+        a.e = 4
+
+
+def test_enum_coercion():
+    class A(Ators):
+        c: Color = Color.RED
+        co: Member[Color, Any] = member(default=Color.RED).coerce(
+            Coercer.TypeInferred()
+        )
+        lo: Member[Level, Any] = member(default=Level.LOW).coerce(
+            Coercer.TypeInferred()
+        )
+
+    assert _validator(Color).type_validator == TypeValidator.Enum(Color)
+    assert TypeValidator.Enum(enum_type=Color).enum_type is Color
+
+    a = A()
+    with pytest.raises(AtorsValidationError) as e:
+        a.c = "blue"
+    assert "Color" in str(e.value.__cause__)
+
+    # Raw values are converted to the matching member when coercing
+    a.co = "blue"
+    assert a.co is Color.BLUE
+    a.lo = 2
+    assert a.lo is Level.HIGH
+
+    with pytest.raises(AtorsCoercionError) as e:
+        a.co = "green"
+    assert "valid members are: RED ('red'), BLUE ('blue')" in str(e.value.__cause__)
+    assert isinstance(e.value.__cause__.__cause__, ValueError)
+
+
+@pytest.mark.parametrize("ann", [Iterable, typing.Iterable, Iterator, typing.Iterator])
+def test_bare_iterable_and_iterator_annotations(ann):
+    class A(Ators):
+        a: ann
+
+    it = iter([1, "a"])
+    assert A(a=it).a is it
+    with pytest.raises(TypeError):
+        A(a=1)
+
+
+def test_iterable_annotation():
+    class A(Ators):
+        a: Iterable[int]
+
+    obj = A(a=[1, 2])
+    # The items are validated lazily and the wrapped iterable can be iterated
+    # several times if the original one can.
+    assert list(obj.a) == [1, 2]
+    assert list(obj.a) == [1, 2]
+    assert obj.a.wrapped == [1, 2]
+
+    obj.a = [1, "a"]
+    it = iter(obj.a)
+    assert next(it) == 1
+    with pytest.raises(AtorsValidationError) as e:
+        next(it)
+    assert "Failed to validate item 1" in str(e.value)
+
+    # Generators can only be consumed once.
+    obj.a = (i for i in range(2))
+    assert list(obj.a) == [0, 1]
+    assert list(obj.a) == []
+
+    with pytest.raises(TypeError) as e:
+        obj.a = 1
+    assert "expects a iterable" in str(e.value.__cause__)
+
+
+def test_iterable_materialize():
+    class A(Ators):
+        a = member().validate(
+            TypeValidator.Iterable(Validator(TypeValidator.Int()), materialize=True)
+        )
+        b = member().validate(TypeValidator.Iterable(None, materialize=True))
+
+    gen = (i for i in range(3))
+    obj = A(a=gen, b="ab")
+    assert obj.a == (0, 1, 2)
+    assert obj.b == ("a", "b")
+    # The generator was consumed by the validation.
+    assert list(gen) == []
+
+    with pytest.raises(TypeError) as e:
+        obj.a = iter([1, "a"])
+    assert "Failed to validate item 1" in str(e.value.__cause__)
+    assert obj.a == (0, 1, 2)
+
+
+class FrozenMapping(collections.abc.Mapping):
+    def __init__(self, data):
+        self._data = dict(data)
+
+    def __getitem__(self, key):
+        return self._data[key]
+
+    def __iter__(self):
+        return iter(self._data)
+
+    def __len__(self):
+        return len(self._data)
+
+
+def test_sequence_and_mapping_annotations():
+    class A(Ators):
+        s: Sequence[int] = ()
+        m: typing.Mapping[str, int] = member(default_factory=lambda: {})
+        c: Member[Sequence[int], Any] = member(default=()).coerce()
+
+    assert get_member(A, "s").validator.type_validator == TypeValidator.Sequence(
+        Validator(TypeValidator.Int())
+    )
+    assert get_member(A, "m").validator.type_validator == TypeValidator.Mapping(
+        (Validator(TypeValidator.Str()), Validator(TypeValidator.Int()))
+    )
+
+    # The values are validated in place and not copied.
+    obj = A()
+    s = [1, 2]
+    obj.s = s
+    assert obj.s is s
+    m = FrozenMapping({"a": 1})
+    obj.m = m
+    assert obj.m is m
+
+    with pytest.raises(TypeError) as e:
+        obj.s = (1, "a")
+    assert "Failed to validate item 1" in str(e.value.__cause__)
+    with pytest.raises(TypeError) as e:
+        obj.s = "a"
+    assert "expects a sequence other than str or bytes" in str(e.value.__cause__)
+    with pytest.raises(TypeError) as e:
+        obj.m = FrozenMapping({"a": "b"})
+    assert "Failed to validate value 'b' with key 'a'" in str(e.value.__cause__)
+    with pytest.raises(TypeError) as e:
+        obj.m = {1: 1}
+    assert "Failed to validate key 1" in str(e.value.__cause__)
+
+    # Coercion produces a list with coerced items.
+    obj.c = ("1", 2)
+    assert obj.c == [1, 2]
+    with pytest.raises(AtorsCoercionError):
+        obj.c = "12"
+
+
+def test_iterator_annotation():
+    class A(Ators):
+        a: Iterator[int]
+        b: Member[Iterator[int], Any] = member().coerce()
+
+    obj = A(a=iter([1, 2]), b=[3, 4])
+    assert list(obj.a) == [1, 2]
+    assert list(obj.b) == [3, 4]
+
+    with pytest.raises(TypeError) as e:
+        obj.a = [1, 2]
+    assert "expects a iterator" in str(e.value.__cause__)
+
+    obj.a = iter(["a"])
+    with pytest.raises(AtorsValidationError):
+        next(obj.a)
+
+
+def test_validator_for():
+    v = validator_for(list[int])
+    assert isinstance(v, Validator)
+    assert isinstance(v.type_validator, TypeValidator.List)
+    assert v.validate([1, 2]) == [1, 2]
+    with pytest.raises(AtorsValidationError) as e:
+        v.validate([1, "a"])
+    assert "Failed to validate item 1" in str(e.value)
+
+    assert validator_for(int | None).validate(None) is None
+
+    # The metadata of a top-level Annotated type are honored.
+    v = validator_for(Annotated[int, Coercer.TypeInferred()])
+    assert v.coercer == Coercer.TypeInferred()
+    assert v.validate("5") == 5
+
+    with pytest.raises(TypeError) as e:
+        validator_for(typing.ClassVar[int])
+    assert "ClassVar" in str(e.value)
+
+
+def test_validator_for_forward_references():
+    with pytest.raises(TypeError) as e:
+        validator_for(list[ForwardRef("OB")])
+    assert "without a namespace" in str(e.value)
+
+    v = validator_for(list[ForwardRef("OB")], namespace={"OB": OB})
+    ob = OB()
+    assert v.validate([ob]) == [ob]
+    with pytest.raises(AtorsValidationError):
+        v.validate([1])
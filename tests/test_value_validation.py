@@ -7,10 +7,31 @@
 # --------------------------------------------------------------------------------------
 """Test value value validation for ators object"""
 
+import math
+import re
+import warnings
+from dataclasses import dataclass
+from decimal import Decimal
+from fractions import Fraction
+from typing import Annotated, Any
+
 import pytest
 
-from ators import Ators, member
-from ators.behaviors import ValueValidator, append_value_validator
+from ators import (
+    Ators,
+    AtorsValidationError,
+    Member,
+    check_value,
+    get_member,
+    member,
+)
+from ators.behaviors import (
+    Coerce,
+    Normalizer,
+    ValueValidator,
+    append_normalizer,
+    append_value_validator,
+)
 
 
 def test_values_value_arg():
@@ -232,3 +253,539 @@ def test_bad_signature_of_method():
                 pass
 
     assert "Method signature for 'append_value_validator'" in e.exconly()
+
+
+@pytest.mark.parametrize(
+    "behavior, name",
+    [(ValueValidator, "value_validator"), (Normalizer, "normalizer")],
+)
+def test_bad_signature_of_object_method(behavior, name):
+    with pytest.raises(TypeError) as e:
+
+        class A(Ators):
+            a: int = getattr(member(), f"append_{name}")(behavior.ObjectMethod("f"))
+
+            def f(self, value):
+                return value
+
+    assert f"Member a {name} behavior reference method 'f'" in e.exconly()
+    assert "expected signature (self, member, value)" in e.exconly()
+
+
+def test_call_value_normalizer():
+    class A(Ators):
+        a: int = (
+            member()
+            .append_normalizer(Normalizer.CallValue(lambda v: min(v, 10)))
+            .append_value_validator(
+                ValueValidator.CallValue(lambda v: v >= 0 or 1 / 0)  # type: ignore
+            )
+        )
+
+    a = A(a=5)
+    assert a.a == 5
+    a.a = 15
+    assert a.a == 10
+    with pytest.raises(ZeroDivisionError):
+        a.a = -1
+    with pytest.raises(TypeError):
+        a.a = "1"  # type: ignore
+
+
+def test_normalized_value_is_type_checked():
+    class A(Ators):
+        a: int = member().append_normalizer(Normalizer.CallValue(lambda v: str(v)))
+        b: list[int] = member().append_normalizer(
+            Normalizer.CallValue(lambda v: list(v))
+        )
+
+    a = A()
+    with pytest.raises(TypeError) as e:
+        a.a = 1
+    assert "'1'" in str(e.value.__cause__)
+    a.b = [1, 2]
+    assert a.b == [1, 2]
+    errors = check_value(A, "a", 1)
+    assert len(errors) == 1
+    assert errors[0].startswith("Type error: ")
+
+
+def test_call_member_object_value_normalizer():
+    seen = []
+
+    def normalize(name, obj, value):
+        seen.append((name, obj))
+        return value.lower()
+
+    class A(Ators):
+        a: str = member().append_normalizer(Normalizer.CallNameObjectValue(normalize))
+
+    a = A(a="ABC")
+    assert a.a == "abc"
+    assert seen == [("a", a)]
+
+
+def test_method_normalizer():
+    class A(Ators):
+        a: float = member()
+
+        @append_normalizer(a)
+        def _round_a(self, member, value):
+            return round(value, 1)
+
+    a = A(a=1.26)
+    assert a.a == 1.3
+
+
+def test_normalizers_are_chained_and_inherited():
+    class A(Ators):
+        a: int = (
+            member()
+            .append_normalizer(Normalizer.CallValue(lambda v: v + 1))
+            .append_normalizer(Normalizer.CallValue(lambda v: v * 2))
+        )
+
+    class B(A):
+        a = member().inherit()
+
+    assert A(a=1).a == 4
+    assert B(a=1).a == 4
+
+
+@pytest.mark.parametrize(
+    "validator, goods, bads",
+    [
+        (ValueValidator.Range(min=0, max=10), [0, 5, 10, 2.5], [-1, 11, math.nan]),
+        (ValueValidator.Range(min=0, max=10, inclusive=False), [1, 9.5], [0, 10]),
+        (ValueValidator.Range(min=0), [0, 1e300], [-0.5, math.nan]),
+        (ValueValidator.Range(max=0), [-1e300, 0], [0.5, math.nan]),
+        (ValueValidator.Range(max=0, inclusive=False), [-1], [0, math.nan]),
+        (ValueValidator.Range(), [-math.inf, 0, math.inf], [math.nan, Decimal("NaN")]),
+        (ValueValidator.Length(min=1, max=2), ["a", [1, 2], {1: 1}], ["", (1, 2, 3)]),
+        (ValueValidator.MultipleOf(3), [0, 3, -9], [1, 4]),
+        (ValueValidator.Predicate(str.isupper), ["A", "AB"], ["a", "Ab"]),
+        (ValueValidator.Regex(r"[a-z]+\d?"), ["ab", "ab1"], ["", "ab12", "1ab"]),
+        (ValueValidator.Regex(re.compile(r"\w+", re.ASCII)), ["a_1"], ["é", "a b"]),
+        (ValueValidator.Regex(rb"[0-9]+"), [b"12", bytearray(b"3")], [b"1a", "12"]),
+    ],
+)
+def test_constraint_value_validators(validator, goods, bads):
+    class A(Ators):
+        a = member().append_value_validator(validator)
+
+    a = A()
+    for good in goods:
+        a.a = good
+        assert a.a is good
+    for bad in bads:
+        with pytest.raises(AtorsValidationError):
+            a.a = bad
+
+
+def test_range_with_numeric_type_validators():
+    class A(Ators):
+        i: int = member().append_value_validator(ValueValidator.Range(min=0, max=10))
+        f: float = member().append_value_validator(
+            ValueValidator.Range(min=0, max=1, inclusive=False)
+        )
+
+    a = A(i=0, f=0.5)
+    a.i = 10
+    assert a.i == 10
+    a.f = 0.25
+    assert a.f == 0.25
+
+    # The type is checked before the range.
+    with pytest.raises(AtorsValidationError) as e:
+        a.i = 5.0
+    assert "out of range" not in str(e.value.__cause__)
+    with pytest.raises(AtorsValidationError) as e:
+        a.i = 11
+    assert "Value 11 is out of range [0, 10]" in str(e.value.__cause__)
+    for bad in (0.0, 1.0, math.nan):
+        with pytest.raises(AtorsValidationError):
+            a.f = bad
+    assert a.f == 0.25
+
+
+def test_regex_value_validator():
+    class A(Ators):
+        a: str = member().append_value_validator(ValueValidator.Regex(r"\w+ \w+"))
+        b = member().append_value_validator(ValueValidator.Regex("[α-ω]+"))
+
+    a = A(a="héllo wörld", b="αβγ")
+    a.a = "日本 語"
+    assert a.a == "日本 語"
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = "日本語"
+    assert f"Value '日本語' does not match the pattern {r'\w+ \w+'!r}" in str(
+        e.value.__cause__
+    )
+
+    # Values which are not str are reported without involving the re module.
+    with pytest.raises(AtorsValidationError) as e:
+        a.b = 1
+    assert (
+        "Value 1 (int) cannot be matched against the pattern '[α-ω]+', expected a str"
+        in str(e.value.__cause__)
+    )
+    assert a.b == "αβγ"
+
+    assert ValueValidator.Regex("a+") == ValueValidator.Regex(re.compile("a+"))
+    assert ValueValidator.Regex("a+").pattern == re.compile("a+")
+    with pytest.raises(TypeError):
+        ValueValidator.Regex(1)
+    with pytest.raises(re.error):
+        ValueValidator.Regex("(")
+
+
+def test_length_value_validator():
+    class Sized:
+        def __init__(self, n):
+            self.n = n
+
+        def __len__(self):
+            if self.n < 0:
+                raise RuntimeError("no length")
+            return self.n
+
+    class A(Ators):
+        a: tuple[int, ...] = member().append_value_validator(
+            ValueValidator.Length(min=1, max=2)
+        )
+        b = member().append_value_validator(ValueValidator.Length(max=1))
+
+    a = A(a=(1,), b="")
+    for value in ([1], (1,), {1}, {1: 1}, "a", Sized(1)):
+        a.b = value
+        assert a.b is value
+
+    # Both the type validator and the length validator run.
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = (1, "a")
+    assert "Expected between" not in str(e.value.__cause__)
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = (1, 2, 3)
+    assert "Expected between 1 and 2 items, got 3" in str(e.value.__cause__)
+    assert a.a == (1,)
+
+    # Objects whose length cannot be computed are reported as invalid.
+    with pytest.raises(AtorsValidationError) as e:
+        a.b = 1
+    assert "Cannot check the length of 1 (int)" in str(e.value.__cause__)
+    assert isinstance(e.value.__cause__.__cause__, TypeError)
+    with pytest.raises(AtorsValidationError) as e:
+        a.b = Sized(-1)
+    assert isinstance(e.value.__cause__.__cause__, RuntimeError)
+
+
+def test_constraint_value_validators_messages():
+    class A(Ators):
+        a = member().append_value_validator(ValueValidator.Range(min=0, max=10))
+        b = member().append_value_validator(ValueValidator.Length(min=1, max=5))
+
+    a = A()
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = 12
+    assert "Value 12 is out of range [0, 10]" in str(e.value.__cause__)
+    with pytest.raises(AtorsValidationError) as e:
+        a.b = [0] * 7
+    assert "Expected between 1 and 5 items, got 7" in str(e.value.__cause__)
+
+
+@pytest.mark.parametrize(
+    "validator, goods, bads",
+    [
+        (
+            ValueValidator.Range(min=Decimal("0.1"), max=Decimal("0.3")),
+            [Decimal("0.1"), Decimal("0.3"), 0.2, Fraction(1, 5)],
+            [Decimal("0.09999999999999999999"), Decimal("0.30000000000000000001")],
+        ),
+        (
+            ValueValidator.Range(min=Decimal("0.1"), inclusive=False),
+            [Decimal("0.10000000000000000001")],
+            [Decimal("0.1"), Decimal("NaN"), math.nan, "a"],
+        ),
+        (
+            ValueValidator.Range(max=Fraction(1, 3)),
+            [Fraction(1, 3), 0],
+            [Fraction(1, 3) + Fraction(1, 10**30)],
+        ),
+        (
+            ValueValidator.Range(min=0, max=1),
+            [Decimal(1), Fraction(1, 2)],
+            [Decimal("1.0000000000000000000001"), Fraction(10**30 + 1, 10**30)],
+        ),
+    ],
+)
+def test_range_exact_numbers(validator, goods, bads):
+    class A(Ators):
+        a = member().append_value_validator(validator)
+
+    a = A()
+    for good in goods:
+        a.a = good
+        assert a.a is good
+    for bad in bads:
+        with pytest.raises(AtorsValidationError):
+            a.a = bad
+
+
+def test_range_exact_bounds_are_preserved():
+    validator = ValueValidator.Range(min=Decimal("0.1"), max=Fraction(1, 3))
+    assert type(validator.min) is Decimal and validator.min == Decimal("0.1")
+    assert validator.max == Fraction(1, 3)
+    assert validator == ValueValidator.Range(min=Fraction(1, 10), max=Fraction(1, 3))
+    assert hash(validator) == hash(
+        ValueValidator.Range(min=Fraction(1, 10), max=Fraction(1, 3))
+    )
+
+    class A(Ators):
+        a = member().append_value_validator(validator)
+
+    with pytest.raises(AtorsValidationError) as e:
+        A().a = Decimal("0.05")
+    assert "Value Decimal('0.05') is out of range [0.1, 1/3]" in str(e.value.__cause__)
+
+
+# Stand-ins for the annotated-types markers which are recognized by module.
+@dataclass(frozen=True)
+class Gt:
+    __module__ = "annotated_types"
+    gt: Any
+
+
+@dataclass(frozen=True)
+class Le:
+    __module__ = "annotated_types"
+    le: Any
+
+
+@dataclass(frozen=True)
+class Interval:
+    __module__ = "annotated_types"
+    gt: Any = None
+    ge: Any = None
+    lt: Any = None
+    le: Any = None
+
+
+@dataclass(frozen=True)
+class MaxLen:
+    __module__ = "annotated_types"
+    max_length: int
+
+
+@dataclass(frozen=True)
+class MultipleOf:
+    __module__ = "annotated_types"
+    multiple_of: Any
+
+
+@dataclass(frozen=True)
+class Predicate:
+    __module__ = "annotated_types"
+    func: Any
+
+
+@dataclass(frozen=True)
+class Timezone:
+    __module__ = "annotated_types"
+    tz: Any
+
+
+def test_range_compares_ints_exactly():
+    class A(Ators):
+        a: int = member(default=0).append_value_validator(
+            ValueValidator.Range(max=2**53)
+        )
+        b: int = member(default=0).append_value_validator(
+            ValueValidator.Range(min=0, max=10**300)
+        )
+        c: float = member(default=1.0).append_value_validator(
+            ValueValidator.Range(min=0.5, max=1.5)
+        )
+
+    a = A()
+    a.a = 2**53
+    with pytest.raises(AtorsValidationError) as e:
+        a.a = 2**53 + 1
+    assert "out of range" in str(e.value.__cause__)
+    a.b = 10**299
+    with pytest.raises(AtorsValidationError) as e:
+        a.b = 10**400
+    assert "out of range" in str(e.value.__cause__)
+    a.c = 1.0
+    with pytest.raises(AtorsValidationError):
+        a.c = 2.0
+    with pytest.raises(AtorsValidationError):
+        a.c = float("nan")
+
+
+def test_annotated_types_constraints():
+    class A(Ators):
+        a: Annotated[int, Gt(0), Le(100)] = 1
+        b: Annotated[float, Interval(ge=0, lt=1)] = 0.0
+        c: Annotated[list[int], MaxLen(2)] = member(default_factory=lambda: [])
+        d: Annotated[int, MultipleOf(2)] = 0
+        e: Annotated[str, Predicate(str.islower)] = "a"
+
+    a = A()
+    a.a = 100
+    a.b = 0.5
+    a.c = [1, 2]
+    a.d = 4
+    a.e = "b"
+    for name, bad in [
+        ("a", 0),
+        ("a", 101),
+        ("b", 1.0),
+        ("b", -0.5),
+        ("c", [1, 2, 3]),
+        ("d", 3),
+        ("e", "B"),
+    ]:
+        with pytest.raises(AtorsValidationError):
+            setattr(a, name, bad)
+
+
+def test_annotated_types_constraints_with_coerce():
+    """The Coerce marker can be mixed with constraint markers."""
+
+    class A(Ators):
+        a: Annotated[int, Gt(0), Coerce, Le(100)] = 1
+
+    validator = get_member(A, "a").validator
+    assert validator.coercer == Coerce
+    assert len(validator.value_validators) == 2
+
+    a = A()
+    a.a = "50"
+    assert a.a == 50
+
+
+def test_annotated_types_constraints_on_container_items():
+    class A(Ators):
+        a: list[Annotated[int, Gt(0)]] = member(default_factory=lambda: [])
+        b: dict[str, Annotated[float, Interval(ge=0, le=1)]] = member(
+            default_factory=lambda: {}
+        )
+        c: tuple[Annotated[int, Gt(0)], Annotated[str, MaxLen(1)]] = (1, "a")
+        d: set[Annotated[int, MultipleOf(2)]] = member(default_factory=lambda: set())
+        e: Annotated[list[Annotated[int, Gt(0)]], MaxLen(2)] = member(
+            default_factory=lambda: []
+        )
+
+    a = A(a=[1, 2], b={"x": 0.5}, c=(2, "b"), d={2, 4}, e=[1])
+    for name, bad in [
+        ("a", [1, 0]),
+        ("b", {"x": 1.5}),
+        ("c", (0, "a")),
+        ("c", (1, "ab")),
+        ("d", {3}),
+        ("e", [0]),
+        ("e", [1, 2, 3]),
+    ]:
+        with pytest.raises(AtorsValidationError):
+            setattr(a, name, bad)
+
+    # The constraints also apply to the items added to the validated containers.
+    with pytest.raises(AtorsValidationError):
+        a.a.append(-1)
+    with pytest.raises(AtorsValidationError):
+        a.b["y"] = 2.0
+    assert a.a == [1, 2]
+    assert a.b == {"x": 0.5}
+
+
+def test_annotated_types_unsupported_constraints_warn():
+    with pytest.warns(UserWarning, match="Ignoring unsupported constraint"):
+
+        class A(Ators):
+            a: Annotated[int, Timezone(None)] = 0
+
+    with pytest.warns(UserWarning, match="Ignoring unsupported constraint"):
+
+        class B(Ators):
+            a: Annotated[str, Gt("a")] = "b"
+
+    # Metadata unrelated to annotated-types are silently ignored.
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+
+        class C(Ators):
+            a: Annotated[int, "meta"] = 0
+
+
+def test_annotated_types_library():
+    annotated_types = pytest.importorskip("annotated_types")
+
+    class A(Ators):
+        a: Annotated[int, annotated_types.Gt(0)] = 1
+        b: Annotated[str, annotated_types.Len(1, 3)] = "a"
+
+    a = A()
+    with pytest.raises(AtorsValidationError):
+        a.a = 0
+    with pytest.raises(AtorsValidationError):
+        a.b = "abcd"
+
+
+def test_check_value():
+    class A(Ators):
+        a: int = (
+            member()
+            .append_value_validator(ValueValidator.Range(min=0, max=10))
+            .append_value_validator(ValueValidator.MultipleOf(3))
+        )
+        b: list[int] = member()
+        c: Member[int, Any] = member().coerce()
+        d: int = member(default=0).append_value_validator(
+            ValueValidator.ObjectMethod("_check")
+        )
+
+        def _check(self, name, value):
+            if value != self.a:
+                raise ValueError(f"{name} must be equal to a")
+
+    assert check_value(A, "a", 6) == []
+
+    # All the failing value validators are reported.
+    errors = check_value(A, "a", 11)
+    assert len(errors) == 2
+    assert errors[0].startswith("Value error: Value 11 is out of range")
+    assert errors[1] == "Value error: Value 11 is not a multiple of 3"
+
+    # Value validators do not run on values of an invalid type.
+    errors = check_value(A, "a", "x")
+    assert len(errors) == 1
+    assert errors[0].startswith("Type error:")
+
+    # The failure of an item includes its cause.
+    (error,) = check_value(A, "b", [1, "a"])
+    assert error.startswith("Type error: Failed to validate item 1")
+    assert "'a' (str)" in error
+
+    # Values are not coerced.
+    assert check_value(A, "c", "1")[0].startswith("Type error:")
+
+    # Validators using the object require an instance.
+    a = A(a=3, b=[], c=0)
+    assert check_value(a, "d", 3) == []
+    assert check_value(a, "d", 4) == ["Value error: d must be equal to a"]
+
+    with pytest.raises(AttributeError, match="A has no member 'e'"):
+        check_value(A, "e", 1)
+    with pytest.raises(TypeError):
+        check_value(int, "a", 1)
+
+
+def test_check_value_propagates_other_errors():
+    def check(value):
+        raise RuntimeError("broken validator")
+
+    class A(Ators):
+        a: int = member().append_value_validator(ValueValidator.CallValue(check))
+
+    with pytest.raises(RuntimeError, match="broken validator"):
+        check_value(A, "a", 1)